@@ -0,0 +1,26 @@
+//! Deserialization keeping the whitespace of the text content.
+
+use std::io::Read;
+use xml::reader::{EventReader, ParserConfig};
+use yaserde::de::Deserializer;
+use yaserde::YaDeserialize;
+
+/// Read a type from a string, like `yaserde::de::from_str` but without trimming the text.
+pub fn from_str<T: YaDeserialize>(s: &str) -> Result<T, String> {
+  from_reader(s.as_bytes())
+}
+
+/// Read a type from a reader, like `yaserde::de::from_reader` but without trimming the text:
+/// the text of mixed content is kept with the whitespace around its child elements.
+pub fn from_reader<R: Read, T: YaDeserialize>(reader: R) -> Result<T, String> {
+  let config = ParserConfig::new()
+    .trim_whitespace(false)
+    .whitespace_to_characters(true)
+    .cdata_to_characters(true)
+    .ignore_comments(true)
+    .coalesce_characters(true);
+
+  T::deserialize(&mut Deserializer::new(EventReader::new_with_config(
+    reader, config,
+  )))
+}
@@ -2,3 +2,9 @@
 #[allow(unused_imports)]
 #[macro_use]
 extern crate xml_schema_derive;
+
+#[cfg(feature = "generator")]
+pub use xml_schema_generator::Generator;
+
+pub mod de;
+pub mod types;
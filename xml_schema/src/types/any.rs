@@ -0,0 +1,583 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent as ReaderEvent;
+use xml::writer::XmlEvent as WriterEvent;
+use yaserde::de::Deserializer;
+use yaserde::ser::Serializer;
+use yaserde::{YaDeserialize, YaSerialize};
+
+/// Namespaces matched by a wildcard, from its `namespace` attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NamespaceConstraint {
+  /// `##any`: every name.
+  Any,
+  /// `##other`: qualified names of another namespace than the target namespace.
+  Other(Option<&'static str>),
+  /// Names of the listed namespaces, `None` standing for unqualified names (`##local`).
+  List(&'static [Option<&'static str>]),
+}
+
+impl NamespaceConstraint {
+  pub fn allows(&self, namespace: Option<&str>) -> bool {
+    let namespace = namespace.filter(|namespace| !namespace.is_empty());
+
+    match self {
+      NamespaceConstraint::Any => true,
+      NamespaceConstraint::Other(target_namespace) => {
+        namespace.is_some() && namespace != *target_namespace
+      }
+      NamespaceConstraint::List(namespaces) => namespaces.contains(&namespace),
+    }
+  }
+}
+
+/// Validation of the content matched by a wildcard, from its `processContents` attribute.
+///
+/// The content is always kept as it was read, `Strict` only requires it to be declared
+/// by a top-level element (or attribute) of the schemas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessContents {
+  Strict,
+  Lax,
+  Skip,
+}
+
+/// Constraints of an `xs:any` or `xs:anyAttribute` wildcard, implemented by the generated code.
+pub trait Wildcard {
+  const NAMESPACE: NamespaceConstraint;
+  const PROCESS_CONTENTS: ProcessContents;
+  /// Top-level declarations of the schemas, as namespace and local name, required by `Strict`.
+  const DECLARED: &'static [(Option<&'static str>, &'static str)] = &[];
+  /// Local names of the attributes declared by the type, they are not matched by the wildcard.
+  const EXCLUDED: &'static [&'static str] = &[];
+
+  /// Check if the wildcard matches a name, fails for an undeclared name with `Strict`.
+  fn matches(name: &OwnedName) -> Result<bool, String> {
+    if Self::EXCLUDED.contains(&name.local_name.as_str())
+      || !Self::NAMESPACE.allows(name.namespace.as_deref())
+    {
+      return Ok(false);
+    }
+
+    let namespace = name
+      .namespace
+      .as_deref()
+      .filter(|namespace| !namespace.is_empty());
+    if Self::PROCESS_CONTENTS == ProcessContents::Strict
+      && !Self::DECLARED.contains(&(namespace, name.local_name.as_str()))
+    {
+      return Err(format!(
+        "{name} is not declared, as required by processContents=\"strict\""
+      ));
+    }
+
+    Ok(true)
+  }
+}
+
+/// Node of an [`XmlElement`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum XmlNode {
+  Element(XmlElement),
+  Text(String),
+}
+
+/// Owned XML element, with its attributes and its content.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmlElement {
+  pub name: OwnedName,
+  pub attributes: Vec<OwnedAttribute>,
+  /// Namespace bindings in scope of the element, declared again when it is written.
+  pub namespace: Namespace,
+  pub children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+  pub fn new(name: OwnedName) -> Self {
+    XmlElement {
+      name,
+      attributes: vec![],
+      namespace: Namespace::empty(),
+      children: vec![],
+    }
+  }
+
+  /// Value of an attribute, by its namespace and local name.
+  pub fn attribute(&self, namespace: Option<&str>, local_name: &str) -> Option<&str> {
+    find_attribute(&self.attributes, namespace, local_name)
+      .map(|attribute| attribute.value.as_str())
+  }
+
+  /// Child elements, without the text content.
+  pub fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+    self.children.iter().filter_map(|child| match child {
+      XmlNode::Element(element) => Some(element),
+      XmlNode::Text(_) => None,
+    })
+  }
+
+  /// Text content of the element, without the text of its child elements.
+  pub fn text(&self) -> String {
+    self
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        XmlNode::Text(text) => Some(text.as_str()),
+        XmlNode::Element(_) => None,
+      })
+      .collect()
+  }
+
+  /// Read the element as a type, e.g. a structure generated from the schema declaring it.
+  pub fn deserialize_as<T: YaDeserialize>(&self) -> Result<T, String> {
+    yaserde::de::from_str(&yaserde::ser::to_string(self)?)
+  }
+
+  /// Read the consecutive sibling elements of a model group nested in a choice, as the children
+  /// of an element named `name`. Reading stops at an element which is not one of the `names`
+  /// of the group, or which is found again while not one of its `repeated` names.
+  pub fn read_group<R: Read>(
+    reader: &mut Deserializer<R>,
+    name: &str,
+    names: &[&str],
+    repeated: &[&str],
+  ) -> Result<Self, String> {
+    let mut group = XmlElement::new(OwnedName::local(name));
+    let mut read_names = vec![];
+
+    loop {
+      match reader.peek()? {
+        ReaderEvent::StartElement { name, .. } => {
+          let local_name = name.local_name.clone();
+          if !names.contains(&local_name.as_str())
+            || (read_names.contains(&local_name) && !repeated.contains(&local_name.as_str()))
+          {
+            break;
+          }
+
+          let element = XmlElement::read(reader)?;
+          group.children.push(XmlNode::Element(element));
+          read_names.push(local_name);
+        }
+        ReaderEvent::EndElement { .. } | ReaderEvent::EndDocument => break,
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    Ok(group)
+  }
+
+  fn read<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let ReaderEvent::StartElement {
+      name,
+      attributes,
+      namespace,
+    } = reader.next_event()?
+    else {
+      return Err("Expected the start of an element".to_string());
+    };
+
+    let mut element = XmlElement {
+      name,
+      attributes,
+      namespace,
+      children: vec![],
+    };
+
+    loop {
+      match reader.peek()? {
+        ReaderEvent::StartElement { .. } => {
+          let child = XmlElement::read(reader)?;
+          element.children.push(XmlNode::Element(child));
+        }
+        ReaderEvent::EndElement { .. } => {
+          reader.next_event()?;
+          return Ok(element);
+        }
+        ReaderEvent::EndDocument => {
+          return Err(format!("Missing the end of element {}", element.name));
+        }
+        _ => {
+          if let ReaderEvent::Characters(text) = reader.next_event()? {
+            element.children.push(XmlNode::Text(text));
+          }
+        }
+      }
+    }
+  }
+
+  fn write<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    let attributes: Vec<_> = self
+      .attributes
+      .iter()
+      .map(|attribute| attribute.borrow())
+      .collect();
+
+    writer
+      .write(WriterEvent::StartElement {
+        name: self.name.borrow(),
+        attributes: Cow::Owned(attributes),
+        namespace: Cow::Borrowed(&self.namespace),
+      })
+      .map_err(|e| e.to_string())?;
+
+    for child in &self.children {
+      match child {
+        XmlNode::Element(element) => element.write(writer)?,
+        XmlNode::Text(text) => writer
+          .write(WriterEvent::characters(text))
+          .map_err(|e| e.to_string())?,
+      }
+    }
+
+    writer
+      .write(WriterEvent::end_element())
+      .map_err(|e| e.to_string())
+  }
+}
+
+impl YaDeserialize for XmlElement {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    XmlElement::read(reader)
+  }
+}
+
+impl YaSerialize for XmlElement {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    self.write(writer)
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<OwnedAttribute>,
+    source_namespace: Namespace,
+  ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Elements matched by an `xs:any` wildcard, kept as they were read to be serialized again.
+///
+/// It is a flattened field of the generated structure, reading the elements not matched
+/// by the other fields.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct XsAny<W> {
+  pub elements: Vec<XmlElement>,
+  wildcard: PhantomData<W>,
+}
+
+impl<W> From<Vec<XmlElement>> for XsAny<W> {
+  fn from(elements: Vec<XmlElement>) -> Self {
+    XsAny {
+      elements,
+      wildcard: PhantomData,
+    }
+  }
+}
+
+impl<W: Wildcard> YaDeserialize for XsAny<W> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let mut elements = vec![];
+
+    // start of the parent element
+    if let ReaderEvent::StartElement { .. } = reader.peek()? {
+      reader.next_event()?;
+    }
+
+    loop {
+      match reader.peek()? {
+        ReaderEvent::StartElement { name, .. } => {
+          let matches = W::matches(name)?;
+          let element = XmlElement::read(reader)?;
+          if matches {
+            elements.push(element);
+          }
+        }
+        ReaderEvent::EndElement { .. } | ReaderEvent::EndDocument => break,
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    Ok(XsAny::from(elements))
+  }
+}
+
+impl<W: Wildcard> YaSerialize for XsAny<W> {
+  fn serialize<S: Write>(&self, writer: &mut Serializer<S>) -> Result<(), String> {
+    self
+      .elements
+      .iter()
+      .try_for_each(|element| element.write(writer))
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<OwnedAttribute>,
+    source_namespace: Namespace,
+  ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Attributes matched by an `xs:anyAttribute` wildcard, by their qualified name.
+///
+/// It is a flattened field of the generated structure, reading the attributes
+/// not declared by the type.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct XsAnyAttributes<W> {
+  attributes: Vec<OwnedAttribute>,
+  wildcard: PhantomData<W>,
+}
+
+impl<W> XsAnyAttributes<W> {
+  pub fn get(&self, namespace: Option<&str>, local_name: &str) -> Option<&str> {
+    find_attribute(&self.attributes, namespace, local_name)
+      .map(|attribute| attribute.value.as_str())
+  }
+
+  /// Set the value of an attribute, returning its previous value.
+  /// A prefix of the name is declared with its namespace when it is serialized.
+  pub fn insert(&mut self, name: OwnedName, value: impl Into<String>) -> Option<String> {
+    let value = value.into();
+    let namespace = name.namespace.as_deref();
+
+    match find_attribute_mut(&mut self.attributes, namespace, &name.local_name) {
+      Some(attribute) => Some(std::mem::replace(&mut attribute.value, value)),
+      None => {
+        self.attributes.push(OwnedAttribute::new(name, value));
+        None
+      }
+    }
+  }
+
+  pub fn remove(&mut self, namespace: Option<&str>, local_name: &str) -> Option<String> {
+    let index = self
+      .attributes
+      .iter()
+      .position(|attribute| has_name(attribute, namespace, local_name))?;
+
+    Some(self.attributes.remove(index).value)
+  }
+
+  /// Attributes in the order they were read or inserted.
+  pub fn iter(&self) -> impl Iterator<Item = (&OwnedName, &str)> {
+    self
+      .attributes
+      .iter()
+      .map(|attribute| (&attribute.name, attribute.value.as_str()))
+  }
+
+  pub fn len(&self) -> usize {
+    self.attributes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.attributes.is_empty()
+  }
+}
+
+impl<W: Wildcard> YaDeserialize for XsAnyAttributes<W> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let mut attributes = vec![];
+
+    // attributes of the parent element
+    if let ReaderEvent::StartElement {
+      attributes: element_attributes,
+      ..
+    } = reader.peek()?
+    {
+      for attribute in element_attributes {
+        if W::matches(&attribute.name)? {
+          attributes.push(attribute.clone());
+        }
+      }
+    }
+
+    Ok(XsAnyAttributes {
+      attributes,
+      wildcard: PhantomData,
+    })
+  }
+}
+
+impl<W: Wildcard> YaSerialize for XsAnyAttributes<W> {
+  fn serialize<S: Write>(&self, _writer: &mut Serializer<S>) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    mut source_attributes: Vec<OwnedAttribute>,
+    mut source_namespace: Namespace,
+  ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+    for attribute in &self.attributes {
+      if let (Some(prefix), Some(namespace)) = (&attribute.name.prefix, &attribute.name.namespace) {
+        source_namespace.put(prefix.as_str(), namespace.as_str());
+      }
+    }
+
+    source_attributes.extend(self.attributes.iter().cloned());
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+fn has_name(attribute: &OwnedAttribute, namespace: Option<&str>, local_name: &str) -> bool {
+  let attribute_namespace = attribute
+    .name
+    .namespace
+    .as_deref()
+    .filter(|namespace| !namespace.is_empty());
+
+  attribute.name.local_name == local_name
+    && attribute_namespace == namespace.filter(|namespace| !namespace.is_empty())
+}
+
+fn find_attribute<'a>(
+  attributes: &'a [OwnedAttribute],
+  namespace: Option<&str>,
+  local_name: &str,
+) -> Option<&'a OwnedAttribute> {
+  attributes
+    .iter()
+    .find(|attribute| has_name(attribute, namespace, local_name))
+}
+
+fn find_attribute_mut<'a>(
+  attributes: &'a mut [OwnedAttribute],
+  namespace: Option<&str>,
+  local_name: &str,
+) -> Option<&'a mut OwnedAttribute> {
+  attributes
+    .iter_mut()
+    .find(|attribute| has_name(attribute, namespace, local_name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug, Default, PartialEq)]
+  struct Extensions;
+
+  impl Wildcard for Extensions {
+    const NAMESPACE: NamespaceConstraint = NamespaceConstraint::Other(Some("http://example.com"));
+    const PROCESS_CONTENTS: ProcessContents = ProcessContents::Lax;
+    const EXCLUDED: &'static [&'static str] = &["id"];
+  }
+
+  #[derive(Clone, Debug, Default, PartialEq)]
+  struct Declared;
+
+  impl Wildcard for Declared {
+    const NAMESPACE: NamespaceConstraint = NamespaceConstraint::List(&[None]);
+    const PROCESS_CONTENTS: ProcessContents = ProcessContents::Strict;
+    const DECLARED: &'static [(Option<&'static str>, &'static str)] = &[(None, "note")];
+  }
+
+  static CONTENT: &str = r#"<Book xmlns="http://example.com" xmlns:x="http://extension.com" id="1" x:rating="5" lang="en"><title>Title</title><x:review x:by="me">Good <x:b>read</x:b></x:review><other/></Book>"#;
+
+  #[test]
+  fn namespace_constraint() {
+    assert!(NamespaceConstraint::Any.allows(None));
+    assert!(NamespaceConstraint::Other(Some("a")).allows(Some("b")));
+    assert!(!NamespaceConstraint::Other(Some("a")).allows(Some("a")));
+    assert!(!NamespaceConstraint::Other(Some("a")).allows(None));
+    assert!(!NamespaceConstraint::Other(None).allows(Some("")));
+    assert!(NamespaceConstraint::List(&[None, Some("a")]).allows(Some("")));
+    assert!(!NamespaceConstraint::List(&[Some("a")]).allows(Some("b")));
+  }
+
+  #[test]
+  fn any_elements() {
+    let any: XsAny<Extensions> = yaserde::de::from_str(CONTENT).unwrap();
+    assert_eq!(any.elements.len(), 1);
+
+    let review = &any.elements[0];
+    assert_eq!(review.name.local_name, "review");
+    assert_eq!(
+      review.attribute(Some("http://extension.com"), "by"),
+      Some("me")
+    );
+    assert_eq!(review.text(), "Good");
+    assert_eq!(review.elements().next().unwrap().text(), "read");
+
+    let mut writer = Serializer::new_for_inner(vec![]);
+    any.serialize(&mut writer).unwrap();
+    assert_eq!(
+      String::from_utf8(writer.into_inner()).unwrap(),
+      r#"<x:review xmlns="http://example.com" xmlns:x="http://extension.com" x:by="me">Good<x:b>read</x:b></x:review>"#
+    );
+  }
+
+  #[test]
+  fn any_attributes() {
+    let mut attributes: XsAnyAttributes<Extensions> = yaserde::de::from_str(CONTENT).unwrap();
+    assert_eq!(attributes.len(), 1);
+    assert_eq!(
+      attributes.get(Some("http://extension.com"), "rating"),
+      Some("5")
+    );
+    assert_eq!(attributes.get(None, "lang"), None);
+
+    let name = OwnedName::qualified("rating", "http://extension.com", Some("x"));
+    assert_eq!(attributes.insert(name, "4"), Some("5".to_string()));
+    assert_eq!(
+      attributes.remove(Some("http://extension.com"), "rating"),
+      Some("4".to_string())
+    );
+    assert!(attributes.is_empty());
+
+    let name = OwnedName::qualified("origin", "http://other.com", Some("o"));
+    attributes.insert(name, "import");
+    let (attributes, namespace) = attributes
+      .serialize_attributes(vec![], Namespace::empty())
+      .unwrap();
+    assert_eq!(
+      attributes[0].to_string(),
+      "{http://other.com}o:origin=\"import\""
+    );
+    assert_eq!(namespace.get("o"), Some("http://other.com"));
+  }
+
+  #[test]
+  fn model_group() {
+    let content = r#"<Contact><street>Main</street><line>1</line><line>2</line><street>Other</street></Contact>"#;
+    let mut reader = Deserializer::new_from_reader(content.as_bytes());
+    reader.next_event().unwrap();
+
+    let group =
+      XmlElement::read_group(&mut reader, "Address", &["street", "line"], &["line"]).unwrap();
+    assert_eq!(group.name.local_name, "Address");
+    assert_eq!(
+      group
+        .elements()
+        .map(|element| element.text())
+        .collect::<Vec<_>>(),
+      vec!["Main", "1", "2"]
+    );
+
+    let street = XmlElement::read(&mut reader).unwrap();
+    assert_eq!(street.text(), "Other");
+  }
+
+  #[test]
+  fn strict_wildcard() {
+    let content = r#"<Book><note>1</note></Book>"#;
+    let any: XsAny<Declared> = yaserde::de::from_str(content).unwrap();
+    assert_eq!(any.elements[0].text(), "1");
+
+    let content = r#"<Book><comment>1</comment></Book>"#;
+    assert_eq!(
+      yaserde::de::from_str::<XsAny<Declared>>(content).unwrap_err(),
+      "comment is not declared, as required by processContents=\"strict\""
+    );
+  }
+}
@@ -0,0 +1,225 @@
+use super::{implement_lexical_type, lexical};
+
+/// `xs:dateTime` value, e.g. `2024-03-15T13:20:00.5+01:00`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsDateTime {
+  year: i64,
+  month: u8,
+  day: u8,
+  hour: u8,
+  minute: u8,
+  second: u8,
+  nanosecond: u32,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:date` value, e.g. `2024-03-15` or `2024-03-15Z`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsDate {
+  year: i64,
+  month: u8,
+  day: u8,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:time` value, e.g. `13:20:00` or `13:20:00.5-05:00`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsTime {
+  hour: u8,
+  minute: u8,
+  second: u8,
+  nanosecond: u32,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+impl XsDateTime {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:dateTime");
+
+    let (date_time, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let (date, time) = date_time.split_once('T').ok_or_else(invalid)?;
+    let (year, month, day) = lexical::parse_date(date).ok_or_else(invalid)?;
+    let (hour, minute, second, nanosecond) = lexical::parse_time(time).ok_or_else(invalid)?;
+
+    Ok(XsDateTime {
+      year,
+      month,
+      day,
+      hour,
+      minute,
+      second,
+      nanosecond,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn year(&self) -> i64 {
+    self.year
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  pub fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Hour of the day, `24` only for the end of the day (`24:00:00`).
+  pub fn hour(&self) -> u8 {
+    self.hour
+  }
+
+  pub fn minute(&self) -> u8 {
+    self.minute
+  }
+
+  pub fn second(&self) -> u8 {
+    self.second
+  }
+
+  pub fn nanosecond(&self) -> u32 {
+    self.nanosecond
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsDate {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:date");
+
+    let (date, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let (year, month, day) = lexical::parse_date(date).ok_or_else(invalid)?;
+
+    Ok(XsDate {
+      year,
+      month,
+      day,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn year(&self) -> i64 {
+    self.year
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  pub fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsTime {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:time");
+
+    let (time, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let (hour, minute, second, nanosecond) = lexical::parse_time(time).ok_or_else(invalid)?;
+
+    Ok(XsTime {
+      hour,
+      minute,
+      second,
+      nanosecond,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  /// Hour of the day, `24` only for the end of the day (`24:00:00`).
+  pub fn hour(&self) -> u8 {
+    self.hour
+  }
+
+  pub fn minute(&self) -> u8 {
+    self.minute
+  }
+
+  pub fn second(&self) -> u8 {
+    self.second
+  }
+
+  pub fn nanosecond(&self) -> u32 {
+    self.nanosecond
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+implement_lexical_type!(XsDateTime, "1970-01-01T00:00:00");
+implement_lexical_type!(XsDate, "1970-01-01");
+implement_lexical_type!(XsTime, "00:00:00");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn date_time() {
+    let value: XsDateTime = "2024-03-15T13:20:00.5+01:00".parse().unwrap();
+
+    assert_eq!((value.year(), value.month(), value.day()), (2024, 3, 15));
+    assert_eq!(
+      (
+        value.hour(),
+        value.minute(),
+        value.second(),
+        value.nanosecond()
+      ),
+      (13, 20, 0, 500_000_000)
+    );
+    assert_eq!(value.timezone(), Some(60));
+    assert_eq!(value.to_string(), "2024-03-15T13:20:00.5+01:00");
+
+    let value: XsDateTime = " 2024-03-15T13:20:00 ".parse().unwrap();
+    assert_eq!(value.timezone(), None);
+    assert_eq!(value.as_str(), "2024-03-15T13:20:00");
+
+    assert_eq!(
+      "2024-03-15".parse::<XsDateTime>().unwrap_err(),
+      "\"2024-03-15\" is not a valid xs:dateTime"
+    );
+    assert_eq!(XsDateTime::default().as_str(), "1970-01-01T00:00:00");
+  }
+
+  #[test]
+  fn date() {
+    let value: XsDate = "2024-02-29Z".parse().unwrap();
+
+    assert_eq!((value.year(), value.month(), value.day()), (2024, 2, 29));
+    assert_eq!(value.timezone(), Some(0));
+    assert_eq!(value.to_string(), "2024-02-29Z");
+
+    assert!("2023-02-29".parse::<XsDate>().is_err());
+  }
+
+  #[test]
+  fn time() {
+    let value: XsTime = "23:59:59-05:00".parse().unwrap();
+
+    assert_eq!((value.hour(), value.minute(), value.second()), (23, 59, 59));
+    assert_eq!(value.timezone(), Some(-300));
+
+    assert!("23:59".parse::<XsTime>().is_err());
+  }
+}
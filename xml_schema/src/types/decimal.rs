@@ -0,0 +1,101 @@
+use crate::implement_text_type;
+use rust_decimal::Decimal;
+
+/// `xs:decimal` value, e.g. `-1.23` or `100.50`, of up to 28 significant digits.
+///
+/// The scale of the value is kept, so `100.50` is serialized with its trailing zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct XsDecimal(Decimal);
+
+impl XsDecimal {
+  pub fn new(value: Decimal) -> Self {
+    XsDecimal(value)
+  }
+
+  pub fn value(&self) -> Decimal {
+    self.0
+  }
+}
+
+impl From<Decimal> for XsDecimal {
+  fn from(value: Decimal) -> Self {
+    XsDecimal(value)
+  }
+}
+
+impl From<XsDecimal> for Decimal {
+  fn from(value: XsDecimal) -> Self {
+    value.0
+  }
+}
+
+impl std::str::FromStr for XsDecimal {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let invalid = || format!("{value:?} is not a valid xs:decimal");
+
+    // an optional sign, then digits with an optional decimal point, e.g. `+.5` or `5.`
+    let trimmed = value.trim();
+    let unsigned = trimmed
+      .strip_prefix('-')
+      .or_else(|| trimmed.strip_prefix('+'))
+      .unwrap_or(trimmed);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+      return Err(invalid());
+    }
+
+    let sign = if trimmed.starts_with('-') { "-" } else { "" };
+    let integer = if integer.is_empty() { "0" } else { integer };
+    let normalized = if fraction.is_empty() {
+      format!("{sign}{integer}")
+    } else {
+      format!("{sign}{integer}.{fraction}")
+    };
+
+    Decimal::from_str_exact(&normalized)
+      .map(XsDecimal)
+      .map_err(|_| invalid())
+  }
+}
+
+impl std::fmt::Display for XsDecimal {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+implement_text_type!(XsDecimal);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decimal() {
+    let value: XsDecimal = " 100.50 ".parse().unwrap();
+    assert_eq!(value.value(), Decimal::new(10050, 2));
+    assert_eq!(value.to_string(), "100.50");
+
+    assert_eq!("-1.23".parse::<XsDecimal>().unwrap().to_string(), "-1.23");
+    assert_eq!("+.5".parse::<XsDecimal>().unwrap().to_string(), "0.5");
+    assert_eq!("5.".parse::<XsDecimal>().unwrap().to_string(), "5");
+    assert_eq!(
+      "12345678901234567890.12345678"
+        .parse::<XsDecimal>()
+        .unwrap()
+        .to_string(),
+      "12345678901234567890.12345678"
+    );
+
+    for invalid in ["", ".", "-", "1e5", "1_000", "1.2.3", "NaN"] {
+      assert_eq!(
+        invalid.parse::<XsDecimal>().unwrap_err(),
+        format!("{invalid:?} is not a valid xs:decimal")
+      );
+    }
+  }
+}
@@ -0,0 +1,183 @@
+use super::{implement_lexical_type, lexical};
+
+/// `xs:duration` value, e.g. `P1Y2M3DT4H5M6.7S` or `-PT30M`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsDuration {
+  negative: bool,
+  years: u64,
+  months: u64,
+  days: u64,
+  hours: u64,
+  minutes: u64,
+  seconds: u64,
+  nanoseconds: u32,
+  lexical: String,
+}
+
+impl XsDuration {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:duration");
+
+    let (negative, rest) = match value.strip_prefix('-') {
+      Some(rest) => (true, rest),
+      None => (false, value),
+    };
+    let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+
+    let (date, time) = match rest.split_once('T') {
+      Some((_date, "")) => return Err(invalid()),
+      Some((date, time)) => (date, Some(time)),
+      None => (rest, None),
+    };
+
+    if date.is_empty() && time.is_none() {
+      return Err(invalid());
+    }
+
+    let mut duration = XsDuration {
+      negative,
+      years: 0,
+      months: 0,
+      days: 0,
+      hours: 0,
+      minutes: 0,
+      seconds: 0,
+      nanoseconds: 0,
+      lexical: value.to_string(),
+    };
+
+    let mut designators = "YMD".chars();
+    for (number, designator) in split_components(date).ok_or_else(invalid)? {
+      // each designator can be present once, in this order
+      if !designators.any(|expected| expected == designator) || number.contains('.') {
+        return Err(invalid());
+      }
+      let number = lexical::parse_digits(number).ok_or_else(invalid)? as u64;
+      match designator {
+        'Y' => duration.years = number,
+        'M' => duration.months = number,
+        _ => duration.days = number,
+      }
+    }
+
+    let mut designators = "HMS".chars();
+    for (number, designator) in split_components(time.unwrap_or_default()).ok_or_else(invalid)? {
+      if !designators.any(|expected| expected == designator) {
+        return Err(invalid());
+      }
+
+      if designator == 'S' {
+        let (seconds, fraction) = match number.split_once('.') {
+          Some((seconds, fraction)) => (seconds, Some(fraction)),
+          None => (number, None),
+        };
+        duration.seconds = lexical::parse_digits(seconds).ok_or_else(invalid)? as u64;
+        duration.nanoseconds = fraction
+          .map(lexical::parse_nanoseconds)
+          .unwrap_or(Some(0))
+          .ok_or_else(invalid)?;
+        continue;
+      }
+
+      if number.contains('.') {
+        return Err(invalid());
+      }
+      let number = lexical::parse_digits(number).ok_or_else(invalid)? as u64;
+      match designator {
+        'H' => duration.hours = number,
+        _ => duration.minutes = number,
+      }
+    }
+
+    Ok(duration)
+  }
+
+  /// Check if the duration is negative, its components are positive.
+  pub fn is_negative(&self) -> bool {
+    self.negative
+  }
+
+  pub fn years(&self) -> u64 {
+    self.years
+  }
+
+  pub fn months(&self) -> u64 {
+    self.months
+  }
+
+  pub fn days(&self) -> u64 {
+    self.days
+  }
+
+  pub fn hours(&self) -> u64 {
+    self.hours
+  }
+
+  pub fn minutes(&self) -> u64 {
+    self.minutes
+  }
+
+  pub fn seconds(&self) -> u64 {
+    self.seconds
+  }
+
+  pub fn nanoseconds(&self) -> u32 {
+    self.nanoseconds
+  }
+}
+
+/// Split the components of a duration, a number followed by its designator, e.g. `1Y2M`.
+fn split_components(value: &str) -> Option<Vec<(&str, char)>> {
+  let mut components = vec![];
+  let mut start = 0;
+
+  for (position, character) in value.char_indices() {
+    if character.is_ascii_digit() || character == '.' {
+      continue;
+    }
+
+    if position == start {
+      return None;
+    }
+    components.push((&value[start..position], character));
+    start = position + character.len_utf8();
+  }
+
+  (start == value.len()).then_some(components)
+}
+
+implement_lexical_type!(XsDuration, "PT0S");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn duration() {
+    let value: XsDuration = "P1Y2M3DT4H5M6.7S".parse().unwrap();
+
+    assert!(!value.is_negative());
+    assert_eq!((value.years(), value.months(), value.days()), (1, 2, 3));
+    assert_eq!(
+      (
+        value.hours(),
+        value.minutes(),
+        value.seconds(),
+        value.nanoseconds()
+      ),
+      (4, 5, 6, 700_000_000)
+    );
+    assert_eq!(value.to_string(), "P1Y2M3DT4H5M6.7S");
+
+    let value: XsDuration = "-PT30M".parse().unwrap();
+    assert!(value.is_negative());
+    assert_eq!(value.minutes(), 30);
+
+    for invalid in ["P", "PT", "P1H", "PT1D", "P1M1Y", "P1.5D", "1D", "P1YT"] {
+      assert_eq!(
+        invalid.parse::<XsDuration>().unwrap_err(),
+        format!("{invalid:?} is not a valid xs:duration")
+      );
+    }
+  }
+}
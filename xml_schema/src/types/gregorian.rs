@@ -0,0 +1,239 @@
+use super::{implement_lexical_type, lexical};
+
+/// `xs:gYear` value, e.g. `2024`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsGYear {
+  year: i64,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:gYearMonth` value, e.g. `2024-03`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsGYearMonth {
+  year: i64,
+  month: u8,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:gMonthDay` value, a recurring day of the year, e.g. `--12-25`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsGMonthDay {
+  month: u8,
+  day: u8,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:gDay` value, a recurring day of the month, e.g. `---15`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsGDay {
+  day: u8,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+/// `xs:gMonth` value, a recurring month of the year, e.g. `--03`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XsGMonth {
+  month: u8,
+  timezone: Option<i16>,
+  lexical: String,
+}
+
+impl XsGYear {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:gYear");
+
+    let (year, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+
+    Ok(XsGYear {
+      year: lexical::parse_year(year).ok_or_else(invalid)?,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn year(&self) -> i64 {
+    self.year
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsGYearMonth {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:gYearMonth");
+
+    let (year_month, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let position = year_month.len().checked_sub(3).ok_or_else(invalid)?;
+    let (year, month) = year_month.split_at(position);
+    let month = month.strip_prefix('-').ok_or_else(invalid)?;
+
+    Ok(XsGYearMonth {
+      year: lexical::parse_year(year).ok_or_else(invalid)?,
+      month: lexical::parse_month(month).ok_or_else(invalid)?,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn year(&self) -> i64 {
+    self.year
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsGMonthDay {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:gMonthDay");
+
+    let (month_day, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let month_day = month_day.strip_prefix("--").ok_or_else(invalid)?;
+    let (month, day) = month_day.split_once('-').ok_or_else(invalid)?;
+    let month = lexical::parse_month(month).ok_or_else(invalid)?;
+
+    Ok(XsGMonthDay {
+      month,
+      day: lexical::parse_day(day, None, month).ok_or_else(invalid)?,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  pub fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsGDay {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:gDay");
+
+    let (day, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let day = day.strip_prefix("---").ok_or_else(invalid)?;
+
+    Ok(XsGDay {
+      day: lexical::parse_day(day, None, 1).ok_or_else(invalid)?,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn day(&self) -> u8 {
+    self.day
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+impl XsGMonth {
+  fn parse(value: &str) -> Result<Self, String> {
+    let invalid = || format!("{value:?} is not a valid xs:gMonth");
+
+    let (month, timezone) = lexical::split_timezone(value).ok_or_else(invalid)?;
+    let month = month.strip_prefix("--").ok_or_else(invalid)?;
+
+    Ok(XsGMonth {
+      month: lexical::parse_month(month).ok_or_else(invalid)?,
+      timezone,
+      lexical: value.to_string(),
+    })
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  /// Offset from UTC in minutes, `None` when the value has no timezone.
+  pub fn timezone(&self) -> Option<i16> {
+    self.timezone
+  }
+}
+
+implement_lexical_type!(XsGYear, "1970");
+implement_lexical_type!(XsGYearMonth, "1970-01");
+implement_lexical_type!(XsGMonthDay, "--01-01");
+implement_lexical_type!(XsGDay, "---01");
+implement_lexical_type!(XsGMonth, "--01");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn year() {
+    let value: XsGYear = "-0044+01:00".parse().unwrap();
+
+    assert_eq!(value.year(), -44);
+    assert_eq!(value.timezone(), Some(60));
+    assert_eq!(value.to_string(), "-0044+01:00");
+    assert_eq!(XsGYear::default().year(), 1970);
+
+    assert_eq!(
+      "24".parse::<XsGYear>().unwrap_err(),
+      "\"24\" is not a valid xs:gYear"
+    );
+    assert!("02024".parse::<XsGYear>().is_err());
+    assert!("2024-03".parse::<XsGYear>().is_err());
+  }
+
+  #[test]
+  fn year_month() {
+    let value: XsGYearMonth = "2024-03Z".parse().unwrap();
+
+    assert_eq!((value.year(), value.month()), (2024, 3));
+    assert_eq!(value.timezone(), Some(0));
+    assert!("2024-13".parse::<XsGYearMonth>().is_err());
+  }
+
+  #[test]
+  fn month_day() {
+    let value: XsGMonthDay = "--02-29".parse().unwrap();
+
+    assert_eq!((value.month(), value.day()), (2, 29));
+    assert_eq!(value.timezone(), None);
+    assert_eq!(value.to_string(), "--02-29");
+
+    let value: XsGMonthDay = "--12-25+01:00".parse().unwrap();
+    assert_eq!(value.timezone(), Some(60));
+    assert_eq!(value.to_string(), "--12-25+01:00");
+
+    assert_eq!(
+      "--04-31".parse::<XsGMonthDay>().unwrap_err(),
+      "\"--04-31\" is not a valid xs:gMonthDay"
+    );
+  }
+
+  #[test]
+  fn day_and_month() {
+    assert_eq!("---15".parse::<XsGDay>().unwrap().day(), 15);
+    assert!("---32".parse::<XsGDay>().is_err());
+    assert_eq!("--03-05:00".parse::<XsGMonth>().unwrap().month(), 3);
+    assert!("--3".parse::<XsGMonth>().is_err());
+  }
+}
@@ -0,0 +1,209 @@
+use crate::implement_text_type;
+
+/// Integer of the sign-restricted integer types, unbounded with the `big-integer` feature.
+#[cfg(feature = "big-integer")]
+pub type Integer = num_bigint::BigInt;
+
+/// Integer of the sign-restricted integer types, unbounded with the `big-integer` feature.
+#[cfg(not(feature = "big-integer"))]
+pub type Integer = i64;
+
+/// Parse the lexical form of an integer, an optional sign followed by digits.
+fn parse_integer(value: &str) -> Option<Integer> {
+  let digits = value
+    .strip_prefix('-')
+    .or_else(|| value.strip_prefix('+'))
+    .unwrap_or(value);
+  if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  value.parse().ok()
+}
+
+/// `xs:integer` value, unbounded.
+#[cfg(feature = "big-integer")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct XsInteger(Integer);
+
+#[cfg(feature = "big-integer")]
+impl XsInteger {
+  pub fn new(value: Integer) -> Self {
+    XsInteger(value)
+  }
+
+  pub fn value(&self) -> &Integer {
+    &self.0
+  }
+
+  pub fn into_inner(self) -> Integer {
+    self.0
+  }
+}
+
+#[cfg(feature = "big-integer")]
+impl From<Integer> for XsInteger {
+  fn from(value: Integer) -> Self {
+    XsInteger(value)
+  }
+}
+
+#[cfg(feature = "big-integer")]
+impl std::str::FromStr for XsInteger {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    parse_integer(value.trim())
+      .map(XsInteger)
+      .ok_or_else(|| format!("{value:?} is not a valid xs:integer"))
+  }
+}
+
+#[cfg(feature = "big-integer")]
+impl std::fmt::Display for XsInteger {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    self.0.fmt(formatter)
+  }
+}
+
+#[cfg(feature = "big-integer")]
+implement_text_type!(XsInteger);
+
+/// Declare an integer type restricted by its sign, checked on creation.
+macro_rules! implement_sign_restricted_type {
+  ($(#[$attribute:meta])* $type_name:ident, $xs_name:literal, $default:literal, |$value:ident| $check:expr) => {
+    $(#[$attribute])*
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct $type_name(Integer);
+
+    impl $type_name {
+      pub fn new(value: Integer) -> Result<Self, String> {
+        let $value = &value;
+        if !$check {
+          return Err(format!(
+            "{:?} is not a valid {}",
+            value.to_string(),
+            $xs_name
+          ));
+        }
+
+        Ok($type_name(value))
+      }
+
+      pub fn value(&self) -> &Integer {
+        &self.0
+      }
+
+      pub fn into_inner(self) -> Integer {
+        self.0
+      }
+    }
+
+    impl Default for $type_name {
+      fn default() -> Self {
+        $type_name(Integer::from($default))
+      }
+    }
+
+    impl std::str::FromStr for $type_name {
+      type Err = String;
+
+      fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("{:?} is not a valid {}", value, $xs_name);
+
+        parse_integer(value.trim())
+          .ok_or_else(invalid)
+          .and_then(|integer| $type_name::new(integer).map_err(|_| invalid()))
+      }
+    }
+
+    impl std::fmt::Display for $type_name {
+      fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(formatter)
+      }
+    }
+
+    implement_text_type!($type_name);
+  };
+}
+
+implement_sign_restricted_type!(
+  /// `xs:positiveInteger` value, greater than zero.
+  XsPositiveInteger,
+  "xs:positiveInteger",
+  1,
+  |value| *value > Integer::default()
+);
+
+implement_sign_restricted_type!(
+  /// `xs:nonNegativeInteger` value, zero or greater.
+  XsNonNegativeInteger,
+  "xs:nonNegativeInteger",
+  0,
+  |value| *value >= Integer::default()
+);
+
+implement_sign_restricted_type!(
+  /// `xs:negativeInteger` value, lower than zero.
+  XsNegativeInteger,
+  "xs:negativeInteger",
+  -1,
+  |value| *value < Integer::default()
+);
+
+implement_sign_restricted_type!(
+  /// `xs:nonPositiveInteger` value, zero or lower.
+  XsNonPositiveInteger,
+  "xs:nonPositiveInteger",
+  0,
+  |value| *value <= Integer::default()
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sign_restricted_integers() {
+    let value: XsPositiveInteger = " +42 ".parse().unwrap();
+    assert_eq!(value.value(), &Integer::from(42));
+    assert_eq!(value.to_string(), "42");
+    assert_eq!(XsPositiveInteger::default().to_string(), "1");
+
+    assert_eq!(
+      "0".parse::<XsPositiveInteger>().unwrap_err(),
+      "\"0\" is not a valid xs:positiveInteger"
+    );
+    assert_eq!(
+      XsPositiveInteger::new(Integer::from(-3)).unwrap_err(),
+      "\"-3\" is not a valid xs:positiveInteger"
+    );
+
+    assert!("0".parse::<XsNonNegativeInteger>().is_ok());
+    assert!("-1".parse::<XsNonNegativeInteger>().is_err());
+    assert_eq!(XsNegativeInteger::default().to_string(), "-1");
+    assert!("0".parse::<XsNegativeInteger>().is_err());
+    assert!("-0".parse::<XsNonPositiveInteger>().is_ok());
+    assert!("1".parse::<XsNonPositiveInteger>().is_err());
+
+    for invalid in ["", "-", "1.0", "1_000", "1e3", "0x10"] {
+      assert_eq!(
+        invalid.parse::<XsNonNegativeInteger>().unwrap_err(),
+        format!("{invalid:?} is not a valid xs:nonNegativeInteger")
+      );
+    }
+  }
+
+  #[cfg(feature = "big-integer")]
+  #[test]
+  fn unbounded_integers() {
+    let lexical = "-123456789012345678901234567890";
+    let value: XsInteger = lexical.parse().unwrap();
+    assert_eq!(value.to_string(), lexical);
+
+    let value: XsNegativeInteger = lexical.parse().unwrap();
+    assert_eq!(value.to_string(), lexical);
+
+    assert!("1_000".parse::<XsInteger>().is_err());
+  }
+}
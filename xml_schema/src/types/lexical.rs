@@ -0,0 +1,170 @@
+/// Split the timezone at the end of a value, `Z` or `+hh:mm`, as an offset in minutes.
+/// Lexical forms are ASCII, other values are rejected.
+pub fn split_timezone(value: &str) -> Option<(&str, Option<i16>)> {
+  if !value.is_ascii() {
+    return None;
+  }
+
+  if let Some(value) = value.strip_suffix('Z') {
+    return Some((value, Some(0)));
+  }
+
+  let Some(position) = value.len().checked_sub(6) else {
+    return Some((value, None));
+  };
+
+  let (rest, timezone) = value.split_at(position);
+  let bytes = timezone.as_bytes();
+  if !matches!(bytes[0], b'+' | b'-') || bytes[3] != b':' {
+    return Some((value, None));
+  }
+
+  let hours = parse_digits(&timezone[1..3])?;
+  let minutes = parse_digits(&timezone[4..6])?;
+  if hours > 14 || minutes > 59 || (hours == 14 && minutes != 0) {
+    return None;
+  }
+
+  let offset = (hours * 60 + minutes) as i16;
+  Some((rest, Some(if bytes[0] == b'-' { -offset } else { offset })))
+}
+
+/// Parse a year of at least 4 digits, possibly negative, without leading zero when longer.
+pub fn parse_year(value: &str) -> Option<i64> {
+  let digits = value.strip_prefix('-').unwrap_or(value);
+  if digits.len() < 4 || (digits.len() > 4 && digits.starts_with('0')) {
+    return None;
+  }
+  if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  value.parse().ok()
+}
+
+/// Parse a fixed number of digits.
+pub fn parse_digits(value: &str) -> Option<u32> {
+  if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  value.parse().ok()
+}
+
+pub fn parse_month(value: &str) -> Option<u8> {
+  parse_two_digits(value).filter(|month| (1..=12).contains(month))
+}
+
+/// Parse a day of the month, checked with the year when it is known.
+pub fn parse_day(value: &str, year: Option<i64>, month: u8) -> Option<u8> {
+  parse_two_digits(value).filter(|day| *day >= 1 && *day <= days_in_month(year, month))
+}
+
+fn parse_two_digits(value: &str) -> Option<u8> {
+  if value.len() != 2 {
+    return None;
+  }
+
+  parse_digits(value).map(|value| value as u8)
+}
+
+fn days_in_month(year: Option<i64>, month: u8) -> u8 {
+  match month {
+    4 | 6 | 9 | 11 => 30,
+    2 => match year {
+      Some(year) if !(year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)) => 28,
+      _ => 29,
+    },
+    _ => 31,
+  }
+}
+
+/// Parse a date, `yyyy-mm-dd`, into its year, month and day.
+pub fn parse_date(value: &str) -> Option<(i64, u8, u8)> {
+  let position = value.len().checked_sub(6)?;
+  let (year, month_day) = value.split_at(position);
+  let month_day = month_day.strip_prefix('-')?;
+  let (month, day) = month_day.split_once('-')?;
+
+  let year = parse_year(year)?;
+  let month = parse_month(month)?;
+  let day = parse_day(day, Some(year), month)?;
+
+  Some((year, month, day))
+}
+
+/// Parse a time, `hh:mm:ss` with optional fractional seconds, into its hour, minute, second and nanosecond.
+/// `24:00:00` is the end of the day.
+pub fn parse_time(value: &str) -> Option<(u8, u8, u8, u32)> {
+  let mut parts = value.splitn(3, ':');
+  let hour = parse_two_digits(parts.next()?)?;
+  let minute = parse_two_digits(parts.next()?)?;
+  let seconds = parts.next()?;
+
+  let (second, fraction) = match seconds.split_once('.') {
+    Some((second, fraction)) => (second, Some(fraction)),
+    None => (seconds, None),
+  };
+  let second = parse_two_digits(second)?;
+  let nanosecond = fraction.map(parse_nanoseconds).unwrap_or(Some(0))?;
+
+  if minute > 59 || second > 59 {
+    return None;
+  }
+
+  if hour > 24 || (hour == 24 && (minute, second, nanosecond) != (0, 0, 0)) {
+    return None;
+  }
+
+  Some((hour, minute, second, nanosecond))
+}
+
+/// Parse the fractional part of seconds, digits beyond nanoseconds are truncated.
+pub fn parse_nanoseconds(fraction: &str) -> Option<u32> {
+  if fraction.is_empty() || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  let digits: String = fraction
+    .chars()
+    .chain("000000000".chars())
+    .take(9)
+    .collect();
+  digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn timezones() {
+    assert_eq!(split_timezone("12:00:00Z"), Some(("12:00:00", Some(0))));
+    assert_eq!(
+      split_timezone("12:00:00-05:30"),
+      Some(("12:00:00", Some(-330)))
+    );
+    assert_eq!(split_timezone("12:00:00"), Some(("12:00:00", None)));
+    assert_eq!(split_timezone("2024-01-01"), Some(("2024-01-01", None)));
+    assert_eq!(split_timezone("2024-01-01+15:00"), None);
+    assert_eq!(split_timezone("2024-01-01é"), None);
+  }
+
+  #[test]
+  fn dates() {
+    assert_eq!(parse_date("2024-02-29"), Some((2024, 2, 29)));
+    assert_eq!(parse_date("-0044-03-15"), Some((-44, 3, 15)));
+    assert_eq!(parse_date("2023-02-29"), None);
+    assert_eq!(parse_date("02024-01-01"), None);
+    assert_eq!(parse_date("2024-1-01"), None);
+  }
+
+  #[test]
+  fn times() {
+    assert_eq!(parse_time("13:20:00.5"), Some((13, 20, 0, 500_000_000)));
+    assert_eq!(parse_time("24:00:00"), Some((24, 0, 0, 0)));
+    assert_eq!(parse_time("24:00:01"), None);
+    assert_eq!(parse_time("13:60:00"), None);
+    assert_eq!(parse_time("13:20"), None);
+  }
+}
@@ -0,0 +1,70 @@
+use crate::implement_text_type;
+
+/// Values of a list built-in type, `xs:IDREFS`, `xs:NMTOKENS` or `xs:ENTITIES`,
+/// separated by whitespace in their lexical form.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct XsList<T> {
+  pub items: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for XsList<T> {
+  fn from(items: Vec<T>) -> Self {
+    XsList { items }
+  }
+}
+
+impl<T: std::str::FromStr> std::str::FromStr for XsList<T> {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let items = value
+      .split([' ', '\t', '\n', '\r'])
+      .filter(|item| !item.is_empty())
+      .map(|item| {
+        item
+          .parse()
+          .map_err(|_| format!("{item:?} is not a valid item of the list {value:?}"))
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(XsList { items })
+  }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for XsList<T> {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    for (index, item) in self.items.iter().enumerate() {
+      if index > 0 {
+        formatter.write_str(" ")?;
+      }
+      write!(formatter, "{item}")?;
+    }
+
+    Ok(())
+  }
+}
+
+implement_text_type!(impl [T: std::str::FromStr + std::fmt::Display] XsList<T>, "XsList");
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn list() {
+    let value: XsList<String> = " id1\tid2\n\n id3 ".parse().unwrap();
+    assert_eq!(value.items, ["id1", "id2", "id3"]);
+    assert_eq!(value.to_string(), "id1 id2 id3");
+
+    assert!("".parse::<XsList<String>>().unwrap().items.is_empty());
+
+    // only the whitespace of XML separates the items
+    let value: XsList<String> = "a\u{a0}b c".parse().unwrap();
+    assert_eq!(value.items, ["a\u{a0}b", "c"]);
+
+    assert_eq!(
+      "1 x".parse::<XsList<u8>>().unwrap_err(),
+      "\"x\" is not a valid item of the list \"1 x\""
+    );
+  }
+}
@@ -0,0 +1,199 @@
+//! Types of the XML Schema built-in types without an exact Rust equivalent.
+//!
+//! The list built-in types are generated as [`XsList`].
+//!
+//! `xs:normalizedString` is generated as [`XsNormalizedString`], and `xs:token` and the types
+//! derived from it as [`XsToken`], their whitespace being normalized when they are read.
+//!
+//! The date and time types are generated with the `date-time` feature.
+//! Their values keep their lexical form, so they are serialized as they were read,
+//! and the presence of a timezone is preserved.
+//!
+//! `xs:decimal` is generated as [`XsDecimal`] with the `decimal` feature,
+//! and the sign-restricted integer types as range-checked types with the `integer-types` feature,
+//! unbounded with the `big-integer` feature, which also generates `xs:integer` as `XsInteger`.
+//! Without feature, the bounds of `xs:decimal` restrictions are checked with [`compare_decimals`].
+//!
+//! The content matched by `xs:any` and `xs:anyAttribute` wildcards is kept in [`XsAny`]
+//! and [`XsAnyAttributes`] fields, constrained by a generated [`Wildcard`].
+//!
+//! `nillable` elements are generated as [`XsNillable`], or [`XsNillableValue`] for the built-in
+//! types, distinguishing a missing element from an element with `xsi:nil="true"`.
+
+mod any;
+#[cfg(feature = "date-time")]
+mod date_time;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "date-time")]
+mod duration;
+#[cfg(feature = "date-time")]
+mod gregorian;
+#[cfg(feature = "integer-types")]
+mod integer;
+#[cfg(feature = "date-time")]
+mod lexical;
+mod list;
+mod nillable;
+mod number;
+mod string;
+
+pub use any::{
+  NamespaceConstraint, ProcessContents, Wildcard, XmlElement, XmlNode, XsAny, XsAnyAttributes,
+};
+#[cfg(feature = "date-time")]
+pub use date_time::{XsDate, XsDateTime, XsTime};
+#[cfg(feature = "decimal")]
+pub use decimal::XsDecimal;
+#[cfg(feature = "date-time")]
+pub use duration::XsDuration;
+#[cfg(feature = "date-time")]
+pub use gregorian::{XsGDay, XsGMonth, XsGMonthDay, XsGYear, XsGYearMonth};
+#[cfg(feature = "big-integer")]
+pub use integer::XsInteger;
+#[cfg(feature = "integer-types")]
+pub use integer::{
+  Integer, XsNegativeInteger, XsNonNegativeInteger, XsNonPositiveInteger, XsPositiveInteger,
+};
+pub use list::XsList;
+pub use nillable::{XsNillable, XsNillableValue};
+pub use number::compare_decimals;
+pub use string::{XsNormalizedString, XsToken};
+
+/// Implement the traits shared by the date and time types, from their `parse` function.
+#[cfg(feature = "date-time")]
+macro_rules! implement_lexical_type {
+  ($type_name:ident, $default:expr) => {
+    impl $type_name {
+      /// Lexical form of the value.
+      pub fn as_str(&self) -> &str {
+        &self.lexical
+      }
+    }
+
+    impl Default for $type_name {
+      fn default() -> Self {
+        $type_name::parse($default).unwrap()
+      }
+    }
+
+    impl std::str::FromStr for $type_name {
+      type Err = String;
+
+      fn from_str(value: &str) -> Result<Self, Self::Err> {
+        $type_name::parse(value.trim())
+      }
+    }
+
+    impl std::fmt::Display for $type_name {
+      fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(&self.lexical)
+      }
+    }
+
+    $crate::implement_text_type!($type_name);
+  };
+}
+
+/// Implement `YaDeserialize` and `YaSerialize` for a type read and written as text content,
+/// from its `FromStr` (with a `String` error) and `Display` implementations.
+///
+/// It plugs a domain type in the generated code with a type mapping:
+///
+/// ```
+/// #[derive(Debug, PartialEq)]
+/// pub struct Money(i64);
+///
+/// impl std::str::FromStr for Money {
+///   type Err = String;
+///
+///   fn from_str(value: &str) -> Result<Self, Self::Err> {
+///     value.trim().parse().map(Money).map_err(|e| format!("{e}"))
+///   }
+/// }
+///
+/// impl std::fmt::Display for Money {
+///   fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+///     write!(formatter, "{}", self.0)
+///   }
+/// }
+///
+/// xml_schema::implement_text_type!(Money);
+///
+/// assert_eq!(yaserde::de::from_str::<Money>("<Money>12</Money>"), Ok(Money(12)));
+/// ```
+#[macro_export]
+macro_rules! implement_text_type {
+  ($type_name:ident) => {
+    $crate::implement_text_type!(impl [] $type_name, stringify!($type_name));
+  };
+  (impl [$($generics:tt)*] $type_name:ty, $label:expr) => {
+    impl<$($generics)*> yaserde::YaDeserialize for $type_name {
+      fn deserialize<R: std::io::Read>(
+        reader: &mut yaserde::de::Deserializer<R>,
+      ) -> Result<Self, String> {
+        loop {
+          match reader.peek()?.to_owned() {
+            xml::reader::XmlEvent::StartElement { .. } => {
+              reader.next_event()?;
+            }
+            xml::reader::XmlEvent::Characters(ref text_content) => {
+              reader.next_event()?;
+              return text_content.parse();
+            }
+            _ => break,
+          }
+        }
+
+        "".parse()
+      }
+    }
+
+    impl<$($generics)*> yaserde::YaSerialize for $type_name {
+      fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut yaserde::ser::Serializer<W>,
+      ) -> Result<(), String> {
+        let skip_start_end = writer.skip_start_end();
+
+        if !skip_start_end {
+          let label = writer
+            .get_start_event_name()
+            .unwrap_or_else(|| $label.to_string());
+          writer
+            .write(xml::writer::XmlEvent::start_element(label.as_str()))
+            .map_err(|e| e.to_string())?;
+        }
+
+        writer
+          .write(xml::writer::XmlEvent::characters(&self.to_string()))
+          .map_err(|e| e.to_string())?;
+
+        if !skip_start_end {
+          writer
+            .write(xml::writer::XmlEvent::end_element())
+            .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+      }
+
+      fn serialize_attributes(
+        &self,
+        source_attributes: Vec<xml::attribute::OwnedAttribute>,
+        source_namespace: xml::namespace::Namespace,
+      ) -> Result<
+        (
+          Vec<xml::attribute::OwnedAttribute>,
+          xml::namespace::Namespace,
+        ),
+        String,
+      > {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  };
+}
+
+#[cfg(feature = "date-time")]
+pub(crate) use implement_lexical_type;
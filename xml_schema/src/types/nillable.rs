@@ -0,0 +1,265 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use xml::attribute::OwnedAttribute;
+use xml::name::{Name, OwnedName};
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent as ReaderEvent;
+use xml::writer::XmlEvent as WriterEvent;
+use yaserde::de::Deserializer;
+use yaserde::ser::Serializer;
+use yaserde::{YaDeserialize, YaSerialize};
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Content of a `nillable` element, whose content is read by its own type.
+///
+/// An element with `xsi:nil="true"` is `Nil`, and a missing element is `Absent`,
+/// which is not serialized.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum XsNillable<T> {
+  #[default]
+  Absent,
+  Nil,
+  Value(T),
+}
+
+/// Value of a `nillable` element of a built-in type, read from the text of the element.
+///
+/// An element with `xsi:nil="true"` is `Nil`, and a missing element is `Absent`,
+/// which is not serialized.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum XsNillableValue<T> {
+  #[default]
+  Absent,
+  Nil,
+  Value(T),
+}
+
+macro_rules! implement_nillable {
+  ($type_name:ident) => {
+    impl<T> $type_name<T> {
+      pub fn is_nil(&self) -> bool {
+        matches!(self, $type_name::Nil)
+      }
+
+      /// Value of the element, neither absent nor nil.
+      pub fn value(&self) -> Option<&T> {
+        match self {
+          $type_name::Value(value) => Some(value),
+          _ => None,
+        }
+      }
+    }
+
+    impl<T> From<T> for $type_name<T> {
+      fn from(value: T) -> Self {
+        $type_name::Value(value)
+      }
+    }
+  };
+}
+
+implement_nillable!(XsNillable);
+implement_nillable!(XsNillableValue);
+
+impl<T: YaDeserialize> YaDeserialize for XsNillable<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    if read_nil(reader)? {
+      return Ok(XsNillable::Nil);
+    }
+
+    T::deserialize(reader).map(XsNillable::Value)
+  }
+}
+
+impl<T: YaSerialize> YaSerialize for XsNillable<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    match self {
+      XsNillable::Absent => Ok(()),
+      XsNillable::Nil => write_nil(writer),
+      XsNillable::Value(value) => value.serialize(writer),
+    }
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<OwnedAttribute>,
+    source_namespace: Namespace,
+  ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+impl<T> YaDeserialize for XsNillableValue<T>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    if read_nil(reader)? {
+      return Ok(XsNillableValue::Nil);
+    }
+
+    if let ReaderEvent::StartElement { .. } = reader.peek()? {
+      reader.next_event()?;
+    }
+
+    let text_content = match reader.peek()? {
+      ReaderEvent::Characters(text_content) => text_content.clone(),
+      _ => String::new(),
+    };
+
+    let value = text_content
+      .parse()
+      .map(XsNillableValue::Value)
+      .map_err(|e| format!("Invalid value {text_content:?}: {e}"))?;
+
+    if let ReaderEvent::Characters(_) = reader.peek()? {
+      reader.next_event()?;
+    }
+
+    Ok(value)
+  }
+}
+
+impl<T: Display> YaSerialize for XsNillableValue<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    let value = match self {
+      XsNillableValue::Absent => return Ok(()),
+      XsNillableValue::Nil => return write_nil(writer),
+      XsNillableValue::Value(value) => value,
+    };
+
+    let skip_start_end = writer.skip_start_end();
+
+    if !skip_start_end {
+      let label = writer
+        .get_start_event_name()
+        .ok_or("Missing the name of the nillable element")?;
+      writer
+        .write(WriterEvent::start_element(label.as_str()))
+        .map_err(|e| e.to_string())?;
+    }
+
+    writer
+      .write(WriterEvent::characters(&value.to_string()))
+      .map_err(|e| e.to_string())?;
+
+    if !skip_start_end {
+      writer
+        .write(WriterEvent::end_element())
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    source_attributes: Vec<OwnedAttribute>,
+    source_namespace: Namespace,
+  ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+    Ok((source_attributes, source_namespace))
+  }
+}
+
+/// Read the start of a nil element, its end is read by the parent structure.
+fn read_nil<R: Read>(reader: &mut Deserializer<R>) -> Result<bool, String> {
+  let is_nil = match reader.peek()? {
+    ReaderEvent::StartElement { attributes, .. } => attributes.iter().any(|attribute| {
+      attribute.name.namespace.as_deref() == Some(XSI_NAMESPACE)
+        && attribute.name.local_name == "nil"
+        && matches!(attribute.value.trim(), "true" | "1")
+    }),
+    _ => false,
+  };
+
+  if is_nil {
+    reader.next_event()?;
+
+    // a nil element has no content
+    while !matches!(
+      reader.peek()?,
+      ReaderEvent::EndElement { .. } | ReaderEvent::EndDocument
+    ) {
+      reader.next_event()?;
+    }
+  }
+
+  Ok(is_nil)
+}
+
+/// Write an empty element with `xsi:nil="true"`, declaring the `xsi` prefix.
+fn write_nil<W: Write>(writer: &mut Serializer<W>) -> Result<(), String> {
+  if writer.skip_start_end() {
+    return Ok(());
+  }
+
+  let label = writer
+    .get_start_event_name()
+    .ok_or("Missing the name of the nillable element")?;
+
+  let nil = OwnedAttribute::new(
+    OwnedName::qualified("nil", XSI_NAMESPACE, Some("xsi")),
+    "true",
+  );
+
+  let mut namespace = Namespace::empty();
+  namespace.put("xsi", XSI_NAMESPACE);
+
+  writer
+    .write(WriterEvent::StartElement {
+      name: Name::from(label.as_str()),
+      attributes: Cow::Owned(vec![nil.borrow()]),
+      namespace: Cow::Owned(namespace),
+    })
+    .map_err(|e| e.to_string())?;
+
+  writer
+    .write(WriterEvent::end_element())
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(
+    Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize,
+  )]
+  #[yaserde(rename = "person")]
+  struct Person {
+    #[yaserde(rename = "age")]
+    age: XsNillableValue<u8>,
+    #[yaserde(rename = "nickname")]
+    nickname: XsNillableValue<String>,
+    #[yaserde(rename = "email")]
+    email: XsNillableValue<String>,
+  }
+
+  #[test]
+  fn nillable_value() {
+    let xml = r#"<person xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><age>42</age><nickname xsi:nil="true" /></person>"#;
+
+    let person: Person = yaserde::de::from_str(xml).unwrap();
+    assert_eq!(
+      person,
+      Person {
+        age: XsNillableValue::Value(42),
+        nickname: XsNillableValue::Nil,
+        email: XsNillableValue::Absent,
+      }
+    );
+    assert!(person.nickname.is_nil());
+    assert_eq!(person.age.value(), Some(&42));
+
+    assert_eq!(
+      yaserde::ser::to_string(&person).unwrap(),
+      r#"<?xml version="1.0" encoding="UTF-8"?><person><age>42</age><nickname xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /></person>"#
+    );
+
+    assert!(yaserde::de::from_str::<Person>("<person><age>old</age></person>").is_err());
+  }
+}
@@ -0,0 +1,78 @@
+use std::cmp::Ordering;
+
+/// Compare the values of two decimal numbers in their lexical form (`-12.50`, `+3`, `.5`…),
+/// without limit of digits nor rounding. `None` if one of them is not a decimal number.
+///
+/// It checks the bounds of restrictions of `xs:decimal` generated as `String`.
+pub fn compare_decimals(left: &str, right: &str) -> Option<Ordering> {
+  let (left_negative, left_integer, left_fraction) = split_decimal(left)?;
+  let (right_negative, right_integer, right_fraction) = split_decimal(right)?;
+
+  let ordering = match (left_negative, right_negative) {
+    (false, true) => Ordering::Greater,
+    (true, false) => Ordering::Less,
+    (negative, _) => {
+      let ordering = left_integer
+        .len()
+        .cmp(&right_integer.len())
+        .then_with(|| left_integer.cmp(right_integer))
+        .then_with(|| left_fraction.cmp(right_fraction));
+
+      if negative {
+        ordering.reverse()
+      } else {
+        ordering
+      }
+    }
+  };
+
+  Some(ordering)
+}
+
+/// Sign, integer digits without leading zeros and fraction digits without trailing zeros.
+/// Zero is positive, whatever its sign.
+fn split_decimal(value: &str) -> Option<(bool, &str, &str)> {
+  let value = value.trim();
+  let (negative, digits) = match value.strip_prefix('-') {
+    Some(digits) => (true, digits),
+    None => (false, value.strip_prefix('+').unwrap_or(value)),
+  };
+
+  let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+  if integer.is_empty() && fraction.is_empty()
+    || !integer
+      .chars()
+      .chain(fraction.chars())
+      .all(|character| character.is_ascii_digit())
+  {
+    return None;
+  }
+
+  let integer = integer.trim_start_matches('0');
+  let fraction = fraction.trim_end_matches('0');
+  let negative = negative && !(integer.is_empty() && fraction.is_empty());
+
+  Some((negative, integer, fraction))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decimal_order() {
+    assert_eq!(compare_decimals("10", "9.99"), Some(Ordering::Greater));
+    assert_eq!(compare_decimals("-0.0", "+0"), Some(Ordering::Equal));
+    assert_eq!(compare_decimals("-2.5", "-2.45"), Some(Ordering::Less));
+    assert_eq!(compare_decimals(".5", "0.50"), Some(Ordering::Equal));
+    assert_eq!(
+      compare_decimals(
+        "12345678901234567890.000000000000000001",
+        "12345678901234567890"
+      ),
+      Some(Ordering::Greater)
+    );
+    assert_eq!(compare_decimals("1e3", "1"), None);
+    assert_eq!(compare_decimals(".", "1"), None);
+  }
+}
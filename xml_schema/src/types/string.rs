@@ -0,0 +1,126 @@
+use crate::implement_text_type;
+
+/// Implement the traits of a string type whose whitespace is normalized when it is built.
+macro_rules! implement_normalized_string {
+  ($type_name:ident) => {
+    impl $type_name {
+      /// Normalized value.
+      pub fn as_str(&self) -> &str {
+        &self.0
+      }
+    }
+
+    impl std::ops::Deref for $type_name {
+      type Target = str;
+
+      fn deref(&self) -> &str {
+        &self.0
+      }
+    }
+
+    impl AsRef<str> for $type_name {
+      fn as_ref(&self) -> &str {
+        &self.0
+      }
+    }
+
+    impl From<&str> for $type_name {
+      fn from(value: &str) -> Self {
+        $type_name::new(value)
+      }
+    }
+
+    impl From<String> for $type_name {
+      fn from(value: String) -> Self {
+        $type_name::new(&value)
+      }
+    }
+
+    impl From<$type_name> for String {
+      fn from(value: $type_name) -> Self {
+        value.0
+      }
+    }
+
+    impl PartialEq<str> for $type_name {
+      fn eq(&self, other: &str) -> bool {
+        self.0 == other
+      }
+    }
+
+    impl PartialEq<&str> for $type_name {
+      fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+      }
+    }
+
+    impl std::str::FromStr for $type_name {
+      type Err = String;
+
+      fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok($type_name::new(value))
+      }
+    }
+
+    impl std::fmt::Display for $type_name {
+      fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(&self.0)
+      }
+    }
+
+    implement_text_type!($type_name);
+  };
+}
+
+/// Value of `xs:normalizedString`, tabs, line feeds and carriage returns are replaced with spaces.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct XsNormalizedString(String);
+
+impl XsNormalizedString {
+  pub fn new(value: &str) -> Self {
+    XsNormalizedString(value.replace(['\t', '\n', '\r'], " "))
+  }
+}
+
+implement_normalized_string!(XsNormalizedString);
+
+/// Value of `xs:token` and of the types derived from it, like `xs:NCName` or `xs:language`.
+/// Sequences of whitespace are collapsed to one space, leading and trailing whitespace is removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct XsToken(String);
+
+impl XsToken {
+  pub fn new(value: &str) -> Self {
+    XsToken(
+      value
+        .split([' ', '\t', '\n', '\r'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" "),
+    )
+  }
+}
+
+implement_normalized_string!(XsToken);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn normalized_string() {
+    let value: XsNormalizedString = " a\tb\r\n c ".parse().unwrap();
+    assert_eq!(value, " a b   c ");
+  }
+
+  #[test]
+  fn token() {
+    let value: XsToken = " a\tb\r\n c ".parse().unwrap();
+    assert_eq!(value, "a b c");
+    assert_eq!(value.to_string(), "a b c");
+
+    // only the whitespace of XML is collapsed
+    assert_eq!(XsToken::from("a\u{a0} b"), "a\u{a0} b");
+    assert_eq!(String::from(XsToken::new("  en ")), "en");
+  }
+}
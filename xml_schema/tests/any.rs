@@ -0,0 +1,53 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn any() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/any.xsd")]
+  struct AnySchema;
+
+  let xml_1 = r#"<Header xmlns:ext="http://extension.com" version="2" ext:trace="abc"><id>1</id><ext:signature algorithm="rsa"><ext:value>xyz</ext:value></ext:signature></Header>"#;
+
+  let sample_1: xml_schema_types::Header = from_str(xml_1).unwrap();
+  assert_eq!(sample_1.id, "1");
+  assert_eq!(sample_1.version, Some("2".to_string()));
+  assert_eq!(sample_1.any_attributes.len(), 1);
+  assert_eq!(
+    sample_1
+      .any_attributes
+      .get(Some("http://extension.com"), "trace"),
+    Some("abc")
+  );
+
+  let signature = &sample_1.any.elements[0];
+  assert_eq!(signature.name.local_name, "signature");
+  assert_eq!(signature.attribute(None, "algorithm"), Some("rsa"));
+  assert_eq!(signature.elements().next().unwrap().text(), "xyz");
+
+  let data = to_string(&sample_1).unwrap();
+  assert!(data.ends_with(xml_1));
+
+  // unqualified elements are not matched by ##other
+  let xml_2 = r#"<Header><id>1</id><unknown>2</unknown></Header>"#;
+  let sample_2: xml_schema_types::Header = from_str(xml_2).unwrap();
+  assert!(sample_2.any.elements.is_empty());
+
+  // processContents is strict by default, only the top-level elements are matched
+  let xml_3 = r#"<Annotated><title>Rust</title><note><author>Ferris</author></note></Annotated>"#;
+  let sample_3: xml_schema_types::Annotated = from_str(xml_3).unwrap();
+  assert_eq!(
+    sample_3.any.elements[0].deserialize_as::<Note>(),
+    Ok(Note {
+      author: "Ferris".to_string()
+    })
+  );
+  assert!(to_string(&sample_3).unwrap().ends_with(xml_3));
+
+  let xml_4 = r#"<Annotated><title>Rust</title><comment>Second edition</comment></Annotated>"#;
+  assert_eq!(
+    from_str::<xml_schema_types::Annotated>(xml_4).unwrap_err(),
+    "comment is not declared, as required by processContents=\"strict\""
+  );
+}
@@ -0,0 +1,79 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn attribute_group() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/attribute_group.xsd")]
+  struct AttributeGroupSchema;
+
+  let xml_1 = r#"
+  <Paragraph id="intro" class="lead" lang="fr" align="left">
+    <text>Bonjour</text>
+  </Paragraph>
+  "#;
+
+  let sample_1: xml_schema_types::Paragraph = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Paragraph {
+    text: "Bonjour".to_string(),
+    align: Some("left".to_string()),
+    id: Some("intro".into()),
+    class: Some("lead".to_string()),
+    lang: Some("fr".into()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  let sample_2: xml_schema_types::Paragraph = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+
+  let xml_2 = r#"<Span id="name" class="bold">Victor Hugo</Span>"#;
+
+  let sample_3: xml_schema_types::Span = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Span {
+    base: "Victor Hugo".to_string(),
+    id: Some("name".into()),
+    class: Some("bold".to_string()),
+  };
+
+  assert_eq!(sample_3, model);
+}
+
+#[test]
+fn anonymous_complex_type_attributes() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/attribute_group.xsd")]
+  struct AttributeGroupSchema;
+
+  let xml_1 = r#"
+  <section id="s1" level="2" status="draft">
+    <title>Introduction</title>
+    <note lang="en" status="final"><text>Reviewed</text></note>
+  </section>
+  "#;
+
+  let sample_1: Section = from_str(xml_1).unwrap();
+
+  let model = Section {
+    title: "Introduction".to_string(),
+    note: Some(Note {
+      text: "Reviewed".to_string(),
+      lang: Some("en".into()),
+      status: xml_schema_types::StatusType::Final,
+    }),
+    id: Some("s1".into()),
+    class: None,
+    level: 2,
+    status: Some(xml_schema_types::StatusType::Draft),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert!(data.contains(r#"<note status="final" lang="en"><text>Reviewed</text></note>"#));
+  assert_eq!(from_str::<Section>(&data).unwrap(), model);
+}
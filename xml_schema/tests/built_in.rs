@@ -0,0 +1,45 @@
+use xml_schema::types::XsList;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn built_in_types() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/built_in.xsd")]
+  struct BuiltInSchema;
+
+  let xml_1 = "<Document id=\" doc1\n\" status=\" in   review \" keywords=\"xml  schema\trust\" type=\"xs:string\">\
+    <name>\n  report\n</name><references>doc2\n  doc3</references><code>  A  B </code><line>first\tsecond</line>\
+    </Document>";
+
+  let sample_1: xml_schema_types::Document = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Document {
+    name: "report".into(),
+    references: XsList::from(vec!["doc2".to_string(), "doc3".to_string()]),
+    code: Some("A B".parse().unwrap()),
+    line: Some("first second".parse().unwrap()),
+    id: Some("doc1".into()),
+    status: Some(xml_schema_types::Status::InReview),
+    keywords: Some("xml schema rust".parse::<XsList<String>>().unwrap()),
+    kind: Some("xs:string".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(sample_1.name.as_str(), "report");
+  assert_eq!(sample_1.code.as_ref().unwrap().value(), "A B");
+  assert_eq!(
+    sample_1.keywords.as_ref().unwrap().items,
+    ["xml", "schema", "rust"]
+  );
+
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(
+    r#"<Document id="doc1" status="in review" keywords="xml schema rust" type="xs:string"><name>report</name><references>doc2 doc3</references><code>A B</code><line>first second</line></Document>"#
+  ));
+
+  // the facets are checked on the normalized value
+  assert!("  ABCDE  ".parse::<xml_schema_types::Code>().is_ok());
+  assert!("ABC DEF".parse::<xml_schema_types::Code>().is_err());
+}
@@ -0,0 +1,46 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn catalog() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/catalog.xsd",
+    target_prefix = "shop",
+    xml_catalog = "tests/catalog.xml",
+    catalog("http://example.com/address" = "tests/catalog_address.xsd"),
+    offline
+  )]
+  struct CatalogSchema;
+
+  let xml = r#"
+  <shop:Order xmlns:shop="http://example.com/shop" xmlns:person="http://example.com/person" xmlns:addr="http://example.com/address">
+    <shop:customer>
+      <person:name>Victor Hugo</person:name>
+    </shop:customer>
+    <shop:shipTo>
+      <addr:city>Paris</addr:city>
+    </shop:shipTo>
+  </shop:Order>
+  "#;
+
+  let order: xml_schema_types::Order = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Order {
+    customer: xml_schema_types::person::Person {
+      name: "Victor Hugo".to_string(),
+      favorite_book: None,
+      lib_library: None,
+    },
+    ship_to: xml_schema_types::address::Address {
+      city: "Paris".to_string(),
+    },
+  };
+
+  assert_eq!(order, model);
+
+  let data = to_string(&model).unwrap();
+  let sample: xml_schema_types::Order = from_str(&data).unwrap();
+  assert_eq!(sample, model);
+}
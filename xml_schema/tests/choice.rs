@@ -0,0 +1,167 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn choice() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml_1 = r#"
+  <Contact>
+    <phone country="fr">
+      <number>0123456789</number>
+    </phone>
+  </Contact>
+  "#;
+
+  let sample_1: xml_schema_types::Contact = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Contact {
+    choice: xml_schema_types::ContactChoice::Phone(xml_schema_types::PhoneType {
+      number: "0123456789".to_string(),
+      country: "fr".to_string(),
+    }),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Contact><phone country="fr"><number>0123456789</number></phone></Contact>"#
+  );
+
+  let xml_2 = r#"<Contact><email>contact@example.com</email></Contact>"#;
+
+  let sample_2: xml_schema_types::Contact = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.choice,
+    xml_schema_types::ContactChoice::Email("contact@example.com".to_string())
+  );
+}
+
+#[test]
+fn choice_in_sequence() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml_1 = r#"
+  <Message>
+    <subject>Hello</subject>
+    <text>First</text>
+    <priority>2</priority>
+    <contact><email>contact@example.com</email></contact>
+    <text>Second</text>
+    <anonymous>true</anonymous>
+  </Message>
+  "#;
+
+  let sample_1: xml_schema_types::Message = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Message {
+    subject: "Hello".to_string(),
+    choice_1_list: xml_schema_types::MessageChoice1List {
+      items: vec![
+        xml_schema_types::MessageChoice1::Text("First".to_string()),
+        xml_schema_types::MessageChoice1::Priority(2),
+        xml_schema_types::MessageChoice1::Contact(xml_schema_types::Contact {
+          choice: xml_schema_types::ContactChoice::Email("contact@example.com".to_string()),
+        }),
+        xml_schema_types::MessageChoice1::Text("Second".to_string()),
+      ],
+    },
+    choice_2: Some(xml_schema_types::MessageChoice2::Anonymous(true)),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Message><subject>Hello</subject><text>First</text><priority>2</priority><contact><email>contact@example.com</email></contact><text>Second</text><anonymous>true</anonymous></Message>"#
+  );
+
+  let xml_2 = r#"<Message><subject>Hello</subject></Message>"#;
+
+  let sample_2: xml_schema_types::Message = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2,
+    xml_schema_types::Message {
+      subject: "Hello".to_string(),
+      choice_1_list: xml_schema_types::MessageChoice1List { items: vec![] },
+      choice_2: None,
+    }
+  );
+}
+
+#[test]
+fn choice_between_elements() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Letter><sender>Ada</sender><address>12 Main Street</address><body>Hello</body></Letter>"#;
+
+  let sample: xml_schema_types::Letter = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Letter {
+    sender: "Ada".to_string(),
+    choice: xml_schema_types::LetterChoice::Address("12 Main Street".to_string()),
+    body: "Hello".to_string(),
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
+
+#[test]
+fn nested_model_groups_in_choice() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Recipient><firstName>Ada</firstName><lastName>Lovelace</lastName><email>ada@example.com</email><telex>1234</telex><street>12 Main Street</street><city>London</city><firstName>Charles</firstName><lastName>Babbage</lastName><x:note xmlns:x="http://example.com/extension">Friend</x:note></Recipient>"#;
+
+  let sample: xml_schema_types::Recipient = from_str(xml).unwrap();
+
+  let note = sample.choice_list.items[5].clone();
+  match &note {
+    xml_schema_types::RecipientChoice::Any(any) => assert_eq!(any.elements[0].text(), "Friend"),
+    _ => panic!(
+      "Expected the element matched by the wildcard, found {:?}",
+      note
+    ),
+  }
+
+  let model = xml_schema_types::Recipient {
+    choice_list: xml_schema_types::RecipientChoiceList {
+      items: vec![
+        xml_schema_types::RecipientChoice::Sequence(xml_schema_types::RecipientChoiceSequence {
+          first_name: "Ada".to_string(),
+          last_name: "Lovelace".to_string(),
+        }),
+        xml_schema_types::RecipientChoice::Email("ada@example.com".to_string()),
+        xml_schema_types::RecipientChoice::Choice(xml_schema_types::RecipientChoiceChoice {
+          choice: xml_schema_types::RecipientChoiceChoiceChoice::Telex("1234".to_string()),
+        }),
+        xml_schema_types::RecipientChoice::PostalAddress(xml_schema_types::PostalAddress {
+          street: "12 Main Street".to_string(),
+          city: "London".to_string(),
+        }),
+        xml_schema_types::RecipientChoice::Sequence(xml_schema_types::RecipientChoiceSequence {
+          first_name: "Charles".to_string(),
+          last_name: "Babbage".to_string(),
+        }),
+        note,
+      ],
+    },
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
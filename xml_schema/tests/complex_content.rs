@@ -0,0 +1,98 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn complex_content_extension() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  let xml_1 =
+    r#"<Employee role="developer" id="7"><name>Ada</name><company>Example</company></Employee>"#;
+
+  let sample_1: xml_schema_types::Employee = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Employee {
+    base: xml_schema_types::Person {
+      name: "Ada".to_string(),
+      id: Some("7".to_string()),
+      nickname: None,
+    },
+    company: "Example".to_string(),
+    role: Some("developer".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_1));
+
+  let xml_2 = r#"<Manager id="1"><name>Grace</name><company>Example</company><team>Compilers</team></Manager>"#;
+
+  let sample_2: xml_schema_types::Manager = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Manager {
+    base: xml_schema_types::Employee {
+      base: xml_schema_types::Person {
+        name: "Grace".to_string(),
+        id: Some("1".to_string()),
+        nickname: None,
+      },
+      company: "Example".to_string(),
+      role: None,
+    },
+    choice: xml_schema_types::ManagerChoice::Team("Compilers".to_string()),
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_2));
+}
+
+#[test]
+fn complex_content_restriction() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  let xml_1 = r#"<Visitor id="3"><name>Alan</name></Visitor>"#;
+
+  let sample_1: xml_schema_types::Visitor = from_str(xml_1).unwrap();
+
+  // the attributes of the base type are kept, unless prohibited
+  let model = xml_schema_types::Visitor {
+    name: "Alan".to_string(),
+    id: Some("3".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+  assert!(to_string(&model).unwrap().ends_with(xml_1));
+
+  // attributes inherited through an extension, redeclared or prohibited by the restriction
+  let xml_3 = r#"<Contractor id="9"><name>Edsger</name><company>Example</company></Contractor>"#;
+
+  let sample_3: xml_schema_types::Contractor = from_str(xml_3).unwrap();
+
+  let model = xml_schema_types::Contractor {
+    name: "Edsger".to_string(),
+    company: "Example".to_string(),
+    nickname: None,
+    id: "9".to_string(),
+  };
+
+  assert_eq!(sample_3, model);
+  assert!(to_string(&model).unwrap().ends_with(xml_3));
+
+  let xml_2 = r#"<Contact><email>contact@example.com</email></Contact>"#;
+
+  let sample_2: xml_schema_types::Contact = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2,
+    xml_schema_types::Contact {
+      email: "contact@example.com".to_string(),
+    }
+  );
+}
@@ -5,7 +5,7 @@ use yaserde::ser::to_string;
 #[test]
 fn complex_type_string() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(source = "xml_schema/tests/complex_type.xsd")]
+  #[xml_schema(source = "tests/complex_type.xsd")]
   struct ComplexTypeSchema;
 
   let xml_1 = r#"
@@ -30,3 +30,37 @@ fn complex_type_string() {
     r#"<?xml version="1.0" encoding="utf-8"?><ComplexListOfElements><Annotation>Test content</Annotation><Label>Label content</Label></ComplexListOfElements>"#
   );
 }
+
+#[test]
+fn complex_type_simple_content() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/complex_type_simple_content.xsd")]
+  struct ComplexTypeSchema;
+
+  let xml_1 = r#"<amount unit="kg">12</amount>"#;
+
+  let sample_1: Amount = from_str(xml_1).unwrap();
+
+  let model = Amount {
+    content: xml_schema_types::Amount {
+      base: xml_schema_types::Quantity {
+        content: "12".to_string(),
+      },
+      unit: Some("kg".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  let sample_2: Amount = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+
+  let label: Label = from_str("<label>Label content</label>").unwrap();
+  assert_eq!(
+    label,
+    Label {
+      content: "Label content".to_string()
+    }
+  );
+}
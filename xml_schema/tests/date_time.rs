@@ -0,0 +1,45 @@
+#![cfg(feature = "date-time")]
+
+use xml_schema::types::{
+  XsDate, XsDateTime, XsDuration, XsGMonthDay, XsGYear, XsGYearMonth, XsTime,
+};
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn date_time() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/date_time.xsd")]
+  struct DateTimeSchema;
+
+  let xml_1 = r#"<Event anniversary="--12-25" month="2024-03Z" year="-0044"><start>2024-03-15T13:20:00.50+01:00</start><day>2024-03-15</day><time>13:20:00</time><length>PT1H30M</length></Event>"#;
+
+  let sample_1: xml_schema_types::Event = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Event {
+    start: "2024-03-15T13:20:00.50+01:00"
+      .parse::<XsDateTime>()
+      .unwrap(),
+    day: "2024-03-15".parse::<XsDate>().unwrap(),
+    time: Some("13:20:00".parse::<XsTime>().unwrap()),
+    length: "PT1H30M".parse::<XsDuration>().unwrap(),
+    anniversary: Some("--12-25".parse::<XsGMonthDay>().unwrap()),
+    month: Some("2024-03Z".parse::<XsGYearMonth>().unwrap()),
+    year: Some("-0044".parse::<XsGYear>().unwrap()),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(sample_1.start.timezone(), Some(60));
+  assert_eq!(sample_1.day.timezone(), None);
+  assert_eq!(sample_1.length.minutes(), 30);
+  assert_eq!(sample_1.year.as_ref().map(XsGYear::year), Some(-44));
+
+  // the lexical forms are kept
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_1));
+
+  let xml_2 =
+    r#"<Event><start>2024-03-15</start><day>2024-03-15</day><length>PT1H</length></Event>"#;
+  assert!(from_str::<xml_schema_types::Event>(xml_2).is_err());
+}
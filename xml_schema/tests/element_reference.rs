@@ -0,0 +1,116 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn element_reference() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/element_reference.xsd")]
+  struct ElementReferenceSchema;
+
+  let xml_1 = r#"
+  <Letter>
+    <title>Greetings</title>
+    <note><text>Hello</text></note>
+    <note><text>World</text></note>
+    <stamp><date>2024-01-01</date></stamp>
+  </Letter>
+  "#;
+
+  let sample_1: xml_schema_types::Letter = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Letter {
+    title: "Greetings".to_string(),
+    note_list: vec![
+      xml_schema_types::NoteContent {
+        text: "Hello".to_string(),
+      },
+      xml_schema_types::NoteContent {
+        text: "World".to_string(),
+      },
+    ],
+    stamp: Some(Stamp {
+      date: "2024-01-01".to_string(),
+    }),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Letter><title>Greetings</title><note><text>Hello</text></note><note><text>World</text></note><stamp><date>2024-01-01</date></stamp></Letter>"#
+  );
+
+  assert_eq!(from_str::<xml_schema_types::Letter>(&data).unwrap(), model);
+}
+
+#[test]
+fn element_reference_in_choice() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/element_reference.xsd")]
+  struct ElementReferenceSchema;
+
+  let documents = [
+    (
+      r#"<?xml version="1.0" encoding="UTF-8"?><Envelope><title>Greetings</title></Envelope>"#,
+      xml_schema_types::EnvelopeChoice::Title("Greetings".to_string()),
+    ),
+    (
+      r#"<?xml version="1.0" encoding="UTF-8"?><Envelope><note><text>Hello</text></note></Envelope>"#,
+      xml_schema_types::EnvelopeChoice::Note(xml_schema_types::NoteContent {
+        text: "Hello".to_string(),
+      }),
+    ),
+    (
+      r#"<?xml version="1.0" encoding="UTF-8"?><Envelope><stamp><date>2024-01-01</date></stamp></Envelope>"#,
+      xml_schema_types::EnvelopeChoice::Stamp(Stamp {
+        date: "2024-01-01".to_string(),
+      }),
+    ),
+  ];
+
+  for (xml, choice) in documents {
+    let model = xml_schema_types::Envelope { choice };
+
+    assert_eq!(from_str::<xml_schema_types::Envelope>(xml).unwrap(), model);
+    assert_eq!(to_string(&model).unwrap(), xml);
+  }
+}
+
+#[test]
+fn element_reference_to_another_namespace() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/element_reference_import.xsd", target_prefix = "m")]
+  struct ElementReferenceImportSchema;
+
+  let xml_1 = r#"
+  <m:Order xmlns:m="http://example.com/order" xmlns:c="http://example.com/customer">
+    <m:id>1</m:id>
+    <c:Address>
+      <c:street>1 Main Street</c:street>
+    </c:Address>
+    <c:Email>contact@example.com</c:Email>
+  </m:Order>
+  "#;
+
+  let sample_1: xml_schema_types::Order = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Order {
+    id: "1".to_string(),
+    c_address: xml_schema_types::customer::AddressType {
+      street: "1 Main Street".to_string(),
+    },
+    choice: xml_schema_types::OrderChoice::Email("contact@example.com".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><m:Order xmlns:c="http://example.com/customer" xmlns:m="http://example.com/order"><m:id>1</m:id><c:Address><c:street>1 Main Street</c:street></c:Address><c:Email>contact@example.com</c:Email></m:Order>"#
+  );
+
+  assert_eq!(from_str::<xml_schema_types::Order>(&data).unwrap(), model);
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn facet() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/facet.xsd")]
+  struct FacetSchema;
+
+  let xml_1 = r#"
+  <Payment currency="EUR">
+    <reference>INV-2024-001</reference>
+    <amount>1250.50</amount>
+    <discount>10</discount>
+  </Payment>
+  "#;
+
+  let sample_1: xml_schema_types::Payment = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Payment {
+    reference: Some(xml_schema_types::Max35Text::new("INV-2024-001".to_string()).unwrap()),
+    amount: Some(
+      xml_schema_types::ImpliedCurrencyAndAmount::new("1250.50".parse().unwrap()).unwrap(),
+    ),
+    discount: Some(xml_schema_types::Percentage::new(10).unwrap()),
+    currency: Some(xml_schema_types::ActiveCurrencyCode::from_str("EUR").unwrap()),
+    channel: xml_schema_types::Max35Text::new("online".to_string()).unwrap(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(model.discount.as_ref().unwrap().value(), &10);
+
+  let data = to_string(&model).unwrap();
+  let sample_2: xml_schema_types::Payment = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+
+  assert_eq!(
+    xml_schema_types::ActiveCurrencyCode::from_str("euro").unwrap_err(),
+    r#""euro" is not a valid value for ActiveCurrencyCode, pattern is "[A-Z]{3,3}""#
+  );
+  assert_eq!(
+    xml_schema_types::ImpliedCurrencyAndAmount::from_str("-1").unwrap_err(),
+    r#""-1" is not a valid value for ImpliedCurrencyAndAmount, minInclusive is 0"#
+  );
+  assert_eq!(
+    xml_schema_types::ImpliedCurrencyAndAmount::from_str("0.123456").unwrap_err(),
+    r#""0.123456" is not a valid value for ImpliedCurrencyAndAmount, fractionDigits is 5"#
+  );
+  assert_eq!(
+    xml_schema_types::Max35Text::new(String::new()).unwrap_err(),
+    r#""" is not a valid value for Max35Text, minLength is 1"#
+  );
+  assert_eq!(
+    xml_schema_types::Percentage::from_str("101").unwrap_err(),
+    r#""101" is not a valid value for Percentage, maxExclusive is 101"#
+  );
+  assert_eq!(
+    xml_schema_types::Percentage::from_str("ten").unwrap_err(),
+    r#""ten" is not a valid value for Percentage"#
+  );
+
+  let xml_2 = r#"
+  <Payment currency="EUR">
+    <reference>INV-2024-001</reference>
+    <amount>1250.505051</amount>
+  </Payment>
+  "#;
+
+  assert!(from_str::<xml_schema_types::Payment>(xml_2).is_err());
+
+  // required content is optional in Rust for the types without default value, but still required
+  let xml_3 = r#"
+  <Payment currency="EUR">
+    <amount>1250.50</amount>
+  </Payment>
+  "#;
+
+  assert_eq!(
+    from_str::<xml_schema_types::Payment>(xml_3).unwrap_err(),
+    r#"Missing element "reference" in Payment"#
+  );
+
+  let xml_4 = r#"
+  <Payment>
+    <reference>INV-2024-001</reference>
+    <amount>1250.50</amount>
+  </Payment>
+  "#;
+
+  assert_eq!(
+    from_str::<xml_schema_types::Payment>(xml_4).unwrap_err(),
+    r#"Missing attribute "currency" in Payment"#
+  );
+
+  let settlement: xml_schema_types::Settlement =
+    from_str("<Settlement><account>FR76-0001</account></Settlement>").unwrap();
+  assert_eq!(
+    settlement.choice,
+    Some(xml_schema_types::SettlementChoice::Account(
+      xml_schema_types::Max35Text::new("FR76-0001".to_string()).unwrap()
+    ))
+  );
+  assert_eq!(
+    from_str::<xml_schema_types::Settlement>("<Settlement></Settlement>").unwrap_err(),
+    "Missing choice SettlementChoice in Settlement"
+  );
+
+  // a restriction of a user-defined type checks the facets of both
+  assert_eq!(
+    xml_schema_types::SmallPercentage::from_str("10")
+      .unwrap()
+      .value()
+      .value(),
+    &10
+  );
+  assert_eq!(
+    xml_schema_types::SmallPercentage::from_str("11").unwrap_err(),
+    r#""11" is not a valid value for SmallPercentage, maxInclusive is 10"#
+  );
+  assert_eq!(
+    xml_schema_types::SmallPercentage::from_str("200").unwrap_err(),
+    r#""200" is not a valid value for Percentage, maxExclusive is 101"#
+  );
+
+  // decimal bounds are compared exactly
+  assert!(xml_schema_types::Rate::from_str("0.10000000000000000001").is_ok());
+  assert_eq!(
+    xml_schema_types::Rate::from_str("0.1").unwrap_err(),
+    r#""0.1" is not a valid value for Rate, minExclusive is 0.1"#
+  );
+  assert_eq!(
+    xml_schema_types::Rate::from_str("99999999999999999999.5").unwrap_err(),
+    r#""99999999999999999999.5" is not a valid value for Rate, maxInclusive is 99999999999999999999"#
+  );
+
+  // restrictions with facets have no default value, only the one declared in the schema
+  let payment = xml_schema_types::Payment::default();
+  assert_eq!(payment.reference, None);
+  assert_eq!(payment.currency, None);
+  assert_eq!(payment.channel.value(), "online");
+}
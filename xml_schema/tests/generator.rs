@@ -0,0 +1,144 @@
+use xml_schema_generator::{Error, Generator};
+
+#[test]
+fn generate_file() {
+  let output = std::env::temp_dir().join("xml_schema_generator_enumeration.rs");
+
+  let generated = Generator::default()
+    .source("tests/simple_type_enumeration.xsd")
+    .output(&output)
+    .generate()
+    .unwrap();
+
+  assert_eq!(generated, output);
+
+  let code = std::fs::read_to_string(&output).unwrap();
+
+  assert!(code.starts_with("mod simple_type_enumeration {\n"));
+  assert!(code.contains("\n        pub enum AlignmentType {\n"));
+  assert!(code.ends_with("pub use simple_type_enumeration::*;\n"));
+}
+
+#[test]
+fn generate_without_source() {
+  assert_eq!(
+    Generator::default().generate_tokens().map(|_| ()),
+    Err(Error::new("No XML Schema source to generate"))
+  );
+}
+
+#[test]
+fn generate_missing_source() {
+  assert!(Generator::default()
+    .source("tests/missing.xsd")
+    .generate_tokens()
+    .is_err());
+}
+
+#[test]
+fn generate_invalid_schema() {
+  let error = Generator::default()
+    .source("tests/invalid_type.xsd")
+    .generate_tokens()
+    .unwrap_err();
+
+  assert_eq!(error.message(), "Type \"unknown\" not implemented");
+  assert_eq!(error.file(), Some("tests/invalid_type.xsd"));
+  assert_eq!(error.position(), Some((6, 7)));
+  assert_eq!(
+    error.to_string(),
+    "tests/invalid_type.xsd:6:7: complexType[@name=Book]/sequence/element[2]: Type \"unknown\" not implemented"
+  );
+}
+
+#[test]
+fn generate_dangling_reference() {
+  let error = Generator::default()
+    .source("tests/dangling_reference.xsd")
+    .generate_tokens()
+    .unwrap_err();
+
+  assert_eq!(
+    error.to_string(),
+    "tests/dangling_reference.xsd:5:7: complexType[@name=Book]/sequence/element[1]: Type \"Author\" is not defined"
+  );
+}
+
+#[test]
+fn generate_unused_type_mappings() {
+  let error = Generator::default()
+    .source("tests/type_mappings.xsd")
+    .type_mapping("Book/isbn", "u64")
+    .type_mapping("Book/isnb", "u64")
+    .type_mapping("xs:duration", "std::time::Duration")
+    .generate_tokens()
+    .unwrap_err();
+
+  assert_eq!(
+    error.to_string(),
+    r#"Type mappings matching no type, element or attribute of the schemas: "Book/isnb", "xs:duration""#
+  );
+}
+
+#[test]
+fn generate_modules() {
+  let directory = std::env::temp_dir().join("xml_schema_generator_modules");
+  let _ = std::fs::remove_dir_all(&directory);
+  std::fs::create_dir_all(&directory).unwrap();
+
+  let generator = Generator::default()
+    .source("tests/import.xsd")
+    .target_prefix("lib");
+
+  let outputs = generator.generate_modules(&directory, false).unwrap();
+  assert_eq!(outputs, vec![directory.join("import.rs")]);
+
+  let code = std::fs::read_to_string(directory.join("import.rs")).unwrap();
+  assert!(code.starts_with("pub mod xml_schema_types {\n"));
+  assert!(code.contains("\npub mod person {\n"));
+
+  let outputs = generator.generate_modules(&directory, true).unwrap();
+  assert_eq!(
+    outputs,
+    vec![
+      directory.join("import").join("person.rs"),
+      directory.join("import").join("mod.rs"),
+    ]
+  );
+
+  let code = std::fs::read_to_string(directory.join("import").join("mod.rs")).unwrap();
+  assert!(code.contains("\npub mod person;\n"));
+
+  let code = std::fs::read_to_string(directory.join("import").join("person.rs")).unwrap();
+  assert!(code.contains("pub struct Person {"));
+}
+
+#[test]
+fn generate_tokens_tracking_schemas() {
+  let tests_directory = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+
+  let generator = Generator::default()
+    .base_directory(&tests_directory)
+    .source("import.xsd")
+    .target_prefix("lib");
+
+  let (code, local_files) = generator.generate_tokens_and_local_files().unwrap();
+
+  let expected: Vec<String> = ["import.xsd", "import_common.xsd", "import_person.xsd"]
+    .iter()
+    .map(|file| {
+      std::fs::canonicalize(tests_directory.join(file))
+        .unwrap()
+        .display()
+        .to_string()
+    })
+    .collect();
+  assert_eq!(local_files.into_iter().collect::<Vec<_>>(), expected);
+
+  // the generated code does not depend on where the schemas are
+  assert!(!code.to_string().contains("include_bytes"));
+  assert_eq!(
+    generator.generate_tokens().unwrap().to_string(),
+    code.to_string()
+  );
+}
@@ -0,0 +1,49 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn import_and_include() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/import.xsd", target_prefix = "lib")]
+  struct ImportSchema;
+
+  let xml_1 = r#"
+  <lib:Book xmlns:lib="http://example.com/library" xmlns:person="http://example.com/person">
+    <lib:title>Les Misérables</lib:title>
+    <lib:isbn>978-2253096337</lib:isbn>
+    <lib:author>
+      <person:name>Victor Hugo</person:name>
+      <person:favoriteBook>978-2253096344</person:favoriteBook>
+      <lib:library>Bibliothèque nationale</lib:library>
+    </lib:author>
+  </lib:Book>
+  "#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Book {
+    title: "Les Misérables".to_string(),
+    isbn: xml_schema_types::Isbn {
+      content: "978-2253096337".to_string(),
+    },
+    author: xml_schema_types::person::Person {
+      name: "Victor Hugo".to_string(),
+      favorite_book: Some(xml_schema_types::Isbn {
+        content: "978-2253096344".to_string(),
+      }),
+      lib_library: Some("Bibliothèque nationale".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><lib:Book xmlns:lib="http://example.com/library" xmlns:person="http://example.com/person"><lib:title>Les Misérables</lib:title><lib:isbn>978-2253096337</lib:isbn><lib:author><person:name>Victor Hugo</person:name><person:favoriteBook>978-2253096344</person:favoriteBook><lib:library>Bibliothèque nationale</lib:library></lib:author></lib:Book>"#
+  );
+
+  let sample_2: xml_schema_types::Book = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+}
@@ -0,0 +1,52 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn inline_simple_type() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/inline_simple_type.xsd")]
+  struct InlineSimpleTypeSchema;
+
+  let xml_1 = r#"
+  <Book ratings="4 5" edition="first" script="latin">
+    <title>Dune</title>
+    <format>paperback</format>
+    <pages>412</pages>
+  </Book>
+  "#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Book {
+    title: Some(xml_schema_types::BookTitle::new("Dune".to_string()).unwrap()),
+    format: xml_schema_types::BookFormat::Paperback,
+    pages: 412,
+    ratings: Some(xml_schema_types::BookRatings { items: vec![4, 5] }),
+    edition: Some(xml_schema_types::BookEdition::Member1(
+      xml_schema_types::BookEditionMember1::First,
+    )),
+    script: Some(xml_schema_types::BookScript::Latin),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  let sample_2: xml_schema_types::Book = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+
+  // the facets and enumerations of the inline types are checked
+  let xml_2 =
+    r#"<Book><title>Dune Messiah</title><format>paperback</format><pages>1</pages></Book>"#;
+  assert!(from_str::<xml_schema_types::Book>(xml_2).is_err());
+
+  let xml_3 = r#"<Book><title>Dune</title><format>ebook</format><pages>1</pages></Book>"#;
+  assert!(from_str::<xml_schema_types::Book>(xml_3).is_err());
+
+  let xml_4 = r#"<Book edition="second"><title>Dune</title><format>paperback</format><pages>1</pages></Book>"#;
+  assert!(from_str::<xml_schema_types::Book>(xml_4).is_err());
+
+  let shelf: Shelf = from_str("<Shelf><label>A1</label></Shelf>").unwrap();
+  assert_eq!(shelf.label.as_ref().unwrap().value(), "A1");
+  assert!(from_str::<Shelf>("<Shelf><label>first</label></Shelf>").is_err());
+}
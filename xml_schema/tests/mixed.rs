@@ -0,0 +1,70 @@
+use xml_schema::de::from_str;
+use xml_schema_derive::XmlSchema;
+use yaserde::ser::to_string;
+
+#[test]
+fn mixed() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/mixed.xsd")]
+  struct MixedSchema;
+
+  use xml_schema_types::{Link, Paragraph, ParagraphContent, ParagraphContentList};
+
+  // the text is read without trimming the whitespace around the child elements
+  let xml_1 = r#"<Paragraph lang="en">Read <b>this</b> and <count>2</count> more <a href="https://www.rust-lang.org">links</a>, <i>twice</i>.</Paragraph>"#;
+
+  let sample_1: Paragraph = from_str(xml_1).unwrap();
+
+  // undeclared children are kept, with the namespace bindings in scope
+  let undeclared = match &sample_1.content.items[7] {
+    ParagraphContent::Other(element) => element.clone(),
+    item => panic!("unexpected item {:?}", item),
+  };
+  assert_eq!(undeclared.name.local_name, "i");
+  assert_eq!(undeclared.text(), "twice");
+
+  let model = Paragraph {
+    content: ParagraphContentList {
+      items: vec![
+        ParagraphContent::Text("Read ".to_string()),
+        ParagraphContent::B("this".to_string()),
+        ParagraphContent::Text(" and ".to_string()),
+        ParagraphContent::Count(2),
+        ParagraphContent::Text(" more ".to_string()),
+        ParagraphContent::A(Link {
+          base: "links".to_string(),
+          href: "https://www.rust-lang.org".to_string(),
+        }),
+        ParagraphContent::Text(", ".to_string()),
+        ParagraphContent::Other(undeclared),
+        ParagraphContent::Text(".".to_string()),
+      ],
+    },
+    lang: Some("en".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_1));
+  assert_eq!(from_str::<Paragraph>(&data).unwrap(), model);
+
+  // whitespace-only text between child elements is kept as well
+  let xml_3 = r#"<Paragraph><b>this</b> <count>2</count></Paragraph>"#;
+  let sample_3: Paragraph = from_str(xml_3).unwrap();
+  assert_eq!(
+    sample_3.content.items,
+    vec![
+      ParagraphContent::B("this".to_string()),
+      ParagraphContent::Text(" ".to_string()),
+      ParagraphContent::Count(2),
+    ]
+  );
+
+  let xml_2 = r#"<note>Text only</note>"#;
+  let sample_2: Note = from_str(xml_2).unwrap();
+  assert_eq!(
+    sample_2.content.items,
+    vec![NoteContent::Text("Text only".to_string())]
+  );
+}
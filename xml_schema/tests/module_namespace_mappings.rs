@@ -0,0 +1,61 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+mod address {
+  #[derive(
+    Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize,
+  )]
+  pub struct Address {
+    #[yaserde(rename = "city")]
+    pub city: String,
+  }
+}
+
+mod payment {
+  #[derive(
+    Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize,
+  )]
+  pub struct Payment {
+    #[yaserde(attribute, rename = "method")]
+    pub method: String,
+  }
+}
+
+#[test]
+fn module_namespace_mappings() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/module_namespace_mappings.xsd",
+    target_prefix = "ord",
+    module_namespace_mappings(
+      "http://example.com/address" = "crate::address",
+      "http://example.com/payment" = "crate::payment",
+    )
+  )]
+  struct ModuleNamespaceMappingsSchema;
+
+  let xml = r#"
+  <ord:Order xmlns:ord="http://example.com/order">
+    <ord:shipTo><city>Paris</city></ord:shipTo>
+    <ord:payment method="card"/>
+  </ord:Order>
+  "#;
+
+  let order: xml_schema_types::Order = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Order {
+    ship_to: address::Address {
+      city: "Paris".to_string(),
+    },
+    payment: payment::Payment {
+      method: "card".to_string(),
+    },
+  };
+
+  assert_eq!(order, model);
+
+  let data = to_string(&model).unwrap();
+  let sample: xml_schema_types::Order = from_str(&data).unwrap();
+  assert_eq!(sample, model);
+}
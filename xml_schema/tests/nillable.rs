@@ -0,0 +1,64 @@
+use xml_schema::types::{XsNillable, XsNillableValue};
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn nillable() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/nillable.xsd")]
+  struct NillableSchema;
+
+  let xml_1 = r#"
+  <Customer xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+    <name>Ada</name>
+    <nickname xsi:nil="true"/>
+    <address xsi:nil="1"></address>
+  </Customer>
+  "#;
+
+  let sample_1: xml_schema_types::Customer = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Customer {
+    name: "Ada".to_string(),
+    age: XsNillableValue::Absent,
+    nickname: XsNillableValue::Nil,
+    address: XsNillable::Nil,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Customer><name>Ada</name><nickname xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /><address xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /></Customer>"#
+  );
+
+  assert_eq!(
+    from_str::<xml_schema_types::Customer>(&data).unwrap(),
+    model
+  );
+}
+
+#[test]
+fn nillable_with_values() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/nillable.xsd")]
+  struct NillableSchema;
+
+  let xml_1 = r#"<Customer><name>Grace</name><age>85</age><nickname>Amazing Grace</nickname><address><city>Arlington</city></address></Customer>"#;
+
+  let sample_1: xml_schema_types::Customer = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Customer {
+    name: "Grace".to_string(),
+    age: XsNillableValue::Value(85),
+    nickname: XsNillableValue::Value("Amazing Grace".to_string()),
+    address: XsNillable::Value(xml_schema_types::Address {
+      city: "Arlington".to_string(),
+    }),
+  };
+
+  assert_eq!(sample_1, model);
+  assert!(to_string(&model).unwrap().ends_with(xml_1));
+}
@@ -0,0 +1,38 @@
+#![cfg(all(feature = "decimal", feature = "big-integer"))]
+
+use xml_schema::types::{
+  XsDecimal, XsInteger, XsNegativeInteger, XsNonNegativeInteger, XsNonPositiveInteger,
+  XsPositiveInteger,
+};
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn numeric() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/numeric.xsd")]
+  struct NumericSchema;
+
+  let xml_1 = r#"<Measure index="0" delta="-2"><amount>12345678901234567890.50</amount><total>-98765432109876543210</total><quantity>3</quantity><offset>-1</offset></Measure>"#;
+
+  let sample_1: xml_schema_types::Measure = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Measure {
+    amount: "12345678901234567890.50".parse::<XsDecimal>().unwrap(),
+    total: "-98765432109876543210".parse::<XsInteger>().unwrap(),
+    quantity: "3".parse::<XsPositiveInteger>().unwrap(),
+    offset: Some("-1".parse::<XsNegativeInteger>().unwrap()),
+    index: Some("0".parse::<XsNonNegativeInteger>().unwrap()),
+    delta: Some("-2".parse::<XsNonPositiveInteger>().unwrap()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  // the scale of decimals is kept
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_1));
+
+  let xml_2 = r#"<Measure><amount>1</amount><total>1</total><quantity>0</quantity></Measure>"#;
+  assert!(from_str::<xml_schema_types::Measure>(xml_2).is_err());
+}
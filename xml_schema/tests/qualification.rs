@@ -0,0 +1,63 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn qualification() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/qualification.xsd", target_prefix = "ord")]
+  struct QualificationSchema;
+
+  let model = xml_schema_types::Order {
+    customer: "Ada".to_string(),
+    reference: "A-12".to_string(),
+    id: Some("7".to_string()),
+    currency: Some("EUR".to_string()),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><ord:Order xmlns:ord="http://example.com/order" ord:id="7" currency="EUR"><customer>Ada</customer><ord:reference>A-12</ord:reference></ord:Order>"#
+  );
+
+  assert_eq!(from_str::<xml_schema_types::Order>(&data).unwrap(), model);
+
+  // the prefixes of a document are chosen by its author
+  let xml_1 = r#"
+  <o:Order xmlns:o="http://example.com/order" o:id="7" currency="EUR">
+    <customer>Ada</customer>
+    <reference xmlns="http://example.com/order">A-12</reference>
+  </o:Order>
+  "#;
+
+  let sample_1: xml_schema_types::Order = from_str(xml_1).unwrap();
+  assert_eq!(sample_1, model);
+
+  // an unqualified element is not in the target namespace
+  let xml_2 = r#"
+  <o:Order xmlns:o="http://example.com/order">
+    <o:customer>Ada</o:customer>
+    <o:reference>A-12</o:reference>
+  </o:Order>
+  "#;
+
+  assert!(from_str::<xml_schema_types::Order>(xml_2).is_err());
+}
+
+#[test]
+fn qualified_top_level_element() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/qualification.xsd", target_prefix = "ord")]
+  struct QualificationSchema;
+
+  let model = Total { content: 3 };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><ord:total xmlns:ord="http://example.com/order">3</ord:total>"#
+  );
+
+  assert_eq!(from_str::<Total>(&data).unwrap(), model);
+}
@@ -0,0 +1,71 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn group_in_sequence() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/sequence.xsd")]
+  struct SequenceSchema;
+
+  let xml = r#"
+  <Customer>
+    <name>Jane</name>
+    <street>1 Main Street</street>
+    <city>Springfield</city>
+    <phone>0123456789</phone>
+  </Customer>
+  "#;
+
+  let sample: xml_schema_types::Customer = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Customer {
+    name: "Jane".to_string(),
+    postal_address: xml_schema_types::PostalAddress {
+      street: "1 Main Street".to_string(),
+      city: "Springfield".to_string(),
+    },
+    phone: "0123456789".to_string(),
+  };
+
+  assert_eq!(sample, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Customer><name>Jane</name><street>1 Main Street</street><city>Springfield</city><phone>0123456789</phone></Customer>"#
+  );
+}
+
+#[test]
+fn nested_sequence() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/sequence.xsd")]
+  struct SequenceSchema;
+
+  let xml = r#"
+  <Invoice>
+    <number>A-1</number>
+    <amount>42</amount>
+    <due>2024-01-31</due>
+    <note>Thank you</note>
+  </Invoice>
+  "#;
+
+  let sample: xml_schema_types::Invoice = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Invoice {
+    number: "A-1".to_string(),
+    amount: 42,
+    choice: xml_schema_types::InvoiceChoice::Due("2024-01-31".to_string()),
+    note: "Thank you".to_string(),
+  };
+
+  assert_eq!(sample, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Invoice><number>A-1</number><amount>42</amount><due>2024-01-31</due><note>Thank you</note></Invoice>"#
+  );
+}
@@ -5,10 +5,7 @@ use yaserde::ser::to_string;
 #[test]
 fn simple_type_string() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(
-    source = "xml_schema/tests/simple_type_string.xsd",
-    target_prefix = "st"
-  )]
+  #[xml_schema(source = "tests/simple_type_string.xsd", target_prefix = "st")]
   struct SimpleTypeSchema;
 
   let xml_1 = r#"
@@ -29,7 +26,7 @@ fn simple_type_string() {
 #[test]
 fn simple_type_list() {
   #[derive(Debug, XmlSchema)]
-  #[xml_schema(source = "xml_schema/tests/simple_type_list.xsd")]
+  #[xml_schema(source = "tests/simple_type_list.xsd")]
   struct SimpleTypeSchema;
 
   let xml_1 = r#"
@@ -42,7 +39,9 @@ fn simple_type_list() {
     strings: Some(xml_schema_types::StringList {
       items: vec!["value1".to_string(), "value2".to_string()],
     }),
-    integers: Some(xml_schema_types::IntegerList { items: vec![3, 6] }),
+    integers: Some(xml_schema_types::IntegerList {
+      items: vec!["3".parse().unwrap(), "6".parse().unwrap()],
+    }),
     booleans: Some(xml_schema_types::BooleanList {
       items: vec![true, false],
     }),
@@ -56,3 +55,166 @@ fn simple_type_list() {
     r#"<?xml version="1.0" encoding="utf-8"?><BaseType strings="value1 value2" integers="3 6" booleans="true false" />"#
   );
 }
+
+#[test]
+fn simple_type_list_invalid_item() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_list.xsd")]
+  struct SimpleTypeSchema;
+
+  let error = from_str::<xml_schema_types::BaseType>(r#"<BaseType integers="1 x" />"#).unwrap_err();
+  assert!(
+    error.contains(r#""x" is not a valid item of the list "1 x""#),
+    "{}",
+    error
+  );
+
+  let sample: xml_schema_types::BaseType = from_str(r#"<BaseType integers="" />"#).unwrap();
+  assert_eq!(
+    sample.integers,
+    Some(xml_schema_types::IntegerList { items: vec![] })
+  );
+
+  let integers: xml_schema_types::IntegerList = " 3\t6 ".parse().unwrap();
+  assert_eq!(integers.to_string(), "3 6");
+}
+
+#[test]
+fn simple_type_enumeration() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_enumeration.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"
+  <Policy alignment="s">
+    <disposition>sampled_out</disposition>
+  </Policy>
+  "#;
+
+  let sample_1: xml_schema_types::Policy = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Policy {
+    disposition: xml_schema_types::DispositionType::SampledOut,
+    alignment: xml_schema_types::AlignmentType::S,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Policy alignment="s"><disposition>sampled_out</disposition></Policy>"#
+  );
+
+  assert_eq!(
+    "quarantine".parse::<xml_schema_types::DispositionType>(),
+    Ok(xml_schema_types::DispositionType::Quarantine)
+  );
+  assert_eq!(xml_schema_types::DispositionType::None.to_string(), "none");
+
+  let xml_2 = r#"
+  <Policy alignment="s">
+    <disposition>unknown</disposition>
+  </Policy>
+  "#;
+
+  assert!(from_str::<xml_schema_types::Policy>(xml_2).is_err());
+
+  let xml_3 = r#"<Policy alignment="x"><disposition>none</disposition></Policy>"#;
+
+  assert!(from_str::<xml_schema_types::Policy>(xml_3).is_err());
+}
+
+#[test]
+fn simple_type_union() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/simple_type_union.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 = r#"
+  <Font weight="bold">
+    <size>12</size>
+  </Font>
+  "#;
+
+  let sample_1: xml_schema_types::Font = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Font {
+    size: xml_schema_types::FontSize::Int(12),
+    weight: xml_schema_types::FontWeight::Member1(xml_schema_types::FontWeightMember1::Bold),
+    family: None,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Font weight="bold"><size>12</size></Font>"#
+  );
+
+  let xml_2 = r#"<Font weight="true"><size>large</size></Font>"#;
+
+  let sample_2: xml_schema_types::Font = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Font {
+    size: xml_schema_types::FontSize::SizeKeyword(xml_schema_types::SizeKeyword::Large),
+    weight: xml_schema_types::FontWeight::Boolean(true),
+    family: None,
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Font weight="true"><size>large</size></Font>"#
+  );
+
+  assert_eq!(
+    "small".parse::<xml_schema_types::FontSize>(),
+    Ok(xml_schema_types::FontSize::SizeKeyword(
+      xml_schema_types::SizeKeyword::Small
+    ))
+  );
+  assert_eq!(xml_schema_types::FontSize::Int(8).to_string(), "8");
+  assert!("medium".parse::<xml_schema_types::FontSize>().is_err());
+
+  let xml_3 = r#"<Font weight="heavy"><size>12</size></Font>"#;
+
+  assert!(from_str::<xml_schema_types::Font>(xml_3).is_err());
+
+  // members of user-defined types are read and written as their text
+  let families = [
+    (
+      "large",
+      xml_schema_types::FontFamily::SizeKeyword(xml_schema_types::SizeKeyword::Large),
+    ),
+    (
+      "10 12",
+      xml_schema_types::FontFamily::Widths(xml_schema_types::Widths {
+        items: vec![10, 12],
+      }),
+    ),
+    (
+      "Fira &amp; Sans",
+      xml_schema_types::FontFamily::FamilyName(xml_schema_types::FamilyName {
+        content: "Fira & Sans".to_string(),
+      }),
+    ),
+  ];
+
+  for (text, family) in families.iter() {
+    let xml_4 = format!(
+      r#"<Font weight="false"><size>1</size><family>{}</family></Font>"#,
+      text
+    );
+
+    let sample_4: xml_schema_types::Font = from_str(&xml_4).unwrap();
+    assert_eq!(sample_4.family.as_ref(), Some(family));
+
+    let data = to_string(&sample_4).unwrap();
+    assert!(data.ends_with(&xml_4));
+    assert_eq!(from_str::<xml_schema_types::Font>(&data).unwrap(), sample_4);
+  }
+}
@@ -0,0 +1,88 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn substitution_group() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let xml_1 = r#"
+  <Drawing>
+    <title>Shapes</title>
+    <circle><radius>2</radius></circle>
+    <tile><side>1</side></tile>
+    <square><side>3</side></square>
+    <sketch:circle xmlns:sketch="http://example.com/sketch"><sketch:strokes>5</sketch:strokes></sketch:circle>
+    <comment>Draft</comment>
+  </Drawing>
+  "#;
+
+  let sample_1: xml_schema_types::Drawing = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Drawing {
+    title: "Shapes".to_string(),
+    shape_list: xml_schema_types::ShapeSubstitutionList {
+      items: vec![
+        xml_schema_types::ShapeSubstitution::Circle(xml_schema_types::CircleType { radius: 2 }),
+        xml_schema_types::ShapeSubstitution::Tile(xml_schema_types::SquareType { side: 1 }),
+        xml_schema_types::ShapeSubstitution::Square(xml_schema_types::SquareType { side: 3 }),
+        // same local name in another namespace
+        xml_schema_types::ShapeSubstitution::CircleSketch(
+          xml_schema_types::sketch::FreehandCircleType { strokes: 5 },
+        ),
+      ],
+    },
+    note: Some(xml_schema_types::NoteSubstitution::Comment(
+      "Draft".to_string(),
+    )),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Drawing><title>Shapes</title><circle><radius>2</radius></circle><tile><side>1</side></tile><square><side>3</side></square><sketch:circle xmlns:sketch="http://example.com/sketch"><sketch:strokes>5</sketch:strokes></sketch:circle><comment>Draft</comment></Drawing>"#
+  );
+
+  let xml_2 = r#"<Drawing><title>Empty</title><note>Head</note></Drawing>"#;
+
+  let sample_2: xml_schema_types::Drawing = from_str(xml_2).unwrap();
+
+  assert!(sample_2.shape_list.items.is_empty());
+  assert_eq!(
+    sample_2.note,
+    Some(xml_schema_types::NoteSubstitution::Note("Head".to_string()))
+  );
+}
+
+#[test]
+fn substitution_group_in_choice() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Gallery><caption>Shapes</caption><tile><side>1</side></tile><sketch:circle xmlns:sketch="http://example.com/sketch"><sketch:strokes>5</sketch:strokes></sketch:circle><caption>End</caption></Gallery>"#;
+
+  let sample: xml_schema_types::Gallery = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Gallery {
+    choice_list: xml_schema_types::GalleryChoiceList {
+      items: vec![
+        xml_schema_types::GalleryChoice::Caption("Shapes".to_string()),
+        xml_schema_types::GalleryChoice::Shape(xml_schema_types::ShapeSubstitution::Tile(
+          xml_schema_types::SquareType { side: 1 },
+        )),
+        xml_schema_types::GalleryChoice::Shape(xml_schema_types::ShapeSubstitution::CircleSketch(
+          xml_schema_types::sketch::FreehandCircleType { strokes: 5 },
+        )),
+        xml_schema_types::GalleryChoice::Caption("End".to_string()),
+      ],
+    },
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
@@ -0,0 +1,81 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn top_level_element_of_built_in_type() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/top_level_element.xsd")]
+  struct TopLevelElementSchema;
+
+  let count: Count = from_str("<count>42</count>").unwrap();
+  assert_eq!(count, Count { content: 42 });
+  assert_eq!(
+    to_string(&count).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><count>42</count>"#
+  );
+
+  let enabled: Enabled = from_str("<enabled>true</enabled>").unwrap();
+  assert_eq!(enabled, Enabled { content: true });
+  assert_eq!(
+    to_string(&enabled).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><enabled>true</enabled>"#
+  );
+
+  let label: Label = from_str("<label>Ada</label>").unwrap();
+  assert_eq!(
+    label,
+    Label {
+      content: "Ada".to_string()
+    }
+  );
+
+  let size: Size = from_str("<size>512</size>").unwrap();
+  assert_eq!(size, Size { content: 512 });
+  assert_eq!(
+    to_string(&size).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><size>512</size>"#
+  );
+
+  assert!(from_str::<Count>("<count>many</count>").is_err());
+}
+
+#[test]
+fn top_level_element_of_simple_type() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/top_level_element.xsd")]
+  struct TopLevelElementSchema;
+
+  let alignment: Alignment = from_str("<alignment>s</alignment>").unwrap();
+  assert_eq!(
+    alignment,
+    Alignment {
+      content: xml_schema_types::AlignmentType::S
+    }
+  );
+  assert_eq!(
+    to_string(&alignment).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><alignment>s</alignment>"#
+  );
+
+  let small: Small = from_str("<small>7</small>").unwrap();
+  assert_eq!(small.content, xml_schema_types::Small::new(7).unwrap());
+  assert_eq!(
+    to_string(&small).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><small>7</small>"#
+  );
+
+  assert!(from_str::<Small>("<small>11</small>").is_err());
+
+  let level: Level = from_str("<level>high</level>").unwrap();
+  assert_eq!(
+    level,
+    Level {
+      content: LevelContent::High
+    }
+  );
+  assert_eq!(
+    to_string(&level).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><level>high</level>"#
+  );
+}
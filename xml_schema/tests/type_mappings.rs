@@ -0,0 +1,97 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+/// Amount in cents.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Money(i64);
+
+impl std::str::FromStr for Money {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let (units, cents) = value.trim().split_once('.').unwrap_or((value.trim(), "0"));
+    let units: i64 = units
+      .parse()
+      .map_err(|_| format!("invalid amount {value:?}"))?;
+    let cents: i64 = format!("{cents:0<2}")
+      .parse()
+      .map_err(|_| format!("invalid amount {value:?}"))?;
+    Ok(Money(units * 100 + cents))
+  }
+}
+
+impl std::fmt::Display for Money {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(formatter, "{}.{:02}", self.0 / 100, self.0 % 100)
+  }
+}
+
+xml_schema::implement_text_type!(Money);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Uri(String);
+
+impl std::str::FromStr for Uri {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value.trim() {
+      uri if uri.contains("://") => Ok(Uri(uri.to_string())),
+      uri => Err(format!("{uri:?} is not an absolute URI")),
+    }
+  }
+}
+
+impl std::fmt::Display for Uri {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    formatter.write_str(&self.0)
+  }
+}
+
+xml_schema::implement_text_type!(Uri);
+
+#[test]
+fn type_mappings() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "tests/type_mappings.xsd",
+    type_mappings(
+      "Money" = "crate::Money",
+      "xs:anyURI" = "crate::Uri",
+      "Book/@lang" = "crate::Uri",
+      "order/reference" = "u32",
+    )
+  )]
+  struct TypeMappingsSchema;
+
+  let xml_1 = r#"<Book lang="http://example.com/en"><title>Rust</title><price>12.50</price><website>https://www.rust-lang.org</website><isbn>978-1</isbn></Book>"#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Book {
+    title: "Rust".to_string(),
+    price: Money(1250),
+    website: Some(Uri("https://www.rust-lang.org".to_string())),
+    isbn: "978-1".to_string(),
+    lang: Some(Uri("http://example.com/en".to_string())),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert!(data.ends_with(xml_1));
+
+  let xml_2 = r#"<Book><title>Rust</title><price>12.50</price><website>rust-lang.org</website><isbn>978-1</isbn></Book>"#;
+  assert!(from_str::<xml_schema_types::Book>(xml_2).is_err());
+
+  let xml_3 = r#"<order><total>3</total><reference>42</reference></order>"#;
+  let sample_3: Order = from_str(xml_3).unwrap();
+  assert_eq!(
+    sample_3,
+    Order {
+      total: Money(300),
+      reference: 42,
+    }
+  );
+}
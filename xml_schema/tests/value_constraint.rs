@@ -0,0 +1,82 @@
+use std::str::FromStr;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn value_constraint() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/value_constraint.xsd")]
+  struct ValueConstraintSchema;
+
+  let xml_1 = r#"<Document><title>Les Misérables</title></Document>"#;
+
+  let sample_1: xml_schema_types::Document = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Document {
+    title: "Les Misérables".to_string(),
+    language: "en".to_string(),
+    pages: 1,
+    rating: xml_schema_types::Rating::new(3).unwrap(),
+    version: xml_schema_types::DocumentVersion,
+    draft: false,
+    priority: xml_schema_types::Priority::Low,
+    code: xml_schema_types::Code {
+      content: "A1".to_string(),
+    },
+    tags: xml_schema_types::Tags::from_str("novel classic").unwrap(),
+    level: xml_schema_types::DocumentLevel,
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(
+    xml_schema_types::Document::default(),
+    xml_schema_types::Document {
+      title: String::new(),
+      ..model.clone()
+    }
+  );
+
+  let xml_2 = r#"
+  <Document version="1.0" draft="true" priority="high" code="B2" tags="novel" level=" high ">
+    <title>Notre-Dame de Paris</title>
+    <language>fr</language>
+    <pages>940</pages>
+    <rating>5</rating>
+  </Document>
+  "#;
+
+  let sample_2: xml_schema_types::Document = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Document {
+    title: "Notre-Dame de Paris".to_string(),
+    language: "fr".to_string(),
+    pages: 940,
+    rating: xml_schema_types::Rating::new(5).unwrap(),
+    version: xml_schema_types::DocumentVersion,
+    draft: true,
+    priority: xml_schema_types::Priority::High,
+    code: xml_schema_types::Code {
+      content: "B2".to_string(),
+    },
+    tags: xml_schema_types::Tags::from_str("novel").unwrap(),
+    level: xml_schema_types::DocumentLevel,
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  let sample_3: xml_schema_types::Document = from_str(&data).unwrap();
+  assert_eq!(sample_3, model);
+
+  let data = to_string(&xml_schema_types::Document::default()).unwrap();
+  assert!(data.contains(r#"version="1.0""#));
+  assert!(!data.contains("draft"));
+
+  let xml_3 = r#"<Document version="2.0"><title>Les Misérables</title></Document>"#;
+
+  assert!(from_str::<xml_schema_types::Document>(xml_3).is_err());
+
+  let xml_4 = r#"<Document level="low"><title>Les Misérables</title></Document>"#;
+  assert!(from_str::<xml_schema_types::Document>(xml_4).is_err());
+}
@@ -0,0 +1,84 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn xsi_type() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/xsi_type.xsd")]
+  struct XsiTypeSchema;
+
+  let xml_1 = r#"
+  <Garage xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+    <owner xsi:type="Employee">
+      <name>Ada</name>
+      <company>Example</company>
+    </owner>
+    <vehicle xsi:type="Car" wheels="4"><seats>5</seats></vehicle>
+    <vehicle xsi:type="Truck" wheels="6"><load>12</load></vehicle>
+  </Garage>
+  "#;
+
+  let sample_1: xml_schema_types::Garage = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Garage {
+    owner: xml_schema_types::PersonDerived::Employee(xml_schema_types::Employee {
+      base: xml_schema_types::Person {
+        name: "Ada".to_string(),
+      },
+      company: "Example".to_string(),
+    }),
+    vehicle_list: vec![
+      xml_schema_types::VehicleDerived::Car(xml_schema_types::Car {
+        base: xml_schema_types::Vehicle { wheels: Some(4) },
+        seats: 5,
+      }),
+      xml_schema_types::VehicleDerived::Truck(xml_schema_types::Truck {
+        base: xml_schema_types::Vehicle { wheels: Some(6) },
+        load: 12,
+      }),
+    ],
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Garage><owner xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Employee"><name>Ada</name><company>Example</company></owner><vehicle xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Car" wheels="4"><seats>5</seats></vehicle><vehicle xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Truck" wheels="6"><load>12</load></vehicle></Garage>"#
+  );
+
+  let sample_2: xml_schema_types::Garage = from_str(&data).unwrap();
+  assert_eq!(sample_2, model);
+}
+
+#[test]
+fn xsi_type_declared_type() {
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "tests/xsi_type.xsd")]
+  struct XsiTypeSchema;
+
+  let xml_1 = r#"<Garage><owner><name>Grace</name></owner></Garage>"#;
+
+  let sample_1: xml_schema_types::Garage = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Garage {
+    owner: xml_schema_types::PersonDerived::Person(xml_schema_types::Person {
+      name: "Grace".to_string(),
+    }),
+    vehicle_list: vec![],
+  };
+
+  assert_eq!(sample_1, model);
+  assert!(to_string(&model).unwrap().ends_with(xml_1));
+
+  let xml_2 = r#"<Garage><owner><name>Grace</name></owner><vehicle wheels="2"/></Garage>"#;
+  assert!(from_str::<xml_schema_types::Garage>(xml_2).is_err());
+
+  let xml_3 = r#"
+  <Garage xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+    <owner xsi:type="Robot"><name>R2</name></owner>
+  </Garage>
+  "#;
+  assert!(from_str::<xml_schema_types::Garage>(xml_3).is_err());
+}
@@ -1,16 +1,130 @@
-use darling::FromDeriveInput;
+use darling::{util::SpannedValue, FromDeriveInput, FromMeta};
 use log::Level;
 use std::collections::BTreeMap;
+use syn::{
+  parse::{Parse, ParseStream},
+  punctuated::Punctuated,
+  LitStr, Token,
+};
 
-#[derive(Debug, PartialEq, Clone, FromDeriveInput)]
+#[derive(Debug, Clone, FromDeriveInput)]
 #[darling(attributes(xml_schema), forward_attrs(allow, doc, cfg))]
 pub struct XmlSchemaAttributes {
   ident: syn::Ident,
+  pub cache_directory: Option<String>,
+  #[darling(default)]
+  pub catalog: Mappings,
   pub log_level: Option<String>,
-  pub module_namespace_mappings: Option<String>,
-  pub source: String,
+  #[darling(default)]
+  pub module_namespace_mappings: ModuleNamespaceMappings,
+  #[darling(default)]
+  pub offline: bool,
+  pub source: SpannedValue<String>,
   pub store_generated_code: Option<String>,
   pub target_prefix: Option<String>,
+  #[darling(default)]
+  pub type_mappings: Mappings,
+  pub xml_catalog: Option<String>,
+}
+
+/// Mappings written as a list of string pairs, e.g. `("xs:anyURI" = "crate::Uri", "Book/@lang" = "crate::Language")`.
+#[derive(Clone, Debug, Default)]
+pub struct Mappings(pub BTreeMap<String, String>);
+
+struct Mapping(LitStr, LitStr);
+
+impl Parse for Mapping {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let key = input.parse()?;
+    input.parse::<Token![=]>()?;
+    Ok(Mapping(key, input.parse()?))
+  }
+}
+
+impl FromMeta for Mappings {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    let syn::Meta::List(list) = item else {
+      return Err(
+        darling::Error::unsupported_format("expected a list of \"key\" = \"value\"")
+          .with_span(item),
+      );
+    };
+
+    let mappings = list.parse_args_with(Punctuated::<Mapping, Token![,]>::parse_terminated)?;
+
+    Ok(Mappings(
+      mappings
+        .into_iter()
+        .map(|Mapping(key, value)| (key.value(), value.value()))
+        .collect(),
+    ))
+  }
+}
+
+/// Rust modules of namespaces, written as a list of string pairs, e.g.
+/// `("http://example.com/a" = "crate::a", "http://example.com/b" = "crate::b")`.
+/// A single `"namespace: module"` string is also accepted, a module alone maps the empty namespace.
+#[derive(Clone, Debug, Default)]
+pub struct ModuleNamespaceMappings(pub BTreeMap<String, String>);
+
+impl FromMeta for ModuleNamespaceMappings {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    let mappings = match item {
+      syn::Meta::List(list) => list
+        .parse_args_with(Punctuated::<Mapping, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect(),
+      syn::Meta::NameValue(syn::MetaNameValue {
+        value:
+          syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+          }),
+        ..
+      }) => {
+        let mapping = value.value();
+        let (namespace, module) = mapping.split_once(": ").unwrap_or(("", &mapping));
+        vec![Mapping(
+          LitStr::new(namespace, value.span()),
+          LitStr::new(module, value.span()),
+        )]
+      }
+      _ => {
+        return Err(
+          darling::Error::unsupported_format("expected a list of \"namespace\" = \"module\"")
+            .with_span(item),
+        )
+      }
+    };
+
+    let mut errors = darling::Error::accumulator();
+    let mut module_namespace_mappings = BTreeMap::new();
+
+    for Mapping(namespace, module) in mappings {
+      if let Err(error) = syn::parse_str::<syn::Path>(&module.value()) {
+        errors.push(
+          darling::Error::custom(format!("invalid module {:?}: {error}", module.value()))
+            .with_span(&module),
+        );
+        continue;
+      }
+
+      if module_namespace_mappings
+        .insert(namespace.value(), module.value())
+        .is_some()
+      {
+        errors.push(
+          darling::Error::custom(format!(
+            "namespace {:?} is mapped more than once",
+            namespace.value()
+          ))
+          .with_span(&namespace),
+        );
+      }
+    }
+
+    errors.finish_with(ModuleNamespaceMappings(module_namespace_mappings))
+  }
 }
 
 impl XmlSchemaAttributes {
@@ -28,21 +142,4 @@ impl XmlSchemaAttributes {
       Some(_) | None => Level::Warn,
     }
   }
-
-  pub fn module_namespace_mappings(&self) -> BTreeMap<String, String> {
-    let module_namespace_mappings = self.module_namespace_mappings.clone().unwrap_or_default();
-    if module_namespace_mappings.is_empty() {
-      return BTreeMap::default();
-    }
-
-    match module_namespace_mappings
-      .split(": ")
-      .collect::<Vec<_>>()
-      .as_slice()
-    {
-      [first] => BTreeMap::from([("".to_owned(), first.to_string())]),
-      [first, second] => BTreeMap::from([(first.to_string(), second.to_string())]),
-      _ => BTreeMap::default(),
-    }
-  }
 }
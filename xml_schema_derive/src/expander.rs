@@ -1,23 +1,49 @@
-use crate::{attribute::XmlSchemaAttributes, xsd::Xsd};
+use crate::attribute::XmlSchemaAttributes;
 use proc_macro2::TokenStream;
-use syn::{token::Pub, Visibility};
+use quote::quote;
+use std::path::PathBuf;
+use xml_schema_generator::{Error, Generator};
 
-pub fn expand_derive(attributes: &XmlSchemaAttributes) -> Result<TokenStream, String> {
+pub fn expand_derive(attributes: &XmlSchemaAttributes) -> Result<TokenStream, Error> {
   let _ = simple_logger::init_with_level(attributes.log_level());
   log::info!("{:?}", attributes);
 
-  let vis = Visibility::Public(Pub::default());
+  // relative paths are resolved from the crate using the macro, not from the current directory
+  let manifest_directory = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
 
-  let xsd = Xsd::new_from_file(
-    attributes.module_name(),
-    vis,
-    &attributes.source,
-    &attributes.module_namespace_mappings(),
-  )?;
-  let generated = xsd.implement(&attributes.target_prefix);
+  let mut generator = Generator::default()
+    .base_directory(&manifest_directory)
+    .named_source(&attributes.module_name(), &attributes.source)
+    .module_namespace_mappings(&attributes.module_namespace_mappings.0)
+    .type_mappings(&attributes.type_mappings.0)
+    .catalogs(&attributes.catalog.0)
+    .offline(attributes.offline);
+
+  if let Some(target_prefix) = &attributes.target_prefix {
+    generator = generator.target_prefix(target_prefix);
+  }
+
+  if let Some(xml_catalog) = &attributes.xml_catalog {
+    generator = generator.xml_catalog(xml_catalog);
+  }
+
+  if let Some(cache_directory) = &attributes.cache_directory {
+    generator = generator.cache_directory(cache_directory);
+  }
+
+  let (mut generated, local_files) = generator.generate_tokens_and_local_files()?;
 
   if let Some(store_generated_code) = &attributes.store_generated_code {
-    std::fs::write(store_generated_code, generated.to_string()).map_err(|e| e.to_string())?;
+    let store_generated_code = manifest_directory.join(store_generated_code);
+    std::fs::write(&store_generated_code, generated.to_string())
+      .map_err(|e| Error::new(format!("{}: {e}", store_generated_code.display())))?;
+  }
+
+  // the code is expanded again when a local schema changes, the stored code keeps no absolute path
+  for path in local_files {
+    generated.extend(quote!(
+      const _: &[u8] = include_bytes!(#path);
+    ));
   }
 
   Ok(generated)
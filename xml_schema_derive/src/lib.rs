@@ -1,25 +1,26 @@
 extern crate proc_macro;
-#[macro_use]
-extern crate quote;
-#[macro_use]
-extern crate yaserde_derive;
 
 use crate::attribute::XmlSchemaAttributes;
 use darling::FromDeriveInput;
-use syn::DeriveInput;
+use syn::{parse_macro_input, DeriveInput};
 
 mod attribute;
 mod expander;
-mod xsd;
 
 #[proc_macro_derive(XmlSchema, attributes(xml_schema))]
 pub fn xml_schema_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-  let input: DeriveInput = syn::parse2(proc_macro2::TokenStream::from(input)).unwrap();
+  let input = parse_macro_input!(input as DeriveInput);
 
-  let attributes = XmlSchemaAttributes::from_derive_input(&input).unwrap();
+  let attributes = match XmlSchemaAttributes::from_derive_input(&input) {
+    Ok(attributes) => attributes,
+    Err(error) => return error.write_errors().into(),
+  };
 
+  // schema errors are reported on the `source` attribute
   match expander::expand_derive(&attributes) {
     Ok(expanded) => expanded.into(),
-    Err(msg) => panic!("{}", msg),
+    Err(error) => syn::Error::new(attributes.source.span(), error)
+      .to_compile_error()
+      .into(),
   }
 }
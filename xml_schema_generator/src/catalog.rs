@@ -0,0 +1,244 @@
+use crate::{loader::is_url, Error};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use xml::reader::{EventReader, XmlEvent};
+
+const CATALOG_NAMESPACE: &str = "urn:oasis:names:tc:entity:xmlns:xml:catalog";
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Local copies of remote schemas, by URL or by namespace for imports without `schemaLocation`.
+///
+/// Entries are added one by one, or read from an [OASIS XML Catalog](https://www.oasis-open.org/committees/download.php/14809/xml-catalogs.html)
+/// file with its `system`, `uri`, `rewriteSystem`, `rewriteURI` and `nextCatalog` entries.
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+  entries: BTreeMap<String, String>,
+  rewrites: Vec<(String, String)>,
+  files: Vec<String>,
+}
+
+impl Catalog {
+  /// Map a URL or a namespace to a local file.
+  pub fn insert(&mut self, uri: &str, path: &str) {
+    self.entries.insert(uri.to_string(), path.to_string());
+  }
+
+  /// Read an OASIS XML Catalog file, relative locations are resolved from the catalog file.
+  pub fn load(&mut self, path: &str) -> Result<(), Error> {
+    if self.files.iter().any(|file| file == path) {
+      return Ok(());
+    }
+    self.files.push(path.to_string());
+
+    let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    let in_file = |message: String| Error::new(message).in_file(path, &content);
+
+    let mut bases = vec![path.to_string()];
+    let mut next_catalogs = vec![];
+
+    for event in EventReader::from_str(&content) {
+      match event.map_err(|e| in_file(e.to_string()))? {
+        XmlEvent::StartElement {
+          name, attributes, ..
+        } => {
+          let attribute = |local_name: &str| {
+            attributes
+              .iter()
+              .find(|attribute| {
+                attribute.name.local_name == local_name && attribute.name.namespace.is_none()
+              })
+              .map(|attribute| attribute.value.as_str())
+          };
+
+          let base = bases.last().cloned().unwrap_or_default();
+          let base = attributes
+            .iter()
+            .find(|attribute| {
+              attribute.name.local_name == "base"
+                && attribute.name.namespace.as_deref() == Some(XML_NAMESPACE)
+            })
+            .map(|attribute| resolve_reference(&base, &attribute.value))
+            .unwrap_or(base);
+
+          if name.namespace.as_deref() == Some(CATALOG_NAMESPACE) {
+            let resolve = |location: &str| resolve_reference(&base, location);
+
+            match (name.local_name.as_str(), attribute("uri")) {
+              ("system", Some(uri)) => {
+                if let Some(system_id) = attribute("systemId") {
+                  self.add(system_id, resolve(uri));
+                }
+              }
+              ("uri", Some(uri)) => {
+                if let Some(name) = attribute("name") {
+                  self.add(name, resolve(uri));
+                }
+              }
+              ("rewriteSystem", _) | ("rewriteURI", _) => {
+                let start =
+                  attribute("systemIdStartString").or_else(|| attribute("uriStartString"));
+                if let (Some(start), Some(prefix)) = (start, attribute("rewritePrefix")) {
+                  self
+                    .rewrites
+                    .push((start.to_string(), resolve_directory(&base, prefix)));
+                }
+              }
+              ("nextCatalog", _) => {
+                if let Some(catalog) = attribute("catalog") {
+                  next_catalogs.push(resolve(catalog));
+                }
+              }
+              ("catalog", _) | ("group", _) => {}
+              (other, _) => log::debug!("Unsupported catalog entry {} in {}", other, path),
+            }
+          }
+
+          bases.push(base);
+        }
+        XmlEvent::EndElement { .. } => {
+          bases.pop();
+        }
+        _ => {}
+      }
+    }
+
+    // delegated catalogs are consulted after the entries of this one
+    for next_catalog in next_catalogs {
+      self.load(&next_catalog)?;
+    }
+
+    Ok(())
+  }
+
+  /// Local file of a URL or a namespace, an exact entry wins over the longest rewritten prefix.
+  /// Between catalog files, the first matching entry is used.
+  pub fn resolve(&self, uri: &str) -> Option<String> {
+    if let Some(path) = self.entries.get(uri) {
+      return Some(path.clone());
+    }
+
+    self
+      .rewrites
+      .iter()
+      .rev()
+      .filter(|(start, _prefix)| uri.starts_with(start.as_str()))
+      .max_by_key(|(start, _prefix)| start.len())
+      .map(|(start, prefix)| format!("{prefix}{}", &uri[start.len()..]))
+  }
+
+  fn add(&mut self, uri: &str, path: String) {
+    self.entries.entry(uri.to_string()).or_insert(path);
+  }
+
+  /// OASIS catalog files read, including the delegated ones.
+  pub fn files(&self) -> &[String] {
+    &self.files
+  }
+}
+
+/// Location of a catalog reference, relative to the catalog file or to its `xml:base`.
+fn resolve_reference(base: &str, location: &str) -> String {
+  let location = location.strip_prefix("file://").unwrap_or(location);
+
+  if is_url(location) || Path::new(location).is_absolute() {
+    location.to_string()
+  } else if is_url(base) {
+    reqwest::Url::parse(base)
+      .and_then(|url| url.join(location))
+      .map(|url| url.to_string())
+      .unwrap_or_else(|_| location.to_string())
+  } else if base.ends_with('/') {
+    format!("{base}{location}")
+  } else {
+    Path::new(base)
+      .parent()
+      .map(|directory| directory.join(location))
+      .unwrap_or_else(|| Path::new(location).to_path_buf())
+      .display()
+      .to_string()
+  }
+}
+
+/// A rewrite prefix keeps its trailing separator, the rest of the URL is appended to it.
+fn resolve_directory(base: &str, prefix: &str) -> String {
+  let directory = resolve_reference(base, prefix);
+
+  if prefix.ends_with('/') && !directory.ends_with('/') {
+    format!("{directory}/")
+  } else {
+    directory
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn oasis_catalog() {
+    let directory = std::env::temp_dir().join(format!("xml_schema_catalog_{}", std::process::id()));
+    fs::create_dir_all(directory.join("w3c")).unwrap();
+
+    fs::write(
+      directory.join("catalog.xml"),
+      r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+        <system systemId="http://www.w3.org/2001/xml.xsd" uri="w3c/xml.xsd"/>
+        <uri name="http://example.com/person" uri="person.xsd"/>
+        <group xml:base="schemas/">
+          <rewriteSystem systemIdStartString="http://example.com/" rewritePrefix="example/"/>
+          <rewriteSystem systemIdStartString="http://example.com/library/" rewritePrefix="/opt/library/"/>
+        </group>
+        <nextCatalog catalog="w3c/catalog.xml"/>
+      </catalog>"#,
+    )
+    .unwrap();
+
+    fs::write(
+      directory.join("w3c/catalog.xml"),
+      r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">
+        <uri name="http://www.w3.org/2000/09/xmldsig#" uri="xmldsig-core-schema.xsd"/>
+        <uri name="http://example.com/person" uri="person.xsd"/>
+      </catalog>"#,
+    )
+    .unwrap();
+
+    let mut catalog = Catalog::default();
+    catalog
+      .load(&directory.join("catalog.xml").display().to_string())
+      .unwrap();
+
+    let local = |path: &str| Some(directory.join(path).display().to_string());
+
+    assert_eq!(
+      catalog.resolve("http://www.w3.org/2001/xml.xsd"),
+      local("w3c/xml.xsd")
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/person"),
+      local("person.xsd")
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/common/types.xsd"),
+      local("schemas/example/common/types.xsd")
+    );
+    assert_eq!(
+      catalog.resolve("http://example.com/library/book.xsd"),
+      Some("/opt/library/book.xsd".to_string())
+    );
+    assert_eq!(
+      catalog.resolve("http://www.w3.org/2000/09/xmldsig#"),
+      local("w3c/xmldsig-core-schema.xsd")
+    );
+    assert_eq!(catalog.resolve("http://www.w3.org/1999/xlink"), None);
+    assert_eq!(catalog.files().len(), 2);
+
+    catalog.insert("http://example.com/person", "local/person.xsd");
+    assert_eq!(
+      catalog.resolve("http://example.com/person"),
+      Some("local/person.xsd".to_string())
+    );
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+use xml::{
+  common::Position,
+  reader::{EventReader, XmlEvent},
+};
+
+/// Error raised while loading or generating a schema.
+///
+/// It locates the XSD component at the origin of the error with its path from the schema root,
+/// e.g. `complexType[@name=Foo]/sequence/element[2]`, and its position in the file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Error {
+  message: String,
+  file: Option<String>,
+  position: Option<(u64, u64)>,
+  path: Vec<String>,
+}
+
+impl Error {
+  pub fn new<M: Into<String>>(message: M) -> Self {
+    Error {
+      message: message.into(),
+      ..Default::default()
+    }
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// XSD file containing the component at the origin of the error.
+  pub fn file(&self) -> Option<&str> {
+    self.file.as_deref()
+  }
+
+  /// Line and column (starting at 1) of the component at the origin of the error.
+  pub fn position(&self) -> Option<(u64, u64)> {
+    self.position
+  }
+
+  /// Path of the component at the origin of the error, from the schema root.
+  pub fn path(&self) -> String {
+    self.path.join("/")
+  }
+
+  /// Prepend the step of a parent component to the path.
+  pub(crate) fn within<S: Into<String>>(mut self, step: S) -> Self {
+    if self.file.is_none() {
+      self.path.insert(0, step.into());
+    }
+    self
+  }
+
+  /// Attach the file where the error occurred, the position is found from the component path.
+  pub(crate) fn in_file(mut self, file: &str, content: &str) -> Self {
+    if self.file.is_none() {
+      self.position = locate(content, &self.path);
+      self.file = Some(file.to_string()).filter(|file| !file.is_empty());
+    }
+    self
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(file) = &self.file {
+      write!(formatter, "{file}")?;
+      if let Some((line, column)) = self.position {
+        write!(formatter, ":{line}:{column}")?;
+      }
+      write!(formatter, ": ")?;
+    }
+
+    if !self.path.is_empty() {
+      write!(formatter, "{}: ", self.path())?;
+    }
+
+    write!(formatter, "{}", self.message)
+  }
+}
+
+impl std::error::Error for Error {}
+
+impl From<String> for Error {
+  fn from(message: String) -> Self {
+    Error::new(message)
+  }
+}
+
+/// Step of a component in a path: its name, or its position among siblings of the same kind.
+pub(crate) fn step(kind: &str, name: Option<&str>, index: usize) -> String {
+  match name {
+    Some(name) if !name.is_empty() => format!("{kind}[@name={name}]"),
+    _ => format!("{kind}[{}]", index + 1),
+  }
+}
+
+/// Find the line and column of the component at `path`, starting from the schema element.
+fn locate(content: &str, path: &[String]) -> Option<(u64, u64)> {
+  let steps: Vec<(&str, Option<&str>)> = path
+    .iter()
+    .map(|step| match step.split_once('[') {
+      Some((kind, predicate)) => (kind, predicate.strip_suffix(']')),
+      None => (step.as_str(), None),
+    })
+    .collect();
+
+  if steps.is_empty() {
+    return None;
+  }
+
+  let mut reader = EventReader::new(content.as_bytes());
+  let mut depth = 0;
+  let mut matched = 0;
+  let mut counters: Vec<(String, usize)> = vec![];
+
+  loop {
+    match reader.next().ok()? {
+      XmlEvent::StartElement {
+        name, attributes, ..
+      } => {
+        depth += 1;
+        if depth != matched + 2 {
+          continue;
+        }
+
+        let count = match counters
+          .iter_mut()
+          .find(|(kind, _count)| *kind == name.local_name)
+        {
+          Some((_kind, count)) => {
+            *count += 1;
+            *count
+          }
+          None => {
+            counters.push((name.local_name.clone(), 1));
+            1
+          }
+        };
+
+        let (kind, predicate) = steps[matched];
+        let is_matching = kind == name.local_name
+          && match predicate.map(|predicate| predicate.strip_prefix("@name=").ok_or(predicate)) {
+            None => count == 1,
+            Some(Ok(expected_name)) => attributes.iter().any(|attribute| {
+              attribute.name.local_name == "name" && attribute.value == expected_name
+            }),
+            Some(Err(index)) => index.parse() == Ok(count),
+          };
+
+        if is_matching {
+          matched += 1;
+          counters.clear();
+
+          if matched == steps.len() {
+            let position = reader.position();
+            return Some((position.row + 1, position.column + 1));
+          }
+        }
+      }
+      XmlEvent::EndElement { .. } => {
+        if matched > 0 && depth == matched + 1 {
+          return None;
+        }
+        depth -= 1;
+      }
+      XmlEvent::EndDocument => return None,
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  static SCHEMA: &str = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:complexType name="Bar"/>
+  <xs:complexType name="Foo">
+    <xs:sequence>
+      <xs:element name="first" type="xs:string"/>
+      <xs:element name="second" type="xs:unknown"/>
+    </xs:sequence>
+  </xs:complexType>
+</xs:schema>"#;
+
+  #[test]
+  fn locate_component() {
+    let error = Error::new("Type \"unknown\" not implemented")
+      .within("element[2]")
+      .within("sequence")
+      .within("complexType[@name=Foo]")
+      .in_file("schema.xsd", SCHEMA);
+
+    assert_eq!(error.path(), "complexType[@name=Foo]/sequence/element[2]");
+    assert_eq!(error.position(), Some((6, 7)));
+    assert_eq!(
+      error.to_string(),
+      "schema.xsd:6:7: complexType[@name=Foo]/sequence/element[2]: Type \"unknown\" not implemented"
+    );
+  }
+
+  #[test]
+  fn missing_component() {
+    let error = Error::new("message")
+      .within("element[3]")
+      .within("sequence")
+      .within("complexType[@name=Foo]")
+      .in_file("schema.xsd", SCHEMA);
+
+    assert_eq!(error.position(), None);
+    assert_eq!(
+      error.to_string(),
+      "schema.xsd: complexType[@name=Foo]/sequence/element[3]: message"
+    );
+  }
+
+  #[test]
+  fn steps() {
+    assert_eq!(step("element", Some("first"), 0), "element[@name=first]");
+    assert_eq!(step("element", Some(""), 1), "element[2]");
+    assert_eq!(step("attribute", None, 0), "attribute[1]");
+  }
+}
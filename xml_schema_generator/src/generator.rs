@@ -0,0 +1,328 @@
+use crate::{
+  catalog::Catalog,
+  loader::{is_url, Loader},
+  xsd::Xsd,
+  Error,
+};
+use proc_macro2::TokenStream;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::{token::Pub, Visibility};
+
+/// Generate the Rust code of XML Schemas outside of the derive macro, typically from a `build.rs`:
+///
+/// ```no_run
+/// xml_schema_generator::Generator::default()
+///   .source("schemas/library.xsd")
+///   .target_prefix("lib")
+///   .output("library.rs")
+///   .generate()
+///   .unwrap();
+/// ```
+///
+/// The generated file can then be included with `include!(concat!(env!("OUT_DIR"), "/library.rs"));`.
+#[derive(Clone, Debug, Default)]
+pub struct Generator {
+  sources: Vec<(String, String)>,
+  target_prefix: Option<String>,
+  module_namespace_mappings: BTreeMap<String, String>,
+  type_mappings: BTreeMap<String, String>,
+  catalog: BTreeMap<String, String>,
+  xml_catalogs: Vec<String>,
+  cache_directory: Option<PathBuf>,
+  offline: bool,
+  base_directory: Option<PathBuf>,
+  output: Option<PathBuf>,
+}
+
+impl Generator {
+  /// Add a schema (local file or HTTP resource), generated in a module named after the file.
+  pub fn source(self, source: &str) -> Self {
+    let module_name = Path::new(source)
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().to_string())
+      .unwrap_or_default();
+
+    self.named_source(&module_name, source)
+  }
+
+  /// Add a schema generated in the `module_name` module.
+  pub fn named_source(mut self, module_name: &str, source: &str) -> Self {
+    self
+      .sources
+      .push((module_name.to_string(), source.to_string()));
+    self
+  }
+
+  /// Prefix of the target namespace, required if the schema declares a `targetNamespace`.
+  pub fn target_prefix(mut self, target_prefix: &str) -> Self {
+    self.target_prefix = Some(target_prefix.to_string());
+    self
+  }
+
+  /// Map a namespace to an existing Rust module, instead of generating it.
+  pub fn module_namespace_mapping(mut self, namespace: &str, module: &str) -> Self {
+    self
+      .module_namespace_mappings
+      .insert(namespace.to_string(), module.to_string());
+    self
+  }
+
+  pub fn module_namespace_mappings(mut self, mappings: &BTreeMap<String, String>) -> Self {
+    self.module_namespace_mappings.extend(mappings.clone());
+    self
+  }
+
+  /// Use an existing Rust type for a type, by its qualified name (e.g. `xs:anyURI` or `ns:Money`),
+  /// or for an element or attribute, by its path from a top-level component (e.g. `Book/isbn` or `Book/@lang`).
+  /// The Rust type has to implement `YaSerialize` and `YaDeserialize`.
+  pub fn type_mapping(mut self, key: &str, rust_type: &str) -> Self {
+    self
+      .type_mappings
+      .insert(key.to_string(), rust_type.to_string());
+    self
+  }
+
+  pub fn type_mappings(mut self, mappings: &BTreeMap<String, String>) -> Self {
+    self.type_mappings.extend(mappings.clone());
+    self
+  }
+
+  /// Read the schema of a URL, or of a namespace imported without `schemaLocation`, from a local file.
+  pub fn catalog(mut self, uri: &str, path: &str) -> Self {
+    self.catalog.insert(uri.to_string(), path.to_string());
+    self
+  }
+
+  pub fn catalogs(mut self, mappings: &BTreeMap<String, String>) -> Self {
+    self.catalog.extend(mappings.clone());
+    self
+  }
+
+  /// Read local copies of remote schemas from an OASIS XML Catalog file.
+  pub fn xml_catalog(mut self, path: &str) -> Self {
+    self.xml_catalogs.push(path.to_string());
+    self
+  }
+
+  /// Directory where downloaded schemas are stored, to be downloaded only once.
+  pub fn cache_directory<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+    self.cache_directory = Some(directory.into());
+    self
+  }
+
+  /// Forbid network access, remote schemas have to be in the catalog or in the cache.
+  /// It is also forbidden when the `XML_SCHEMA_OFFLINE` environment variable is set.
+  pub fn offline(mut self, offline: bool) -> Self {
+    self.offline = offline;
+    self
+  }
+
+  /// Directory of the relative paths of the sources, catalogs and cache directory,
+  /// default to the current directory. The derive macro uses the manifest directory of the crate.
+  pub fn base_directory<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+    self.base_directory = Some(directory.into());
+    self
+  }
+
+  /// Output file, relative paths are resolved in `OUT_DIR` when it is defined.
+  /// Default to `xml_schema.rs`.
+  pub fn output<P: Into<PathBuf>>(mut self, output: P) -> Self {
+    self.output = Some(output.into());
+    self
+  }
+
+  /// Generated code of the schemas.
+  pub fn generate_tokens(&self) -> Result<TokenStream, Error> {
+    self
+      .generate_tokens_and_local_files()
+      .map(|(generated, _)| generated)
+  }
+
+  /// Generated code, and the canonical paths of every local schema and catalog file it is read from,
+  /// e.g. to include them with `include_bytes!` so that the code is generated again when one of them changes.
+  pub fn generate_tokens_and_local_files(&self) -> Result<(TokenStream, BTreeSet<String>), Error> {
+    let loader = self.loader()?;
+    let schemas = self.load(&loader)?;
+
+    let generated = schemas
+      .iter()
+      .map(|xsd| xsd.implement(&self.target_prefix))
+      .collect::<Result<TokenStream, _>>()?;
+    check_type_mappings(&schemas)?;
+
+    Ok((generated, get_local_files(&schemas, &loader)))
+  }
+
+  /// Write the formatted code in the output file and return its path.
+  /// In a build script, cargo is notified to generate again when a local schema changes.
+  pub fn generate(&self) -> Result<PathBuf, Error> {
+    let (generated, local_files) = self.generate_tokens_and_local_files()?;
+
+    let output = self.get_output_path();
+    write_code(&output, generated)?;
+
+    if std::env::var_os("OUT_DIR").is_some() {
+      for path in local_files {
+        println!("cargo:rerun-if-changed={path}");
+      }
+    }
+
+    Ok(output)
+  }
+
+  /// Write each module in its own file in `directory`, to be declared with `mod <module>;`.
+  /// With `split_namespaces`, the module of each imported namespace is written in its own file,
+  /// next to the `mod.rs` file of the main namespace.
+  pub fn generate_modules(
+    &self,
+    directory: &Path,
+    split_namespaces: bool,
+  ) -> Result<Vec<PathBuf>, Error> {
+    let schemas = self.load(&self.loader()?)?;
+    let mut files = vec![];
+
+    for xsd in &schemas {
+      let module_name = xsd.get_module_name().to_string();
+
+      if split_namespaces {
+        let module_directory = directory.join(&module_name);
+
+        let mut main_module = TokenStream::new();
+        for (name, implementation) in xsd.implement_modules(&self.target_prefix)? {
+          match name {
+            None => main_module.extend(implementation),
+            Some(name) => {
+              main_module.extend(quote!(pub mod #name;));
+              files.push((module_directory.join(format!("{name}.rs")), implementation));
+            }
+          }
+        }
+
+        files.push((module_directory.join("mod.rs"), main_module));
+      } else {
+        let implementation = xsd.implement_content(&self.target_prefix)?;
+        files.push((directory.join(format!("{module_name}.rs")), implementation));
+      }
+    }
+
+    // nothing is written when the type mappings do not match the schemas
+    check_type_mappings(&schemas)?;
+
+    let mut outputs = vec![];
+    for (output, implementation) in files {
+      if let Some(module_directory) = output.parent() {
+        fs::create_dir_all(module_directory)
+          .map_err(|e| format!("{}: {e}", module_directory.display()))?;
+      }
+      write_code(&output, implementation)?;
+      outputs.push(output);
+    }
+
+    Ok(outputs)
+  }
+
+  fn loader(&self) -> Result<Loader, Error> {
+    let mut catalog = Catalog::default();
+    for xml_catalog in &self.xml_catalogs {
+      catalog.load(&self.resolve_path(xml_catalog))?;
+    }
+    for (uri, path) in &self.catalog {
+      catalog.insert(uri, &self.resolve_path(path));
+    }
+
+    let cache_directory =
+      self
+        .cache_directory
+        .as_ref()
+        .map(|directory| match &self.base_directory {
+          Some(base_directory) => base_directory.join(directory),
+          None => directory.clone(),
+        });
+
+    Ok(Loader::new(catalog, cache_directory, self.offline))
+  }
+
+  fn load(&self, loader: &Loader) -> Result<Vec<Xsd>, Error> {
+    if self.sources.is_empty() {
+      return Err(Error::new("No XML Schema source to generate"));
+    }
+
+    self
+      .sources
+      .iter()
+      .map(|(module_name, source)| {
+        Xsd::new(
+          module_name.to_string(),
+          Visibility::Public(Pub::default()),
+          &self.resolve_path(source),
+          loader,
+          &self.module_namespace_mappings,
+          &self.type_mappings,
+        )
+      })
+      .collect()
+  }
+
+  /// Local path relative to the base directory, URLs and absolute paths are kept.
+  fn resolve_path(&self, path: &str) -> String {
+    match &self.base_directory {
+      Some(base_directory) if !is_url(path) => base_directory.join(path).display().to_string(),
+      _ => path.to_string(),
+    }
+  }
+
+  fn get_output_path(&self) -> PathBuf {
+    let output = self
+      .output
+      .clone()
+      .unwrap_or_else(|| PathBuf::from("xml_schema.rs"));
+
+    match std::env::var_os("OUT_DIR") {
+      Some(out_dir) if output.is_relative() => Path::new(&out_dir).join(output),
+      _ => output,
+    }
+  }
+}
+
+/// Fail on the keys of the type mappings which matched nothing in any of the generated schemas,
+/// e.g. misspelled ones, instead of silently generating the types they should replace.
+fn check_type_mappings(schemas: &[Xsd]) -> Result<(), Error> {
+  let unused_keys = schemas
+    .iter()
+    .map(Xsd::get_unused_type_mappings)
+    .reduce(|unused_keys, keys| unused_keys.intersection(&keys).cloned().collect())
+    .unwrap_or_default();
+
+  if unused_keys.is_empty() {
+    return Ok(());
+  }
+
+  let keys: Vec<String> = unused_keys.iter().map(|key| format!("{key:?}")).collect();
+  Err(Error::new(format!(
+    "Type mappings matching no type, element or attribute of the schemas: {}",
+    keys.join(", ")
+  )))
+}
+
+/// Absolute paths of the local schemas and catalogs, without duplicates.
+fn get_local_files(schemas: &[Xsd], loader: &Loader) -> BTreeSet<String> {
+  schemas
+    .iter()
+    .flat_map(|xsd| xsd.get_local_locations())
+    .chain(loader.get_catalog_files().iter().map(String::as_str))
+    .map(|path| {
+      fs::canonicalize(path)
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| path.to_string())
+    })
+    .collect()
+}
+
+fn write_code(output: &Path, code: TokenStream) -> Result<(), Error> {
+  let file = syn::parse2::<syn::File>(code).map_err(|e| e.to_string())?;
+  let code = prettyplease::unparse(&file);
+
+  fs::write(output, code).map_err(|e| Error::new(format!("{}: {e}", output.display())))
+}
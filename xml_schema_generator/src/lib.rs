@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate yaserde_derive;
+
+mod catalog;
+mod error;
+mod generator;
+mod loader;
+mod xsd;
+
+pub use error::Error;
+pub use generator::Generator;
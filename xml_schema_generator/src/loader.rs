@@ -0,0 +1,213 @@
+use crate::{catalog::Catalog, Error};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable forbidding network access when set to anything but `0` or `false`,
+/// e.g. for sandboxed builds.
+pub const OFFLINE_VARIABLE: &str = "XML_SCHEMA_OFFLINE";
+
+/// Fetch schemas from the catalog, the cache or the network, in this order.
+///
+/// Downloaded schemas are stored in the cache directory, in files named after the hashes of
+/// their URL and of their content, so a remote schema is downloaded only once.
+#[derive(Clone, Debug, Default)]
+pub struct Loader {
+  catalog: Catalog,
+  cache_directory: Option<PathBuf>,
+  offline: bool,
+}
+
+impl Loader {
+  pub fn new(catalog: Catalog, cache_directory: Option<PathBuf>, offline: bool) -> Self {
+    let offline = offline
+      || std::env::var(OFFLINE_VARIABLE)
+        .map(|value| !matches!(value.as_str(), "" | "0" | "false"))
+        .unwrap_or_default();
+
+    Loader {
+      catalog,
+      cache_directory,
+      offline,
+    }
+  }
+
+  /// Local file of the schema of a namespace imported without `schemaLocation`.
+  pub fn get_namespace_location(&self, namespace: &str) -> Option<String> {
+    self.catalog.resolve(namespace)
+  }
+
+  /// Content of a schema, with the local file it is read from.
+  pub fn load(&self, location: &str) -> Result<(String, Option<String>), Error> {
+    let (content, path) = match self.catalog.resolve(location) {
+      Some(path) => {
+        log::info!("Load schema {} from {}", location, path);
+        (read_file(&path)?, Some(path))
+      }
+      None if is_url(location) => (self.download(location)?, None),
+      None => (read_file(location)?, Some(location.to_string())),
+    };
+
+    // skip BOM header, can be present on some files
+    let content = match content.strip_prefix('\u{feff}') {
+      Some(content) => content.to_owned(),
+      None => content,
+    };
+
+    Ok((content, path))
+  }
+
+  fn download(&self, url: &str) -> Result<String, Error> {
+    if let Some(content) = self.read_cache(url) {
+      log::info!("Load HTTP schema {} from the cache", url);
+      return Ok(content);
+    }
+
+    if self.offline {
+      let cache = self
+        .cache_directory
+        .as_ref()
+        .map(|directory| format!(" nor in the cache {}", directory.display()))
+        .unwrap_or_default();
+
+      return Err(Error::new(format!(
+        "Network access is disabled, the schema {url} is not in the catalog{cache}"
+      )));
+    }
+
+    log::info!("Load HTTP schema {}", url);
+    let content = reqwest::blocking::get(url)
+      .and_then(|response| response.error_for_status())
+      .and_then(|response| response.text())
+      .map_err(|e| format!("{url}: {e}"))?;
+
+    self.write_cache(url, &content)?;
+
+    Ok(content)
+  }
+
+  /// Cached content of a URL, an entry whose content does not match its hash is ignored.
+  fn read_cache(&self, url: &str) -> Option<String> {
+    let url_hash = format!("{:016x}-", hash(url.as_bytes()));
+
+    fs::read_dir(self.cache_directory.as_ref()?)
+      .ok()?
+      .filter_map(|entry| entry.ok())
+      .map(|entry| entry.path())
+      .filter(|path| path.extension().is_some_and(|extension| extension == "xsd"))
+      .filter_map(|path| {
+        let content_hash = path
+          .file_stem()?
+          .to_str()?
+          .strip_prefix(&url_hash)?
+          .to_string();
+        let content = fs::read_to_string(&path).ok()?;
+
+        (content_hash == format!("{:016x}", hash(content.as_bytes()))).then_some(content)
+      })
+      .next()
+  }
+
+  fn write_cache(&self, url: &str, content: &str) -> Result<(), Error> {
+    let Some(directory) = &self.cache_directory else {
+      return Ok(());
+    };
+
+    let in_directory = |e: std::io::Error| Error::new(format!("{}: {e}", directory.display()));
+    fs::create_dir_all(directory).map_err(in_directory)?;
+
+    let url_hash = format!("{:016x}-", hash(url.as_bytes()));
+    let name = format!("{url_hash}{:016x}.xsd", hash(content.as_bytes()));
+
+    // previous versions of the schema are replaced
+    for entry in fs::read_dir(directory).map_err(in_directory)?.flatten() {
+      if entry.file_name().to_string_lossy().starts_with(&url_hash) {
+        let _ = fs::remove_file(entry.path());
+      }
+    }
+
+    // written next to the entry first, so a partially written file is never read
+    let temporary = directory.join(format!("{name}.{}.tmp", std::process::id()));
+    fs::write(&temporary, content).map_err(in_directory)?;
+    fs::rename(&temporary, directory.join(name)).map_err(in_directory)
+  }
+
+  /// Local files of the catalog, to be watched with the schemas.
+  pub fn get_catalog_files(&self) -> &[String] {
+    self.catalog.files()
+  }
+}
+
+pub fn is_url(location: &str) -> bool {
+  location.starts_with("http://") || location.starts_with("https://")
+}
+
+fn read_file(path: &str) -> Result<String, Error> {
+  if Path::new(path).is_relative() {
+    if let Ok(directory) = std::env::current_dir() {
+      log::info!("The current directory is {}", directory.display());
+    }
+  }
+
+  fs::read_to_string(path).map_err(|e| Error::new(format!("{path}: {e}")))
+}
+
+/// 64-bit FNV-1a hash, stable across Rust versions unlike the `std` hashers.
+fn hash(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+    (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cache() {
+    let directory = std::env::temp_dir().join(format!("xml_schema_cache_{}", std::process::id()));
+    let url = "http://example.com/schemas/library.xsd";
+
+    let loader = Loader::new(Catalog::default(), Some(directory.clone()), true);
+    assert_eq!(
+      loader.load(url).unwrap_err().to_string(),
+      format!(
+        "Network access is disabled, the schema {url} is not in the catalog nor in the cache {}",
+        directory.display()
+      )
+    );
+
+    loader.write_cache(url, "<schema/>").unwrap();
+    loader.write_cache(url, "\u{feff}<xs:schema/>").unwrap();
+    assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+    assert_eq!(
+      loader.load(url).unwrap(),
+      ("<xs:schema/>".to_string(), None)
+    );
+
+    // a modified entry is not trusted
+    let entry = fs::read_dir(&directory).unwrap().next().unwrap().unwrap();
+    fs::write(entry.path(), "<xs:schema>").unwrap();
+    assert!(loader.load(url).is_err());
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn catalog_before_network() {
+    let mut catalog = Catalog::default();
+    catalog.insert("http://example.com/person", "tests/person.xsd");
+
+    let loader = Loader::new(catalog, None, true);
+    assert_eq!(
+      loader.get_namespace_location("http://example.com/person"),
+      Some("tests/person.xsd".to_string())
+    );
+    assert_eq!(
+      loader
+        .load("http://example.com/person")
+        .unwrap_err()
+        .to_string(),
+      "tests/person.xsd: No such file or directory (os error 2)"
+    );
+  }
+}
@@ -0,0 +1,114 @@
+use clap::Parser;
+use std::path::PathBuf;
+use xml_schema_generator::Generator;
+
+/// Generate Rust modules from XML Schema files.
+#[derive(Debug, Parser)]
+#[command(name = "xml-schema", version)]
+struct Arguments {
+  /// XML Schema files or HTTP resources, each one is generated in a module named after the file
+  #[arg(required = true)]
+  sources: Vec<String>,
+  /// Prefix of the target namespace, required if the schema declares a `targetNamespace`
+  #[arg(long)]
+  target_prefix: Option<String>,
+  /// Map a namespace to an existing Rust module instead of generating it, as `NAMESPACE=MODULE`
+  #[arg(long = "module-namespace-mapping", value_parser = parse_mapping)]
+  module_namespace_mappings: Vec<(String, String)>,
+  /// Use an existing Rust type for a type or the element or attribute at a path, as `KEY=TYPE`,
+  /// e.g. `xs:anyURI=crate::Uri` or `Book/@lang=crate::Language`
+  #[arg(long = "type-mapping", value_parser = parse_type_mapping)]
+  type_mappings: Vec<(String, String)>,
+  /// Read the schema of a URL or of a namespace from a local file, as `URI=PATH`
+  #[arg(long = "catalog", value_parser = parse_catalog)]
+  catalog: Vec<(String, String)>,
+  /// OASIS XML Catalog file of local copies of remote schemas
+  #[arg(long = "xml-catalog")]
+  xml_catalogs: Vec<String>,
+  /// Directory where downloaded schemas are stored
+  #[arg(long = "cache-dir")]
+  cache_directory: Option<PathBuf>,
+  /// Forbid network access, remote schemas have to be in a catalog or in the cache
+  #[arg(long)]
+  offline: bool,
+  /// Logger level: error, warn, info, debug or trace
+  #[arg(long, default_value = "warn")]
+  log_level: log::Level,
+  /// Directory where the modules are written
+  #[arg(long, short, default_value = ".")]
+  output_dir: PathBuf,
+  /// Write the module of each imported namespace in its own file
+  #[arg(long)]
+  split_namespaces: bool,
+}
+
+fn parse_mapping(value: &str) -> Result<(String, String), String> {
+  value
+    .rsplit_once('=')
+    .map(|(namespace, module)| (namespace.to_string(), module.to_string()))
+    .ok_or_else(|| format!("expected NAMESPACE=MODULE, found {value:?}"))
+}
+
+fn parse_type_mapping(value: &str) -> Result<(String, String), String> {
+  value
+    .split_once('=')
+    .map(|(key, rust_type)| (key.to_string(), rust_type.to_string()))
+    .ok_or_else(|| format!("expected KEY=TYPE, found {value:?}"))
+}
+
+fn parse_catalog(value: &str) -> Result<(String, String), String> {
+  value
+    .rsplit_once('=')
+    .map(|(uri, path)| (uri.to_string(), path.to_string()))
+    .ok_or_else(|| format!("expected URI=PATH, found {value:?}"))
+}
+
+fn main() {
+  let arguments = Arguments::parse();
+  let _ = simple_logger::init_with_level(arguments.log_level);
+
+  let mut generator = arguments
+    .sources
+    .iter()
+    .fold(Generator::default(), |generator, source| {
+      generator.source(source)
+    });
+
+  if let Some(target_prefix) = &arguments.target_prefix {
+    generator = generator.target_prefix(target_prefix);
+  }
+
+  for (namespace, module) in &arguments.module_namespace_mappings {
+    generator = generator.module_namespace_mapping(namespace, module);
+  }
+
+  for (key, rust_type) in &arguments.type_mappings {
+    generator = generator.type_mapping(key, rust_type);
+  }
+
+  for (uri, path) in &arguments.catalog {
+    generator = generator.catalog(uri, path);
+  }
+
+  for xml_catalog in &arguments.xml_catalogs {
+    generator = generator.xml_catalog(xml_catalog);
+  }
+
+  if let Some(cache_directory) = &arguments.cache_directory {
+    generator = generator.cache_directory(cache_directory);
+  }
+
+  generator = generator.offline(arguments.offline);
+
+  match generator.generate_modules(&arguments.output_dir, arguments.split_namespaces) {
+    Ok(outputs) => {
+      for output in outputs {
+        println!("{}", output.display());
+      }
+    }
+    Err(error) => {
+      eprintln!("error: {error}");
+      std::process::exit(1);
+    }
+  }
+}
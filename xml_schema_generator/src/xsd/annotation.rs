@@ -1,4 +1,7 @@
-use crate::xsd::{attribute::Attribute, Implementation, XsdContext};
+use crate::{
+  xsd::{attribute::Attribute, Implementation, XsdContext},
+  Error,
+};
 use proc_macro2::TokenStream;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
@@ -26,7 +29,7 @@ impl Implementation for Annotation {
     _namespace_definition: &TokenStream,
     _prefix: &Option<String>,
     _context: &XsdContext,
-  ) -> TokenStream {
+  ) -> Result<TokenStream, Error> {
     log::info!("Generate annotation");
 
     let documentation = self
@@ -34,6 +37,6 @@ impl Implementation for Annotation {
       .iter()
       .map(|documentation| quote!(#[doc = #documentation]));
 
-    quote!(#(#documentation)*)
+    Ok(quote!(#(#documentation)*))
   }
 }
@@ -0,0 +1,576 @@
+use crate::{
+  error::step,
+  xsd::{
+    qualification::Qualification,
+    registry::ComponentKind,
+    rust_types_mapping::RustTypesMapping,
+    simple_type::SimpleType,
+    value_constraint::{has_default, FieldType, ValueConstraint},
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use heck::ToSnakeCase;
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "attribute",
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Attribute {
+  #[yaserde(prefix = "xs", attribute)]
+  pub name: Option<String>,
+  #[yaserde(rename = "type", attribute)]
+  pub kind: Option<String>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
+  #[yaserde(rename = "use", attribute)]
+  pub required: Required,
+  #[yaserde(rename = "ref", attribute)]
+  pub reference: Option<String>,
+  #[yaserde(attribute)]
+  pub form: Option<Qualification>,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Option<SimpleType>,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Debug, Default, PartialEq, YaDeserialize)]
+pub enum Required {
+  #[default]
+  #[yaserde(rename = "optional")]
+  Optional,
+  #[yaserde(rename = "required")]
+  Required,
+  #[yaserde(rename = "prohibited")]
+  Prohibited,
+}
+
+impl Implementation for Attribute {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    if let Some((declaration, prefix, context)) = self.get_reference_declaration(prefix, context)? {
+      return declaration.implement(_namespace_definition, &prefix, &context);
+    }
+
+    // a prohibited attribute can not be present, no field is generated
+    let Some(raw_name) = self
+      .name
+      .as_ref()
+      .filter(|_name| self.required != Required::Prohibited)
+    else {
+      return Ok(quote!());
+    };
+    let name = raw_name.to_snake_case();
+
+    let name = if name == "type" {
+      "kind".to_string()
+    } else {
+      name
+    };
+
+    let field_name = Ident::new(&name, Span::call_site());
+
+    let (rust_type, field_type) = match (
+      context.get_field_type_mapping(&format!("@{raw_name}")),
+      self.reference.as_ref(),
+      self.kind.as_ref(),
+      self.simple_type.as_ref(),
+    ) {
+      (Some(rust_type), _, _, _) => (rust_type, FieldType::Mapped),
+      (None, None, Some(kind), None) => {
+        let rust_type = RustTypesMapping::get(context, kind)?;
+
+        // the fields of top-level elements are generated outside of the types module
+        let module = (!context.is_in_sub_module()
+          && !RustTypesMapping::is_xs_type(context, kind)
+          && context.get_type_mapping(kind).is_none())
+        .then_some(quote!(xml_schema_types::))
+        .unwrap_or_default();
+
+        (quote!(#module #rust_type), FieldType::Named(kind))
+      }
+      (None, None, None, Some(simple_type)) => (
+        simple_type
+          .get_type_implementation(context, prefix, &context.get_inline_type_name(raw_name))
+          .map_err(|e| e.within("simpleType"))?,
+        FieldType::Simple(simple_type),
+      ),
+      // a reference left unresolved is declared in a namespace mapped to a module
+      (None, Some(reference), None, None) => (
+        RustTypesMapping::get_reference(context, ComponentKind::Attribute, reference)?,
+        FieldType::Attribute(reference),
+      ),
+      (None, _, _, _) => {
+        return Err(Error::new(format!(
+          "Attribute {raw_name:?} requires exactly one of type, ref or simpleType"
+        )))
+      }
+    };
+
+    // an absent attribute with a default or fixed value takes this value
+    let (rust_type, default) = match ValueConstraint::new(&self.default, &self.fixed)? {
+      Some(constraint) => context.get_value_constraints()?.add_field(
+        context,
+        &field_name,
+        field_type,
+        &rust_type,
+        &constraint,
+      )?,
+      None if self.required == Required::Optional => (quote!(Option<#rust_type>), quote!()),
+      // yaserde starts from the default value of a field, which some types do not have
+      None if !has_default(context, field_type)? => {
+        context
+          .get_value_constraints()?
+          .add_required_field(&field_name, format!("attribute {raw_name:?}"));
+        (quote!(Option<#rust_type>), quote!())
+      }
+      None => (rust_type, quote!()),
+    };
+
+    let rename = (&name != raw_name)
+      .then_some(quote!(, rename=#raw_name))
+      .unwrap_or_default();
+
+    // a qualified attribute is in the target namespace, an unqualified one in no namespace
+    let prefix = prefix
+      .as_ref()
+      .filter(|_prefix| context.is_qualified_attribute(&self.form))
+      .map(|prefix| quote!(, prefix=#prefix))
+      .unwrap_or_default();
+
+    Ok(quote!(
+      #[yaserde(attribute #rename #prefix #default)]
+      pub #field_name: #rust_type,
+    ))
+  }
+}
+
+impl Attribute {
+  /// Type generated for the inline simple type of the attribute, next to its structure.
+  pub fn implement_inline_type(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    if let Some((declaration, prefix, context)) = self.get_reference_declaration(prefix, context)? {
+      return declaration.implement_inline_type(namespace_definition, &prefix, &context);
+    }
+
+    match (&self.name, &self.simple_type) {
+      (Some(name), Some(simple_type)) if self.required != Required::Prohibited => simple_type
+        .implement_inline(
+          namespace_definition,
+          prefix,
+          context,
+          &context.get_inline_type_name(name),
+        )
+        .map_err(|e| e.within("simpleType")),
+      _ => Ok(quote!()),
+    }
+  }
+
+  /// Name of the attribute in XML documents, without namespace prefix.
+  pub fn get_local_name(&self) -> &str {
+    match (&self.name, &self.reference) {
+      (Some(name), _) => name,
+      (None, Some(reference)) => reference.rsplit(':').next().unwrap_or_default(),
+      (None, None) => "",
+    }
+  }
+
+  /// Attribute declared by the top-level attribute it refers to, used with the value constraints
+  /// of the reference. It is qualified, with the prefix of its namespace and the context of its schema.
+  fn get_reference_declaration(
+    &self,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<Option<(Attribute, Option<String>, XsdContext)>, Error> {
+    let Some(reference) = self
+      .reference
+      .as_ref()
+      .filter(|_reference| self.name.is_none())
+    else {
+      return Ok(None);
+    };
+
+    let (namespace, local_name) = context.resolve(reference)?;
+
+    let prefix = if namespace == context.get_target_namespace().unwrap_or_default() {
+      prefix.clone()
+    } else {
+      reference
+        .split_once(':')
+        .map(|(prefix, _local_name)| prefix.to_string())
+    };
+
+    let Some((declaration, definition_context)) = context.get_attribute(reference)? else {
+      // attributes of the namespaces mapped to existing modules are not loaded
+      let attribute = Attribute {
+        name: Some(local_name),
+        kind: None,
+        simple_type: None,
+        reference: Some(reference.to_string()),
+        form: Some(Qualification::Qualidified),
+        ..self.clone()
+      };
+      return Ok(Some((attribute, prefix, context.clone())));
+    };
+
+    let attribute = Attribute {
+      name: Some(local_name),
+      kind: declaration.kind,
+      simple_type: declaration.simple_type,
+      default: self.default.clone().or(declaration.default),
+      fixed: self.fixed.clone().or(declaration.fixed),
+      required: self.required.clone(),
+      reference: None,
+      form: Some(Qualification::Qualidified),
+    };
+
+    Ok(Some((attribute, prefix, definition_context)))
+  }
+}
+
+/// Types generated for the inline simple types of attributes.
+pub fn implement_inline_types(
+  attributes: &[Attribute],
+  namespace_definition: &TokenStream,
+  prefix: &Option<String>,
+  context: &XsdContext,
+) -> Result<TokenStream, Error> {
+  attributes
+    .iter()
+    .enumerate()
+    .map(|(index, attribute)| {
+      attribute
+        .implement_inline_type(namespace_definition, prefix, context)
+        .map_err(|e| e.within(step("attribute", None, index)))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::{registry::Registry, schema::Schema};
+  use std::rc::Rc;
+  use std::str::FromStr;
+
+  #[test]
+  fn default_required() {
+    assert_eq!(Required::default(), Required::Optional);
+  }
+
+  #[test]
+  fn string_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      form: None,
+      required: Required::Required,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn optional_string_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: Option<String> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn default_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: Some("en".to_string()),
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_value_constraints(&Ident::new("Document", Span::call_site()));
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, default = "default_document_language")]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename="type")]
+        pub kind: Option<String> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn reference_type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: None,
+      reference: Some("MyType".to_string()),
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename="type")]
+        pub kind: Option<MyType> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn bad_type_attribute() {
+    let attribute = Attribute {
+      name: Some("type".to_string()),
+      kind: None,
+      reference: None,
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert_eq!(
+      attribute
+        .implement(&TokenStream::new(), &None, &context)
+        .unwrap_err(),
+      Error::new("Attribute \"type\" requires exactly one of type, ref or simpleType")
+    );
+  }
+
+  #[test]
+  fn attribute_without_name() {
+    let attribute = Attribute {
+      name: None,
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      form: None,
+      required: Required::Optional,
+      simple_type: None,
+      default: None,
+      fixed: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap()
+      .to_string();
+    assert!(implementation.is_empty());
+  }
+
+  #[test]
+  fn qualified_attribute() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_form_defaults(&None, &Some(Qualification::Qualidified));
+    let prefix = Some("ord".to_string());
+
+    let attribute = Attribute {
+      name: Some("order-id".to_string()),
+      kind: Some("xs:string".to_string()),
+      required: Required::Required,
+      ..Default::default()
+    };
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &prefix, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename = "order-id", prefix = "ord")]
+        pub order_id: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let attribute = Attribute {
+      form: Some(Qualification::Unqualified),
+      ..attribute
+    };
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &prefix, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, rename = "order-id")]
+        pub order_id: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn attribute_reference() {
+    let content = r#"
+      <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:attribute name="status" type="StatusType" default="draft"/>
+        <xs:simpleType name="StatusType">
+          <xs:restriction base="xs:string">
+            <xs:enumeration value="draft"/>
+            <xs:enumeration value="final"/>
+          </xs:restriction>
+        </xs:simpleType>
+      </xs:schema>
+    "#;
+
+    let context = XsdContext::new(content).unwrap();
+    let mut registry = Registry::default();
+    registry
+      .register(&yaserde::de::from_str::<Schema>(content).unwrap(), &context)
+      .unwrap();
+    let context = context
+      .with_registry(&Rc::new(registry))
+      .with_value_constraints(&Ident::new("Document", Span::call_site()));
+
+    let attribute = Attribute {
+      reference: Some("status".to_string()),
+      ..Default::default()
+    };
+
+    let implementation = attribute
+      .implement(&TokenStream::new(), &None, &context)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, default = "default_document_status")]
+        pub status: xml_schema_types::StatusType,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
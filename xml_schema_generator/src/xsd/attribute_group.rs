@@ -0,0 +1,261 @@
+use crate::{
+  error::step,
+  xsd::{attribute::Attribute, Implementation, XsdContext},
+  Error,
+};
+use proc_macro2::TokenStream;
+use std::collections::BTreeSet;
+
+// without prefix, the element of the group itself is not confused with its nested groups
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "attributeGroup",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct AttributeGroup {
+  #[yaserde(prefix = "xs", attribute)]
+  pub name: Option<String>,
+  #[yaserde(rename = "ref", attribute)]
+  pub reference: Option<String>,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+}
+
+/// Attribute fields of the referenced attribute groups, expanded recursively.
+/// A group referenced many times, directly or through other groups, is expanded once.
+/// The `excluded` attributes are redeclared or prohibited by the type using the groups.
+pub fn implement_attribute_groups(
+  attribute_groups: &[AttributeGroup],
+  excluded: &[&str],
+  namespace_definition: &TokenStream,
+  prefix: &Option<String>,
+  context: &XsdContext,
+) -> Result<TokenStream, Error> {
+  expand_attribute_groups(
+    attribute_groups,
+    context,
+    &|attribute, context| {
+      if excluded.contains(&attribute.get_local_name()) {
+        return Ok(quote!());
+      }
+
+      attribute.implement(namespace_definition, prefix, context)
+    },
+    &mut vec![],
+    &mut BTreeSet::new(),
+  )
+}
+
+/// Types generated for the inline simple types of the attributes of the referenced attribute groups,
+/// next to the structure they are expanded in.
+pub fn implement_attribute_groups_inline_types(
+  attribute_groups: &[AttributeGroup],
+  namespace_definition: &TokenStream,
+  prefix: &Option<String>,
+  context: &XsdContext,
+) -> Result<TokenStream, Error> {
+  expand_attribute_groups(
+    attribute_groups,
+    context,
+    &|attribute, context| attribute.implement_inline_type(namespace_definition, prefix, context),
+    &mut vec![],
+    &mut BTreeSet::new(),
+  )
+}
+
+type ImplementAttribute<'a> = dyn Fn(&Attribute, &XsdContext) -> Result<TokenStream, Error> + 'a;
+
+fn expand_attribute_groups(
+  attribute_groups: &[AttributeGroup],
+  context: &XsdContext,
+  implement: &ImplementAttribute,
+  stack: &mut Vec<(String, String)>,
+  expanded: &mut BTreeSet<(String, String)>,
+) -> Result<TokenStream, Error> {
+  attribute_groups
+    .iter()
+    .enumerate()
+    .map(|(index, attribute_group)| {
+      attribute_group
+        .expand(context, implement, stack, expanded)
+        .map_err(|e| e.within(step("attributeGroup", None, index)))
+    })
+    .collect()
+}
+
+impl AttributeGroup {
+  fn expand(
+    &self,
+    context: &XsdContext,
+    implement: &ImplementAttribute,
+    stack: &mut Vec<(String, String)>,
+    expanded: &mut BTreeSet<(String, String)>,
+  ) -> Result<TokenStream, Error> {
+    let Some(reference) = &self.reference else {
+      return Err(Error::new("Missing reference for attribute group"));
+    };
+
+    let (qualified_name, definition, definition_context) =
+      context.get_attribute_group(reference)?;
+
+    if stack.contains(&qualified_name) {
+      return Err(Error::new(format!(
+        "Attribute group {reference:?} references itself"
+      )));
+    }
+
+    if !expanded.insert(qualified_name.clone()) {
+      return Ok(quote!());
+    }
+
+    log::info!("Expand attribute group {:?}", reference);
+
+    let attributes: TokenStream = definition
+      .attributes
+      .iter()
+      .enumerate()
+      .map(|(index, attribute)| {
+        implement(attribute, &definition_context)
+          .map_err(|e| e.within(step("attribute", None, index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    stack.push(qualified_name);
+    let nested_attributes = expand_attribute_groups(
+      &definition.attribute_groups,
+      &definition_context,
+      implement,
+      stack,
+      expanded,
+    );
+    stack.pop();
+
+    let nested_attributes = nested_attributes?;
+
+    Ok(quote!(
+      #attributes
+      #nested_attributes
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::{registry::Registry, schema::Schema};
+  use std::rc::Rc;
+  use std::str::FromStr;
+
+  fn get_context(content: &str) -> XsdContext {
+    let context = XsdContext::new(content).unwrap();
+
+    let mut registry = Registry::default();
+    registry
+      .register(&yaserde::de::from_str::<Schema>(content).unwrap(), &context)
+      .unwrap();
+
+    context.with_registry(&Rc::new(registry))
+  }
+
+  fn reference(reference: &str) -> AttributeGroup {
+    AttributeGroup {
+      reference: Some(reference.to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn nested_attribute_groups() {
+    let context = get_context(
+      r#"
+      <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:attributeGroup name="coreattrs">
+          <xs:attribute name="id" type="xs:ID"/>
+        </xs:attributeGroup>
+        <xs:attributeGroup name="i18n">
+          <xs:attribute name="lang" type="xs:language"/>
+        </xs:attributeGroup>
+        <xs:attributeGroup name="attrs">
+          <xs:attributeGroup ref="coreattrs"/>
+          <xs:attributeGroup ref="i18n"/>
+          <xs:attribute name="class" type="xs:string"/>
+        </xs:attributeGroup>
+      </xs:schema>
+    "#,
+    );
+
+    let implementation = implement_attribute_groups(
+      &[reference("attrs"), reference("i18n")],
+      &[],
+      &TokenStream::new(),
+      &None,
+      &context,
+    )
+    .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub class: Option<String> ,
+        #[yaserde(attribute)]
+        pub id: Option<xml_schema::types::XsToken> ,
+        #[yaserde(attribute)]
+        pub lang: Option<xml_schema::types::XsToken> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn circular_attribute_groups() {
+    let context = get_context(
+      r#"
+      <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:attributeGroup name="first">
+          <xs:attributeGroup ref="second"/>
+        </xs:attributeGroup>
+        <xs:attributeGroup name="second">
+          <xs:attributeGroup ref="first"/>
+        </xs:attributeGroup>
+      </xs:schema>
+    "#,
+    );
+
+    let error = implement_attribute_groups(
+      &[reference("first")],
+      &[],
+      &TokenStream::new(),
+      &None,
+      &context,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "attributeGroup[1]/attributeGroup[1]/attributeGroup[1]: Attribute group \"first\" references itself"
+    );
+  }
+
+  #[test]
+  fn undefined_attribute_group() {
+    let context = get_context(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"/>"#);
+
+    let error = implement_attribute_groups(
+      &[reference("missing")],
+      &[],
+      &TokenStream::new(),
+      &None,
+      &context,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "attributeGroup[1]: Attribute group \"missing\" is not defined"
+    );
+  }
+}
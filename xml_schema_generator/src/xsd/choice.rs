@@ -0,0 +1,882 @@
+use crate::{
+  error::step,
+  xsd::{
+    element::Element,
+    enumeration::get_variant_idents,
+    group::Group,
+    max_occurences::MaxOccurences,
+    mixed,
+    particle::{get_attribute, get_indexed_particles, read_model_group, Particle},
+    registry::ComponentKind,
+    XsdContext,
+  },
+  Error,
+};
+use heck::ToUpperCamelCase;
+use log::info;
+use proc_macro2::TokenStream;
+use std::collections::BTreeSet;
+use std::io::Read;
+use syn::Ident;
+use yaserde::{de::Deserializer, YaDeserialize};
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Choice {
+  pub min_occurences: Option<u64>,
+  pub max_occurences: Option<MaxOccurences>,
+  pub particles: Vec<Particle>,
+}
+
+impl YaDeserialize for Choice {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let (attributes, particles) = read_model_group(reader)?;
+
+    let min_occurences = get_attribute(&attributes, "minOccurs")
+      .map(|value| {
+        value
+          .parse()
+          .map_err(|e| format!("Invalid minOccurs {value:?}: {e}"))
+      })
+      .transpose()?;
+    let max_occurences = get_attribute(&attributes, "maxOccurs")
+      .map(str::parse)
+      .transpose()?;
+
+    Ok(Choice {
+      min_occurences,
+      max_occurences,
+      particles,
+    })
+  }
+}
+
+impl Choice {
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    _prefix: &Option<String>,
+    struct_name: &Ident,
+    index: Option<usize>,
+  ) -> Result<TokenStream, Error> {
+    if self.get_alternatives().is_empty() {
+      return Ok(quote!());
+    }
+
+    let (choice_name, field_name) = Self::get_names(struct_name, index);
+    info!("Generate choice {}", choice_name);
+
+    if self.is_multiple() {
+      let list_name = format_ident!("{}List", choice_name);
+      let field_name = format_ident!("{}_list", field_name);
+
+      Ok(quote!(
+        #[yaserde(flatten)]
+        pub #field_name: #list_name,
+      ))
+    } else if self.min_occurences == Some(0) {
+      Ok(quote!(
+        #[yaserde(flatten)]
+        pub #field_name: Option<#choice_name>,
+      ))
+    } else if !self.has_default(context)? {
+      // yaserde starts from the default value of a field, which the choice does not have
+      context
+        .get_value_constraints()?
+        .add_required_field(&field_name, format!("choice {choice_name}"));
+
+      Ok(quote!(
+        #[yaserde(flatten)]
+        pub #field_name: Option<#choice_name>,
+      ))
+    } else {
+      Ok(quote!(
+        #[yaserde(flatten)]
+        pub #field_name: #choice_name,
+      ))
+    }
+  }
+
+  pub fn get_sub_types_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+    index: Option<usize>,
+  ) -> Result<TokenStream, Error> {
+    let alternatives = self.get_alternatives();
+    if alternatives.is_empty() {
+      return Ok(quote!());
+    }
+
+    let (choice_name, _field_name) = Self::get_names(struct_name, index);
+
+    let variants = Self::get_variants(&alternatives);
+
+    let rust_types: Vec<TokenStream> = alternatives
+      .iter()
+      .zip(variants.iter())
+      .map(|((index, particle), variant)| {
+        Self::get_variant_type(context, &choice_name, variant, particle)
+          .map_err(|e| e.within(step(particle.get_kind(), None, *index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let definitions: TokenStream = variants
+      .iter()
+      .zip(rust_types.iter())
+      .map(|(variant, rust_type)| quote!(#variant(#rust_type),))
+      .collect();
+
+    // the first variant with a default value, elements of restrictions with facets have none
+    let mut default_variant = None;
+    for ((_, particle), variant) in alternatives.iter().zip(variants.iter()) {
+      if Self::has_variant_default(context, particle)? {
+        default_variant = Some(variant);
+        break;
+      }
+    }
+    let default_implementation = default_variant
+      .map(|variant| {
+        quote!(
+          impl Default for #choice_name {
+            fn default() -> Self {
+              #choice_name::#variant(Default::default())
+            }
+          }
+        )
+      })
+      .unwrap_or_default();
+
+    let single_implementation = Self::get_deserialize_implementation(
+      context,
+      &alternatives,
+      &choice_name,
+      &variants,
+      &rust_types,
+      |value| quote!(return Ok(#value);),
+      quote!(Err(format!(
+        "No element of {} found",
+        stringify!(#choice_name)
+      ))),
+    )?;
+
+    let serialize_variants: TokenStream = alternatives
+      .iter()
+      .zip(variants.iter())
+      .map(|((index, particle), variant)| {
+        let substitutes = match particle {
+          Particle::Element(element) => element
+            .get_substitutes(context)
+            .map_err(|e| e.within(step("element", None, *index)))?,
+          _ => vec![],
+        };
+
+        let serialize_value = match particle {
+          // the substitution group writes the element of its variant
+          Particle::Element(_) if !substitutes.is_empty() => {
+            quote!(yaserde::YaSerialize::serialize(value, writer)?;)
+          }
+          Particle::Element(element) => {
+            let prefix = element
+              .get_name_prefix(context, prefix)
+              .map_err(|e| e.within(step("element", None, *index)))?;
+            element.implement_write_value(context, &prefix)
+          }
+          // the elements of a model group or a wildcard are written in the parent element
+          _ => quote!(
+            writer.set_start_event_name(None);
+            writer.set_skip_start_end(true);
+            yaserde::YaSerialize::serialize(value, writer)?;
+          ),
+        };
+
+        Ok::<_, Error>(quote!(
+          #choice_name::#variant(value) => {
+            #serialize_value
+          }
+        ))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let list_implementation = if self.is_multiple() {
+      let list_name = format_ident!("{}List", choice_name);
+
+      let list_deserialize = Self::get_deserialize_implementation(
+        context,
+        &alternatives,
+        &choice_name,
+        &variants,
+        &rust_types,
+        |value| quote!(items.push(#value);),
+        quote!(Ok(#list_name { items })),
+      )?;
+
+      quote!(
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct #list_name {
+          pub items: Vec<#choice_name>,
+        }
+
+        impl yaserde::YaDeserialize for #list_name {
+          fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+            let mut items = vec![];
+            #list_deserialize
+          }
+        }
+
+        impl yaserde::YaSerialize for #list_name {
+          fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+            for item in &self.items {
+              yaserde::YaSerialize::serialize(item, writer)?;
+            }
+
+            Ok(())
+          }
+
+          fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+            Ok((source_attributes, source_namespace))
+          }
+        }
+      )
+    } else {
+      quote!()
+    };
+
+    let sub_types_implementation: TokenStream = alternatives
+      .iter()
+      .zip(variants.iter())
+      .map(|((index, particle), variant)| {
+        Self::get_variant_sub_types(
+          context,
+          namespace_definition,
+          prefix,
+          &choice_name,
+          variant,
+          particle,
+        )
+        .map_err(|e| e.within(step(particle.get_kind(), None, *index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      pub enum #choice_name {
+        #definitions
+      }
+
+      #default_implementation
+
+      impl yaserde::YaDeserialize for #choice_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          #single_implementation
+        }
+      }
+
+      impl yaserde::YaSerialize for #choice_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          match self {
+            #serialize_variants
+          }
+
+          Ok(())
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+
+      #list_implementation
+
+      #sub_types_implementation
+    ))
+  }
+
+  /// Check if the enumeration of the choice implements `Default`, with one of its variants.
+  fn has_default(&self, context: &XsdContext) -> Result<bool, Error> {
+    for (_, particle) in self.get_alternatives() {
+      if Self::has_variant_default(context, particle)? {
+        return Ok(true);
+      }
+    }
+    Ok(false)
+  }
+
+  /// Check if the type of a variant implements `Default`, the structures of nested model groups
+  /// and wildcards always do.
+  fn has_variant_default(context: &XsdContext, particle: &Particle) -> Result<bool, Error> {
+    match particle {
+      Particle::Element(element) => element.has_default(context),
+      _ => Ok(true),
+    }
+  }
+
+  /// Rust type of a variant: the type of an element, the structure of a nested model group
+  /// or the elements matched by a wildcard.
+  fn get_variant_type(
+    context: &XsdContext,
+    choice_name: &Ident,
+    variant: &Ident,
+    particle: &Particle,
+  ) -> Result<TokenStream, Error> {
+    let type_name = format_ident!("{}{}", choice_name, variant);
+
+    match particle {
+      Particle::Element(element) => match element.get_substitution_type(context, false)? {
+        Some(substitution_type) => Ok(substitution_type),
+        None => element.get_type_implementation(context),
+      },
+      Particle::Sequence(_) | Particle::Choice(_) => Ok(quote!(#type_name)),
+      Particle::Group(group) => {
+        let group_type = group.get_type_implementation(context, &None)?;
+        let module = (!context.is_in_sub_module())
+          .then_some(quote!(xml_schema_types::))
+          .unwrap_or_default();
+
+        Ok(quote!(#module#group_type))
+      }
+      Particle::Any(_) => Ok(quote!(xml_schema::types::XsAny<#type_name>)),
+    }
+  }
+
+  /// Types used by a variant: the inline type of an element, the structure of a nested
+  /// sequence or choice, the constraints of a wildcard. Referenced groups are generated apart.
+  fn get_variant_sub_types(
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    choice_name: &Ident,
+    variant: &Ident,
+    particle: &Particle,
+  ) -> Result<TokenStream, Error> {
+    let type_name = format_ident!("{}{}", choice_name, variant);
+    let context = &context.with_value_constraints(&type_name);
+
+    let (fields, sub_types) = match particle {
+      Particle::Element(element) => {
+        return element.get_subtypes_implementation(namespace_definition, prefix, context)
+      }
+      Particle::Sequence(sequence) => (
+        sequence.get_field_implementation(context, prefix, &type_name)?,
+        sequence.get_sub_types_implementation(context, namespace_definition, prefix, &type_name)?,
+      ),
+      Particle::Choice(choice) => (
+        choice.get_field_implementation(context, prefix, &type_name, None)?,
+        choice.get_sub_types_implementation(
+          context,
+          namespace_definition,
+          prefix,
+          &type_name,
+          None,
+        )?,
+      ),
+      Particle::Group(_) => return Ok(quote!()),
+      Particle::Any(wildcard) => {
+        return wildcard.implement_constraints(context, &type_name, ComponentKind::Element, &[])
+      }
+    };
+
+    let (derive_default, value_constraints) =
+      context.get_value_constraints()?.implement(&fields)?;
+    let (derive_deserialize, deserialize) = mixed::implement_derive_deserialize(
+      false,
+      &context
+        .get_value_constraints()?
+        .get_required_fields(&fields)?,
+      namespace_definition,
+      &type_name,
+      &fields,
+    )?;
+
+    Ok(quote!(
+      #[derive(Clone, Debug, #derive_default PartialEq, #derive_deserialize yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #type_name {
+        #fields
+      }
+
+      #deserialize
+      #value_constraints
+
+      #sub_types
+    ))
+  }
+
+  /// Choices are flattened into their parent structure: the reader receives the parent element
+  /// with all child elements not consumed by the other fields.
+  ///
+  /// A nested model group is selected by the names of its elements, the consecutive elements
+  /// of the group are then read as its structure. A wildcard matches the elements not
+  /// selected by the other variants.
+  fn get_deserialize_implementation<F: Fn(TokenStream) -> TokenStream>(
+    context: &XsdContext,
+    alternatives: &[(usize, &Particle)],
+    choice_name: &Ident,
+    variants: &[Ident],
+    rust_types: &[TokenStream],
+    action: F,
+    result: TokenStream,
+  ) -> Result<TokenStream, Error> {
+    let mut selected_names = BTreeSet::new();
+    let mut match_variants = vec![];
+    let mut match_wildcards = vec![];
+
+    for (((index, particle), variant), rust_type) in alternatives
+      .iter()
+      .zip(variants.iter())
+      .zip(rust_types.iter())
+    {
+      let action = action(quote!(#choice_name::#variant(value)));
+      let substitutes = match particle {
+        Particle::Element(element) => element
+          .get_substitutes(context)
+          .map_err(|e| e.within(step("element", None, *index)))?,
+        _ => vec![],
+      };
+
+      match particle {
+        Particle::Element(_) if !substitutes.is_empty() => {
+          let selecting_names: Vec<&str> = substitutes
+            .iter()
+            .map(|substitute| substitute.element.name.as_str())
+            .filter(|name| selected_names.insert(name.to_string()))
+            .collect();
+          if selecting_names.is_empty() {
+            continue;
+          }
+
+          // the substitution group reads the element of its variant in a parent element
+          match_variants.push(quote!(
+            #(#selecting_names)|* => {
+              let element = <xml_schema::types::XmlElement as yaserde::YaDeserialize>::deserialize(reader)?;
+              let mut group = xml_schema::types::XmlElement::new(xml::name::OwnedName::local(stringify!(#choice_name)));
+              group.children.push(xml_schema::types::XmlNode::Element(element));
+              let value = group.deserialize_as::<#rust_type>()?;
+              #action
+            }
+          ));
+        }
+        Particle::Element(element) => {
+          let element_name = element.get_local_name();
+          if !selected_names.insert(element_name.to_string()) {
+            continue;
+          }
+
+          let read_value = element.implement_read_value(context, rust_type);
+
+          match_variants.push(quote!(
+            #element_name => {
+              #read_value
+              #action
+            }
+          ));
+        }
+        Particle::Any(_) => {
+          let wildcard_name = format_ident!("{}{}", choice_name, variant);
+
+          match_wildcards.push(quote!(
+            _ if <#wildcard_name as xml_schema::types::Wildcard>::matches(name)? => {
+              let element = <xml_schema::types::XmlElement as yaserde::YaDeserialize>::deserialize(reader)?;
+              let value = xml_schema::types::XsAny::from(vec![element]);
+              #action
+            }
+          ));
+        }
+        _ => {
+          let group_names = Self::get_group_names(context, particle)
+            .map_err(|e| e.within(step(particle.get_kind(), None, *index)))?;
+
+          let names: Vec<&str> = group_names.iter().map(|(name, _)| name.as_str()).collect();
+          let repeated: Vec<&str> = group_names
+            .iter()
+            .filter(|(_, repeated)| *repeated)
+            .map(|(name, _)| name.as_str())
+            .collect();
+          let selecting_names: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| selected_names.insert(name.to_string()))
+            .collect();
+          if selecting_names.is_empty() {
+            continue;
+          }
+
+          // the group is read as the element of its structure, derived structures skip their root
+          let label = Self::get_group_label(choice_name, variant, particle);
+
+          match_variants.push(quote!(
+            #(#selecting_names)|* => {
+              let group = xml_schema::types::XmlElement::read_group(reader, #label, &[#(#names),*], &[#(#repeated),*])?;
+              let value = group.deserialize_as::<#rust_type>()?;
+              #action
+            }
+          ));
+        }
+      }
+    }
+
+    Ok(quote!(
+      let start_depth = reader.depth();
+
+      loop {
+        match reader.peek()?.to_owned() {
+          xml::reader::XmlEvent::StartElement { ref name, .. } => {
+            if reader.depth() == start_depth {
+              reader.next_event()?;
+              continue;
+            }
+
+            match name.local_name.as_str() {
+              #(#match_variants)*
+              #(#match_wildcards)*
+              _ => {
+                reader.next_event()?;
+                reader.skip_element(|_event| {})?;
+              }
+            }
+          }
+          xml::reader::XmlEvent::EndElement { .. } | xml::reader::XmlEvent::EndDocument => {
+            break;
+          }
+          _ => {
+            reader.next_event()?;
+          }
+        }
+      }
+
+      #result
+    ))
+  }
+
+  /// Name of the root element of the structure of a nested model group.
+  fn get_group_label(choice_name: &Ident, variant: &Ident, particle: &Particle) -> String {
+    match particle {
+      Particle::Group(Group {
+        reference: Some(reference),
+        ..
+      }) => reference
+        .split(':')
+        .next_back()
+        .unwrap_or_default()
+        .to_upper_camel_case(),
+      _ => format!("{choice_name}{variant}"),
+    }
+  }
+
+  /// Local names of the elements of a model group of the choice, with whether they can be
+  /// repeated, referenced groups being resolved in the loaded schemas.
+  fn get_group_names(
+    context: &XsdContext,
+    particle: &Particle,
+  ) -> Result<Vec<(String, bool)>, Error> {
+    let mut names: Vec<(String, bool)> = vec![];
+    Self::collect_group_names(context, particle, false, &mut names)?;
+    Ok(names)
+  }
+
+  fn collect_group_names(
+    context: &XsdContext,
+    particle: &Particle,
+    repeated: bool,
+    names: &mut Vec<(String, bool)>,
+  ) -> Result<(), Error> {
+    let collect_particles = |context: &XsdContext,
+                             particles: &[Particle],
+                             repeated: bool,
+                             names: &mut Vec<(String, bool)>| {
+      get_indexed_particles(particles)
+        .into_iter()
+        .try_for_each(|(index, particle)| {
+          Self::collect_group_names(context, particle, repeated, names)
+            .map_err(|e| e.within(step(particle.get_kind(), None, index)))
+        })
+    };
+
+    match particle {
+      Particle::Element(element) => {
+        let repeated = repeated || is_repeated(&element.max_occurences);
+
+        // a reference to the head of a substitution group reads any element of the group
+        let substitutes = element.get_substitutes(context)?;
+        let element_names: Vec<&str> = if substitutes.is_empty() {
+          vec![element.get_local_name()]
+        } else {
+          substitutes
+            .iter()
+            .map(|substitute| substitute.element.name.as_str())
+            .collect()
+        };
+
+        for name in element_names {
+          match names.iter_mut().find(|(other, _)| other == name) {
+            Some((_, other_repeated)) => *other_repeated = true,
+            None if !name.is_empty() => names.push((name.to_string(), repeated)),
+            None => {}
+          }
+        }
+
+        Ok(())
+      }
+      Particle::Sequence(sequence) => {
+        collect_particles(context, &sequence.particles, repeated, names)
+      }
+      Particle::Choice(choice) => collect_particles(
+        context,
+        &choice.particles,
+        repeated || choice.is_multiple(),
+        names,
+      ),
+      Particle::Group(group) => {
+        let Some(reference) = &group.reference else {
+          return Err(Error::new("Missing reference for group"));
+        };
+
+        let Some((definition, definition_context)) = context.get_group(reference)? else {
+          return Err(Error::new(format!(
+            "Group {reference:?} is not defined in the loaded schemas, its elements can not be read in a choice"
+          )));
+        };
+
+        if let Some(sequence) = &definition.sequence {
+          collect_particles(&definition_context, &sequence.particles, repeated, names)
+            .map_err(|e| e.within("sequence"))?;
+        }
+
+        if let Some(choice) = &definition.choice {
+          collect_particles(
+            &definition_context,
+            &choice.particles,
+            repeated || choice.is_multiple(),
+            names,
+          )
+          .map_err(|e| e.within("choice"))?;
+        }
+
+        Ok(())
+      }
+      Particle::Any(_) => Err(Error::new(
+        "xs:any is not supported in a model group of a choice",
+      )),
+    }
+  }
+
+  fn get_names(struct_name: &Ident, index: Option<usize>) -> (Ident, Ident) {
+    match index {
+      Some(index) => (
+        format_ident!("{}Choice{}", struct_name, index + 1),
+        format_ident!("choice_{}", index + 1),
+      ),
+      None => (
+        format_ident!("{}Choice", struct_name),
+        format_ident!("choice"),
+      ),
+    }
+  }
+
+  /// Variant names of the alternatives, colliding names being made unique.
+  /// Nested model groups are named after their kind, or the referenced group.
+  fn get_variants(alternatives: &[(usize, &Particle)]) -> Vec<Ident> {
+    let names: Vec<&str> = alternatives
+      .iter()
+      .map(|(_index, particle)| match particle {
+        Particle::Element(element) => element.get_local_name(),
+        Particle::Group(Group {
+          reference: Some(reference),
+          ..
+        }) => reference.split(':').next_back().unwrap_or_default(),
+        _ => particle.get_kind(),
+      })
+      .collect();
+
+    get_variant_idents(&names)
+  }
+
+  /// Particles of the choice with their position among the particles of the same kind,
+  /// used to locate errors.
+  fn get_alternatives(&self) -> Vec<(usize, &Particle)> {
+    get_indexed_particles(&self.particles)
+      .into_iter()
+      .filter(|(_index, particle)| match particle {
+        Particle::Element(element) => !element.get_local_name().is_empty(),
+        _ => true,
+      })
+      .collect()
+  }
+
+  /// Elements with their position in the choice, used to locate errors.
+  /// Nested model groups and wildcards are not supported in mixed content.
+  pub fn get_elements(&self) -> Result<Vec<(usize, &Element)>, Error> {
+    let mut elements = vec![];
+
+    for (index, particle) in get_indexed_particles(&self.particles) {
+      match particle {
+        Particle::Element(element) => {
+          if !element.get_local_name().is_empty() {
+            elements.push((index, element.as_ref()));
+          }
+        }
+        _ => {
+          return Err(
+            Error::new(format!(
+              "xs:{} is not supported in a choice of mixed content",
+              particle.get_kind()
+            ))
+            .within(step(particle.get_kind(), None, index)),
+          )
+        }
+      }
+    }
+
+    Ok(elements)
+  }
+
+  /// Repeated elements inside a choice are handled as repetitions of the choice itself.
+  fn is_multiple(&self) -> bool {
+    is_repeated(&self.max_occurences)
+      || self.particles.iter().any(|particle| match particle {
+        Particle::Element(element) => is_repeated(&element.max_occurences),
+        _ => false,
+      })
+  }
+}
+
+fn is_repeated(max_occurences: &Option<MaxOccurences>) -> bool {
+  max_occurences.is_some() && max_occurences != &Some(MaxOccurences::Number { value: 1 })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+  use std::str::FromStr;
+
+  #[test]
+  fn choice_field_implementation() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Contact", Span::call_site());
+
+    let mut choice = Choice {
+      min_occurences: None,
+      max_occurences: None,
+      particles: vec![Particle::Element(Box::new(Element {
+        name: "email".to_string(),
+        kind: Some("xs:string".to_string()),
+        ..Default::default()
+      }))],
+    };
+
+    let implementation = choice
+      .get_field_implementation(&context, &None, &struct_name, None)
+      .unwrap();
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(flatten)] pub choice: ContactChoice,"#).unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    choice.min_occurences = Some(0);
+    let implementation = choice
+      .get_field_implementation(&context, &None, &struct_name, Some(1))
+      .unwrap();
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(flatten)] pub choice_2: Option<ContactChoice2> ,"#)
+        .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    choice.max_occurences = Some(MaxOccurences::Unbounded);
+    let implementation = choice
+      .get_field_implementation(&context, &None, &struct_name, None)
+      .unwrap();
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(flatten)] pub choice_list: ContactChoiceList,"#).unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn empty_choice() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Contact", Span::call_site());
+
+    let choice = Choice::default();
+
+    assert!(choice
+      .get_field_implementation(&context, &None, &struct_name, None)
+      .unwrap()
+      .is_empty());
+    assert!(choice
+      .get_sub_types_implementation(&context, &TokenStream::new(), &None, &struct_name, None)
+      .unwrap()
+      .is_empty());
+  }
+
+  #[test]
+  fn unique_variants() {
+    let choice: Choice = yaserde::de::from_str(
+      r#"<xs:choice xmlns:xs="http://www.w3.org/2001/XMLSchema" maxOccurs="unbounded">
+        <xs:element name="a-b" type="xs:string"/>
+        <xs:element name="aB" type="xs:string"/>
+        <xs:element name="self" type="xs:string"/>
+      </xs:choice>"#,
+    )
+    .unwrap();
+
+    assert_eq!(choice.max_occurences, Some(MaxOccurences::Unbounded));
+
+    let variants: Vec<String> = Choice::get_variants(&choice.get_alternatives())
+      .iter()
+      .map(|variant| variant.to_string())
+      .collect();
+
+    assert_eq!(variants, vec!["AB0", "AB1", "Self_"]);
+  }
+
+  #[test]
+  fn nested_particles() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let choice: Choice = yaserde::de::from_str(
+      r#"<xs:choice xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:element name="email" type="xs:string"/>
+        <xs:sequence>
+          <xs:element name="street" type="xs:string"/>
+          <xs:choice maxOccurs="unbounded">
+            <xs:element name="line" type="xs:string"/>
+            <xs:element name="street" type="xs:string"/>
+          </xs:choice>
+        </xs:sequence>
+        <xs:group ref="phone"/>
+        <xs:any processContents="lax"/>
+      </xs:choice>"#,
+    )
+    .unwrap();
+
+    let alternatives = choice.get_alternatives();
+    let variants: Vec<String> = Choice::get_variants(&alternatives)
+      .iter()
+      .map(|variant| variant.to_string())
+      .collect();
+
+    assert_eq!(variants, vec!["Email", "Sequence", "Phone", "Any"]);
+    assert_eq!(
+      Choice::get_group_names(&context, alternatives[1].1).unwrap(),
+      vec![("street".to_string(), true), ("line".to_string(), true)]
+    );
+    assert_eq!(
+      choice.get_elements().unwrap_err().to_string(),
+      "sequence[1]: xs:sequence is not supported in a choice of mixed content"
+    );
+  }
+}
@@ -0,0 +1,331 @@
+use crate::{
+  xsd::{
+    attribute::Attribute, attribute_group::AttributeGroup, complex_type::ComplexType,
+    extension::Extension, group::Group, restriction::Restriction,
+    rust_types_mapping::RustTypesMapping, xsd_context::XsdContext,
+  },
+  Error,
+};
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct ComplexContent {
+  #[yaserde(attribute)]
+  pub mixed: bool,
+  pub extension: Option<Extension>,
+  pub restriction: Option<Restriction>,
+}
+
+impl ComplexContent {
+  /// Qualified name of the base type of the derivation.
+  pub fn get_base(&self) -> Option<&str> {
+    match (&self.extension, &self.restriction) {
+      (Some(extension), _) => Some(extension.base.as_str()),
+      (None, Some(restriction)) => restriction.base.as_deref(),
+      (None, None) => None,
+    }
+  }
+
+  /// Type with the content model and the attributes declared by the derivation of `complex_type`,
+  /// and the step of the derivation in the component path. A derivation by restriction keeps
+  /// the attributes of its base type which it does not prohibit.
+  pub fn get_derived_type(
+    &self,
+    complex_type: &ComplexType,
+    context: &XsdContext,
+  ) -> Result<(ComplexType, &'static str), Error> {
+    self.derive(complex_type, context, &mut vec![])
+  }
+
+  fn derive(
+    &self,
+    complex_type: &ComplexType,
+    context: &XsdContext,
+    visited: &mut Vec<(String, String)>,
+  ) -> Result<(ComplexType, &'static str), Error> {
+    let mixed = complex_type.mixed || self.mixed;
+
+    match (&self.extension, &self.restriction) {
+      (Some(extension), None) => Ok((
+        ComplexType {
+          name: complex_type.name.clone(),
+          mixed,
+          attributes: extension.attributes.clone(),
+          attribute_groups: extension.attribute_groups.clone(),
+          any_attribute: extension.any_attribute.clone(),
+          sequence: extension.sequences.first().cloned(),
+          choice: extension.choice.clone(),
+          annotation: complex_type.annotation.clone(),
+          ..Default::default()
+        },
+        "extension",
+      )),
+      (None, Some(restriction)) => {
+        let (base_attributes, base_attribute_groups) = match &restriction.base {
+          Some(base) => get_attribute_uses(context, base, visited)?,
+          None => (vec![], vec![]),
+        };
+
+        // attributes redeclared by the restriction replace the ones of the base type,
+        // prohibited ones generate no field
+        let declared_attributes: Vec<&str> = restriction
+          .attributes
+          .iter()
+          .map(Attribute::get_local_name)
+          .collect();
+
+        let attributes = base_attributes
+          .into_iter()
+          .filter(|attribute| !declared_attributes.contains(&attribute.get_local_name()))
+          .chain(restriction.attributes.iter().cloned())
+          .collect();
+
+        let attribute_groups = base_attribute_groups
+          .into_iter()
+          .chain(restriction.attribute_groups.iter().cloned())
+          .collect();
+
+        Ok((
+          ComplexType {
+            name: complex_type.name.clone(),
+            mixed,
+            attributes,
+            attribute_groups,
+            any_attribute: restriction.any_attribute.clone(),
+            sequence: restriction.sequence.clone(),
+            choice: restriction.choice.clone(),
+            annotation: complex_type.annotation.clone(),
+            ..Default::default()
+          },
+          "restriction",
+        ))
+      }
+      _ => Err(Error::new(
+        "complexContent requires either an extension or a restriction",
+      )),
+    }
+  }
+
+  /// Fields of the base type and of the group of the derivation, before the fields of the derived type.
+  /// A derivation by extension reads the content of its base type in a flattened `base` field,
+  /// a derivation by restriction declares the whole content of the type.
+  pub fn get_base_fields(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> Result<TokenStream, Error> {
+    if let Some(extension) = &self.extension {
+      let base = implement_base(context, &extension.base).map_err(|e| e.within("extension"))?;
+      let group =
+        implement_group(context, prefix, &extension.group).map_err(|e| e.within("extension"))?;
+
+      return Ok(quote!(
+        #base
+        #group
+      ));
+    }
+
+    self
+      .restriction
+      .as_ref()
+      .map(|restriction| {
+        implement_group(context, prefix, &restriction.group).map_err(|e| e.within("restriction"))
+      })
+      .transpose()
+      .map(Option::unwrap_or_default)
+  }
+}
+
+/// Attributes and attribute groups of a complex type, the ones inherited from its base types included.
+/// Types defined outside of the loaded schemas and `xs:anyType` have none.
+fn get_attribute_uses(
+  context: &XsdContext,
+  reference: &str,
+  visited: &mut Vec<(String, String)>,
+) -> Result<(Vec<Attribute>, Vec<AttributeGroup>), Error> {
+  if RustTypesMapping::is_xs_type(context, reference) {
+    return Ok((vec![], vec![]));
+  }
+
+  let qualified_name = context.resolve(reference)?;
+  if visited.contains(&qualified_name) {
+    return Err(Error::new(format!(
+      "Complex type {reference:?} derives from itself"
+    )));
+  }
+
+  let Some((complex_type, definition_context)) = context.get_complex_type(reference)? else {
+    return Ok((vec![], vec![]));
+  };
+
+  let Some(complex_content) = &complex_type.complex_content else {
+    return Ok((complex_type.attributes, complex_type.attribute_groups));
+  };
+
+  visited.push(qualified_name);
+  let (derived_type, derivation) =
+    complex_content.derive(&complex_type, &definition_context, visited)?;
+
+  let (mut attributes, mut attribute_groups) = match (derivation, complex_content.get_base()) {
+    ("extension", Some(base)) => get_attribute_uses(&definition_context, base, visited)?,
+    _ => (vec![], vec![]),
+  };
+  visited.pop();
+
+  attributes.extend(derived_type.attributes);
+  attribute_groups.extend(derived_type.attribute_groups);
+
+  Ok((attributes, attribute_groups))
+}
+
+/// Every type derives from `xs:anyType`, which has no field.
+fn implement_base(context: &XsdContext, base: &str) -> Result<TokenStream, Error> {
+  if RustTypesMapping::is_xs_type(context, base) {
+    return match base.rsplit(':').next() {
+      Some("anyType") => Ok(quote!()),
+      _ => Err(Error::new(format!(
+        "Base type {base:?} of a complex content must be a complex type"
+      ))),
+    };
+  }
+
+  let rust_type = RustTypesMapping::get(context, base)?;
+  let module = (!context.is_in_sub_module())
+    .then_some(quote!(xml_schema_types::))
+    .unwrap_or_default();
+
+  Ok(quote!(
+    #[yaserde(flatten)]
+    pub base: #module#rust_type,
+  ))
+}
+
+fn implement_group(
+  context: &XsdContext,
+  prefix: &Option<String>,
+  group: &Option<Group>,
+) -> Result<TokenStream, Error> {
+  let Some(group) = group else {
+    return Ok(quote!());
+  };
+
+  let group_type = group
+    .get_type_implementation(context, prefix)
+    .map_err(|e| e.within("group"))?;
+  let module = (!context.is_in_sub_module())
+    .then_some(quote!(xml_schema_types::))
+    .unwrap_or_default();
+
+  Ok(quote!(
+    #[yaserde(flatten)]
+    pub group: #module#group_type,
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::sequence::Sequence;
+  use std::str::FromStr;
+
+  #[test]
+  fn extension_base_fields() {
+    let complex_content = ComplexContent {
+      extension: Some(Extension {
+        base: "Person".to_string(),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let mut context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    context.set_is_in_sub_module(true);
+
+    let implementation = complex_content.get_base_fields(&context, &None).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub base: Person,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn restriction_of_any_type() {
+    let complex_content = ComplexContent {
+      restriction: Some(Restriction {
+        base: Some("xs:anyType".to_string()),
+        attributes: vec![Attribute {
+          name: Some("lang".to_string()),
+          kind: Some("xs:string".to_string()),
+          ..Default::default()
+        }],
+        sequence: Some(Sequence::default()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let complex_type = ComplexType {
+      name: "Label".to_string(),
+      complex_content: Some(complex_content.clone()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let (derived_type, step) = complex_content
+      .get_derived_type(&complex_type, &context)
+      .unwrap();
+    assert_eq!(step, "restriction");
+    assert_eq!(derived_type.name, "Label");
+    assert_eq!(derived_type.attributes.len(), 1);
+    assert!(derived_type.sequence.is_some());
+
+    assert!(complex_content
+      .get_base_fields(&context, &None)
+      .unwrap()
+      .is_empty());
+  }
+
+  #[test]
+  fn invalid_complex_content() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let complex_content = ComplexContent::default();
+    assert_eq!(
+      complex_content
+        .get_derived_type(&ComplexType::default(), &context)
+        .unwrap_err()
+        .message(),
+      "complexContent requires either an extension or a restriction"
+    );
+
+    let complex_content = ComplexContent {
+      extension: Some(Extension {
+        base: "xs:string".to_string(),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let error = complex_content
+      .get_base_fields(&context, &None)
+      .unwrap_err();
+    assert_eq!(
+      error.message(),
+      "Base type \"xs:string\" of a complex content must be a complex type"
+    );
+    assert_eq!(error.path(), "extension");
+  }
+}
@@ -0,0 +1,471 @@
+use crate::{
+  error::step,
+  xsd::{
+    annotation::Annotation,
+    attribute::{implement_inline_types, Attribute},
+    attribute_group::{
+      implement_attribute_groups, implement_attribute_groups_inline_types, AttributeGroup,
+    },
+    choice::Choice,
+    complex_content::ComplexContent,
+    mixed::{self, MixedElement},
+    sequence::Sequence,
+    simple_content::SimpleContent,
+    wildcard::Wildcard,
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use std::collections::BTreeSet;
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "complexType"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct ComplexType {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(attribute)]
+  pub mixed: bool,
+  #[yaserde(rename = "abstract", attribute)]
+  pub is_abstract: bool,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  #[yaserde(rename = "anyAttribute")]
+  pub any_attribute: Option<Wildcard>,
+  pub sequence: Option<Sequence>,
+  pub choice: Option<Choice>,
+  #[yaserde(rename = "simpleContent")]
+  pub simple_content: Option<SimpleContent>,
+  #[yaserde(rename = "complexContent")]
+  pub complex_content: Option<ComplexContent>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Implementation for ComplexType {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let context = &context.with_value_constraints(&self.get_struct_name());
+
+    // a type derived from a complex type has the fields of its base before its own ones
+    if let Some(complex_content) = &self.complex_content {
+      let (derived_type, derivation) = complex_content
+        .get_derived_type(self, context)
+        .map_err(|e| e.within("complexContent"))?;
+      let base_fields = complex_content
+        .get_base_fields(context, prefix)
+        .map_err(|e| e.within("complexContent"))?;
+
+      return derived_type
+        .implement_content(namespace_definition, prefix, context, base_fields)
+        .map_err(|e| e.within(derivation).within("complexContent"));
+    }
+
+    self.implement_content(namespace_definition, prefix, context, quote!())
+  }
+}
+
+impl ComplexType {
+  fn implement_content(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    base_fields: TokenStream,
+  ) -> Result<TokenStream, Error> {
+    let struct_name = self.get_struct_name();
+    let context = &context.with_path(&self.name);
+
+    let (fields, any_attribute_constraints) =
+      self.get_content_fields(namespace_definition, prefix, context, &struct_name)?;
+
+    let sub_types_implementation =
+      self.get_sub_types_implementation(context, namespace_definition, prefix, &struct_name)?;
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context))
+      .transpose()?
+      .unwrap_or_default();
+
+    let fields = quote!(
+      #base_fields
+      #fields
+    );
+    let (derive_default, value_constraints) =
+      context.get_value_constraints()?.implement(&fields)?;
+    let (derive_deserialize, deserialize) = mixed::implement_derive_deserialize(
+      self.mixed,
+      &context
+        .get_value_constraints()?
+        .get_required_fields(&fields)?,
+      namespace_definition,
+      &struct_name,
+      &fields,
+    )?;
+
+    Ok(quote! {
+      #docs
+
+      #[derive(Clone, Debug, #derive_default PartialEq, #derive_deserialize yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #fields
+      }
+
+      #deserialize
+      #value_constraints
+
+      #any_attribute_constraints
+      #sub_types_implementation
+    })
+  }
+
+  /// Fields of the content and of the attributes of the type, without the ones of its base type,
+  /// with the constraints of its attribute wildcard.
+  fn get_content_fields(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    log::info!("Generate sequence");
+    let sequence = self
+      .sequence
+      .as_ref()
+      .filter(|_sequence| !self.mixed)
+      .map(|sequence| {
+        sequence
+          .implement_childs(namespace_definition, prefix, context, struct_name)
+          .map_err(|e| e.within("sequence"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    log::info!("Generate choice");
+    let choice = self
+      .choice
+      .as_ref()
+      .filter(|_choice| !self.mixed)
+      .map(|choice| {
+        choice
+          .get_field_implementation(context, prefix, struct_name, None)
+          .map_err(|e| e.within("choice"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    // text and child elements of a mixed type are read in a single list, in document order
+    let mixed_content = if self.mixed {
+      mixed::get_field_implementation(struct_name)
+    } else {
+      quote!()
+    };
+
+    log::info!("Generate simple content");
+    let simple_content = self
+      .simple_content
+      .as_ref()
+      .map(|simple_content| {
+        simple_content
+          .implement(namespace_definition, prefix, context)
+          .map_err(|e| e.within("simpleContent"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let attributes: TokenStream = self
+      .attributes
+      .iter()
+      .enumerate()
+      .map(|(index, attribute)| {
+        attribute
+          .implement(namespace_definition, prefix, context)
+          .map_err(|e| e.within(step("attribute", None, index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let declared_attributes: Vec<&str> = self
+      .attributes
+      .iter()
+      .map(Attribute::get_local_name)
+      .collect();
+
+    let attribute_groups = implement_attribute_groups(
+      &self.attribute_groups,
+      &declared_attributes,
+      namespace_definition,
+      prefix,
+      context,
+    )?;
+
+    let (any_attributes, any_attribute_constraints) = self
+      .get_any_attribute()
+      .map(|(any_attribute, path)| {
+        any_attribute
+          .implement_any_attribute(
+            context,
+            struct_name,
+            &quote!(#simple_content #attributes #attribute_groups),
+          )
+          .map_err(|e| e.within(path))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let fields = quote!(
+      #sequence
+      #choice
+      #mixed_content
+      #simple_content
+      #attributes
+      #attribute_groups
+      #any_attributes
+    );
+
+    Ok((fields, any_attribute_constraints))
+  }
+
+  /// Fields of an anonymous type, in the structure of its element,
+  /// with the constraints of its attribute wildcard.
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    if let Some(complex_content) = &self.complex_content {
+      let (derived_type, derivation) = complex_content
+        .get_derived_type(self, context)
+        .map_err(|e| e.within("complexContent"))?;
+      let base_fields = complex_content
+        .get_base_fields(context, prefix)
+        .map_err(|e| e.within("complexContent"))?;
+      let (fields, constraints) = derived_type
+        .get_content_fields(namespace_definition, prefix, context, struct_name)
+        .map_err(|e| e.within(derivation).within("complexContent"))?;
+
+      return Ok((
+        quote!(
+          #base_fields
+          #fields
+        ),
+        constraints,
+      ));
+    }
+
+    self.get_content_fields(namespace_definition, prefix, context, struct_name)
+  }
+
+  pub fn get_sub_types_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    if let Some(complex_content) = &self.complex_content {
+      let (derived_type, derivation) = complex_content
+        .get_derived_type(self, context)
+        .map_err(|e| e.within("complexContent"))?;
+
+      return derived_type
+        .get_sub_types_implementation(context, namespace_definition, prefix, struct_name)
+        .map_err(|e| e.within(derivation).within("complexContent"));
+    }
+
+    let sequence = self
+      .sequence
+      .as_ref()
+      .map(|sequence| {
+        sequence
+          .get_sub_types_implementation(context, namespace_definition, prefix, struct_name)
+          .map_err(|e| e.within("sequence"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let choice = self
+      .choice
+      .as_ref()
+      .map(|choice| {
+        choice
+          .get_sub_types_implementation(context, namespace_definition, prefix, struct_name, None)
+          .map_err(|e| e.within("choice"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let mixed_content = if self.mixed {
+      mixed::get_sub_types_implementation(
+        context,
+        prefix,
+        struct_name,
+        &self.get_mixed_elements()?,
+      )?
+    } else {
+      quote!()
+    };
+
+    let attributes =
+      implement_inline_types(&self.attributes, namespace_definition, prefix, context)?;
+    let attribute_groups = implement_attribute_groups_inline_types(
+      &self.attribute_groups,
+      namespace_definition,
+      prefix,
+      context,
+    )?;
+
+    let simple_content_attributes = self
+      .simple_content
+      .as_ref()
+      .map(|simple_content| {
+        let extension = &simple_content.extension;
+        let attributes =
+          implement_inline_types(&extension.attributes, namespace_definition, prefix, context)?;
+        let attribute_groups = implement_attribute_groups_inline_types(
+          &extension.attribute_groups,
+          namespace_definition,
+          prefix,
+          context,
+        )?;
+
+        Ok::<_, Error>(quote!(
+          #attributes
+          #attribute_groups
+        ))
+      })
+      .transpose()
+      .map_err(|e| e.within("extension").within("simpleContent"))?
+      .unwrap_or_default();
+
+    Ok(quote!(
+      #sequence
+      #choice
+      #mixed_content
+      #attributes
+      #attribute_groups
+      #simple_content_attributes
+    ))
+  }
+
+  /// Child elements of a mixed type, from its sequence and choices, an element repeated
+  /// in many places of the content model being listed once.
+  fn get_mixed_elements(&self) -> Result<Vec<MixedElement<'_>>, Error> {
+    let mut mixed_elements = vec![];
+
+    if let Some(sequence) = &self.sequence {
+      for (index, element) in sequence.get_elements() {
+        let path = vec!["sequence".to_string(), step("element", None, index)];
+        mixed_elements.push(MixedElement { path, element });
+      }
+
+      for (choice_index, choice) in sequence.get_choices() {
+        let choice_path = vec!["sequence".to_string(), step("choice", None, choice_index)];
+        let elements = choice.get_elements().map_err(|e| {
+          choice_path
+            .iter()
+            .rev()
+            .fold(e, |e, step| e.within(step.clone()))
+        })?;
+
+        for (index, element) in elements {
+          let mut path = choice_path.clone();
+          path.push(step("element", None, index));
+          mixed_elements.push(MixedElement { path, element });
+        }
+      }
+    }
+
+    if let Some(choice) = &self.choice {
+      let elements = choice.get_elements().map_err(|e| e.within("choice"))?;
+
+      for (index, element) in elements {
+        let path = vec!["choice".to_string(), step("element", None, index)];
+        mixed_elements.push(MixedElement { path, element });
+      }
+    }
+
+    let mut names = BTreeSet::new();
+    mixed_elements.retain(|mixed_element| {
+      let name = mixed_element.element.get_local_name();
+      !name.is_empty() && names.insert(name.to_string())
+    });
+
+    Ok(mixed_elements)
+  }
+
+  /// Qualified name of the complex type this type derives from.
+  pub fn get_base(&self) -> Option<&str> {
+    self
+      .complex_content
+      .as_ref()
+      .and_then(|complex_content| complex_content.get_base())
+  }
+
+  /// Wildcard of the attributes, declared by the type or its simple content.
+  fn get_any_attribute(&self) -> Option<(&Wildcard, &'static str)> {
+    self
+      .any_attribute
+      .as_ref()
+      .map(|any_attribute| (any_attribute, "anyAttribute"))
+      .or_else(|| {
+        self
+          .simple_content
+          .as_ref()
+          .and_then(|simple_content| simple_content.extension.any_attribute.as_ref())
+          .map(|any_attribute| (any_attribute, "simpleContent/extension/anyAttribute"))
+      })
+  }
+
+  /// Check if the type, or the derivation of its complex content, has mixed content.
+  pub fn is_mixed(&self) -> bool {
+    self.mixed
+      || self
+        .complex_content
+        .as_ref()
+        .is_some_and(|complex_content| complex_content.mixed)
+  }
+
+  fn get_struct_name(&self) -> Ident {
+    Ident::new(
+      &self.name.replace('.', "_").to_upper_camel_case(),
+      Span::call_site(),
+    )
+  }
+
+  pub fn get_integrated_implementation(&self, parent_name: &str) -> TokenStream {
+    if self.simple_content.is_some() {
+      return quote!(String);
+    }
+
+    if self.sequence.is_some()
+      || self.choice.is_some()
+      || self.mixed
+      || self.complex_content.is_some()
+    {
+      let list_wrapper = Ident::new(
+        &parent_name.replace('.', "_").to_upper_camel_case(),
+        Span::call_site(),
+      );
+      return quote!(#list_wrapper);
+    }
+
+    quote!(String)
+  }
+}
@@ -0,0 +1,298 @@
+use crate::{
+  xsd::{complex_type::ComplexType, rust_types_mapping::RustTypesMapping, XsdContext},
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Name of the enumeration of the types derived from a complex type.
+pub fn get_name(name: &str) -> String {
+  format!("{}Derived", name.replace('.', "_").to_upper_camel_case())
+}
+
+/// Enumeration of a top-level complex type and of the types derived from it,
+/// dispatched by the `xsi:type` attribute of the element.
+/// Nothing is generated if no type derives from the complex type.
+pub fn implement(
+  context: &XsdContext,
+  prefix: &Option<String>,
+  complex_type: &ComplexType,
+) -> Result<TokenStream, Error> {
+  let namespace = context.get_target_namespace().unwrap_or_default();
+  let derived_types = context.get_derived_types(namespace, &complex_type.name);
+  if derived_types.is_empty() {
+    return Ok(quote!());
+  }
+
+  let enum_name = format_ident!("{}", get_name(&complex_type.name));
+  let base_name = &complex_type.name;
+
+  let variants: Vec<Ident> = derived_types
+    .iter()
+    .map(|derived_type| {
+      Ident::new(
+        &derived_type.name.replace('.', "_").to_upper_camel_case(),
+        Span::call_site(),
+      )
+    })
+    .collect();
+
+  let rust_types: Vec<TokenStream> = derived_types
+    .iter()
+    .map(|derived_type| {
+      RustTypesMapping::get_qualified(context, &derived_type.namespace, &derived_type.name)
+    })
+    .collect::<Result<_, _>>()?;
+
+  let deserialize_variants: TokenStream = derived_types
+    .iter()
+    .zip(variants.iter())
+    .zip(rust_types.iter())
+    .map(|((derived_type, variant), rust_type)| {
+      let type_namespace = &derived_type.namespace;
+      let type_name = &derived_type.name;
+
+      quote!(
+        Some((#type_namespace, #type_name)) => {
+          <#rust_type as yaserde::YaDeserialize>::deserialize(reader).map(#enum_name::#variant)
+        }
+      )
+    })
+    .collect();
+
+  // an element without `xsi:type` has the declared type, which cannot be abstract
+  let first_variant = &variants[0];
+  let untyped = if complex_type.is_abstract {
+    quote!(Err(format!(
+      "Element of the abstract type {} requires an xsi:type attribute",
+      #base_name
+    )))
+  } else {
+    quote!(yaserde::YaDeserialize::deserialize(reader).map(#enum_name::#first_variant))
+  };
+
+  // the type is written with the prefix bound in its schema
+  let serialize_variants: TokenStream = derived_types
+    .iter()
+    .zip(variants.iter())
+    .map(|(derived_type, variant)| {
+      if !complex_type.is_abstract
+        && derived_type.namespace == namespace
+        && derived_type.name == complex_type.name
+      {
+        return quote!(
+          #enum_name::#variant(value) => yaserde::YaSerialize::serialize(value, writer),
+        );
+      }
+
+      let type_namespace = &derived_type.namespace;
+      let (type_prefix, qualified_name) = match (type_namespace.is_empty(), &derived_type.prefix) {
+        (true, _) => (quote!(None), derived_type.name.clone()),
+        (false, type_prefix) => {
+          let type_prefix = type_prefix
+            .clone()
+            .or_else(|| {
+              prefix
+                .clone()
+                .filter(|_| derived_type.namespace == namespace)
+            })
+            .unwrap_or_else(|| "ns".to_string());
+          let qualified_name = format!("{type_prefix}:{}", derived_type.name);
+          (quote!(Some(#type_prefix)), qualified_name)
+        }
+      };
+
+      quote!(
+        #enum_name::#variant(value) => {
+          write_typed_element(writer, value, #type_prefix, #type_namespace, #qualified_name)
+        }
+      )
+    })
+    .collect();
+
+  Ok(quote!(
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum #enum_name {
+      #(#variants(#rust_types),)*
+    }
+
+    impl Default for #enum_name {
+      fn default() -> Self {
+        #enum_name::#first_variant(Default::default())
+      }
+    }
+
+    impl yaserde::YaDeserialize for #enum_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        let xsi_type = match reader.peek()? {
+          xml::reader::XmlEvent::StartElement { attributes, namespace, .. } => attributes
+            .iter()
+            .find(|attribute| {
+              attribute.name.local_name == "type"
+                && attribute.name.namespace.as_deref() == Some(#XSI_NAMESPACE)
+            })
+            .map(|attribute| {
+              let (type_prefix, type_name) = attribute
+                .value
+                .trim()
+                .split_once(':')
+                .unwrap_or(("", attribute.value.trim()));
+              let type_namespace = namespace.get(type_prefix).unwrap_or_default().to_string();
+              (type_namespace, type_name.to_string())
+            }),
+          _ => None,
+        };
+
+        match xsi_type.as_ref().map(|(type_namespace, type_name)| (type_namespace.as_str(), type_name.as_str())) {
+          #deserialize_variants
+          None => #untyped,
+          Some((type_namespace, type_name)) => Err(format!(
+            "Type {{{}}}{} is not a type derived from {}",
+            type_namespace, type_name, #base_name
+          )),
+        }
+      }
+    }
+
+    impl yaserde::YaSerialize for #enum_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        /// Write the element with an `xsi:type` attribute naming the type of its `value`.
+        fn write_typed_element<W: std::io::Write, T: yaserde::YaSerialize>(
+          writer: &mut yaserde::ser::Serializer<W>,
+          value: &T,
+          type_prefix: Option<&str>,
+          type_namespace: &str,
+          qualified_name: &str,
+        ) -> Result<(), String> {
+          // a flattened value has no element to carry the type
+          if writer.skip_start_end() {
+            return value.serialize(writer);
+          }
+
+          let element_name = writer
+            .get_start_event_name()
+            .ok_or_else(|| format!("Missing element name of the type {}", qualified_name))?;
+
+          let mut namespace = xml::namespace::Namespace::empty();
+          namespace.put("xsi", #XSI_NAMESPACE);
+          if let Some(type_prefix) = type_prefix {
+            namespace.put(type_prefix, type_namespace);
+          }
+
+          let xsi_type = xml::attribute::OwnedAttribute::new(
+            xml::name::OwnedName::qualified("type", #XSI_NAMESPACE, Some("xsi")),
+            qualified_name,
+          );
+          let (attributes, namespace) = value.serialize_attributes(vec![xsi_type], namespace)?;
+
+          writer
+            .write(xml::writer::XmlEvent::StartElement {
+              name: xml::name::Name::from(element_name.as_str()),
+              attributes: std::borrow::Cow::Owned(attributes.iter().map(|attribute| attribute.borrow()).collect()),
+              namespace: std::borrow::Cow::Owned(namespace),
+            })
+            .map_err(|e| e.to_string())?;
+
+          writer.set_start_event_name(None);
+          writer.set_skip_start_end(true);
+          value.serialize(writer)?;
+
+          writer
+            .write(xml::writer::XmlEvent::end_element())
+            .map_err(|e| e.to_string())
+        }
+
+        match self {
+          #serialize_variants
+        }
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::{registry::Registry, schema::Schema};
+  use std::rc::Rc;
+
+  static SCHEMA: &str = r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:org="http://example.com/org"
+      targetNamespace="http://example.com/org"
+      >
+      <xs:complexType name="Party" abstract="true"/>
+      <xs:complexType name="Person">
+        <xs:complexContent>
+          <xs:extension base="org:Party"/>
+        </xs:complexContent>
+      </xs:complexType>
+      <xs:complexType name="Company">
+        <xs:complexContent>
+          <xs:extension base="org:Party"/>
+        </xs:complexContent>
+      </xs:complexType>
+      <xs:complexType name="Address"/>
+    </xs:schema>
+  "#;
+
+  fn context() -> XsdContext {
+    let context = XsdContext::new(SCHEMA)
+      .unwrap()
+      .with_target_namespace(&Some("http://example.com/org".to_string()));
+
+    let mut registry = Registry::default();
+    registry
+      .register(&yaserde::de::from_str::<Schema>(SCHEMA).unwrap(), &context)
+      .unwrap();
+
+    let mut context = context.with_registry(&Rc::new(registry));
+    context.set_is_in_sub_module(true);
+    context
+  }
+
+  #[test]
+  fn derived_types() {
+    let complex_type = ComplexType {
+      name: "Party".to_string(),
+      is_abstract: true,
+      ..Default::default()
+    };
+
+    let implementation = implement(&context(), &None, &complex_type)
+      .unwrap()
+      .to_string();
+
+    let expected = quote!(
+      pub enum PartyDerived {
+        Company(Company),
+        Person(Person),
+      }
+    );
+    assert!(implementation.contains(&expected.to_string()));
+
+    assert!(implementation.contains(r#"Some (("http://example.com/org" , "Company")) =>"#));
+    assert!(implementation.contains(r#"Some ("org") , "http://example.com/org" , "org:Person""#));
+    assert!(implementation.contains("requires an xsi:type attribute"));
+  }
+
+  #[test]
+  fn no_derived_types() {
+    let complex_type = ComplexType {
+      name: "Address".to_string(),
+      ..Default::default()
+    };
+
+    assert!(implement(&context(), &None, &complex_type)
+      .unwrap()
+      .is_empty());
+  }
+}
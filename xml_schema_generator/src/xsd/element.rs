@@ -0,0 +1,1020 @@
+use crate::{
+  xsd::{
+    annotation::Annotation,
+    complex_type::ComplexType,
+    max_occurences::MaxOccurences,
+    mixed,
+    qualification::Qualification,
+    registry::{Component, ComponentKind, Substitute},
+    rust_types_mapping::RustTypesMapping,
+    simple_type::{implement_deserialize_from_str, SimpleType},
+    value_constraint::{has_default, FieldType, ValueConstraint},
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Element {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(rename = "type", attribute)]
+  pub kind: Option<String>,
+  #[yaserde(rename = "ref", attribute)]
+  pub refers: Option<String>,
+  #[yaserde(rename = "minOccurs", attribute)]
+  pub min_occurences: Option<u64>,
+  #[yaserde(rename = "maxOccurs", attribute)]
+  pub max_occurences: Option<MaxOccurences>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
+  #[yaserde(attribute)]
+  pub nillable: bool,
+  #[yaserde(attribute)]
+  pub form: Option<Qualification>,
+  #[yaserde(rename = "abstract", attribute)]
+  pub is_abstract: bool,
+  #[yaserde(rename = "substitutionGroup", attribute)]
+  pub substitution_group: Option<String>,
+  #[yaserde(rename = "complexType")]
+  pub complex_type: Option<ComplexType>,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Option<SimpleType>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+impl Implementation for Element {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let struct_name = Ident::new(
+      &self.name.replace('.', "_").to_upper_camel_case(),
+      Span::call_site(),
+    );
+
+    let context = &context
+      .with_child_path(&self.name)
+      .with_value_constraints(&struct_name);
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context))
+      .transpose()?
+      .unwrap_or_default();
+
+    if let Some((rust_type, inline_type)) =
+      self.get_simple_content_type(namespace_definition, prefix, context)?
+    {
+      let implementation =
+        self.implement_simple_content(prefix, context, &struct_name, &rust_type)?;
+
+      return Ok(quote! {
+        #docs
+        #implementation
+        #inline_type
+      });
+    }
+
+    let (fields, extra_structs) = if let Some(kind) = &self.kind {
+      let extern_type = RustTypesMapping::get(context, kind)?;
+
+      // yaserde reads the text content of a structure as a `String` only,
+      // complex types read the content of the element
+      let (subtype_mode, extern_type) = if RustTypesMapping::is_xs_string(context, kind) {
+        (quote!(text), quote!(String))
+      } else if context.get_type_mapping(kind).is_some() {
+        (quote!(flatten), extern_type)
+      } else {
+        (quote!(flatten), quote!(xml_schema_types::#extern_type))
+      };
+
+      (
+        quote!(
+          #[yaserde(#subtype_mode)]
+          pub content: #extern_type,
+        ),
+        quote!(),
+      )
+    } else if let Some(complex_type) = &self.complex_type {
+      let (fields_definition, constraints) = complex_type
+        .get_field_implementation(context, namespace_definition, prefix, &struct_name)
+        .map_err(|e| e.within("complexType"))?;
+
+      let sub_types_implementation = complex_type
+        .get_sub_types_implementation(context, namespace_definition, prefix, &struct_name)
+        .map_err(|e| e.within("complexType"))?;
+
+      (
+        fields_definition,
+        quote!(
+          #constraints
+          #sub_types_implementation
+        ),
+      )
+    } else {
+      (quote!(), quote!())
+    };
+
+    let (derive_default, value_constraints) =
+      context.get_value_constraints()?.implement(&fields)?;
+    let mixed = self
+      .complex_type
+      .as_ref()
+      .is_some_and(ComplexType::is_mixed);
+    let (derive_deserialize, deserialize) = mixed::implement_derive_deserialize(
+      mixed,
+      &context
+        .get_value_constraints()?
+        .get_required_fields(&fields)?,
+      namespace_definition,
+      &struct_name,
+      &fields,
+    )?;
+
+    Ok(quote! {
+      #docs
+      #[derive(Clone, Debug, #derive_default PartialEq, #derive_deserialize yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #fields
+      }
+
+      #deserialize
+      #value_constraints
+
+      #extra_structs
+    })
+  }
+}
+
+impl Element {
+  /// Rust type of the content of a top-level element with a simple type other than a string,
+  /// with the implementation of its inline type. `None` for complex types and strings,
+  /// read by yaserde, and for types configured in the type mappings.
+  fn get_simple_content_type(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<Option<(TokenStream, TokenStream)>, Error> {
+    if let Some(kind) = &self.kind {
+      if RustTypesMapping::is_xs_string(context, kind) || context.get_type_mapping(kind).is_some() {
+        return Ok(None);
+      }
+
+      let rust_type = RustTypesMapping::get(context, kind)?;
+
+      if RustTypesMapping::is_xs_type(context, kind) {
+        return Ok(Some((rust_type, quote!())));
+      }
+
+      if matches!(
+        context.lookup(ComponentKind::Type, kind)?,
+        Some(Component::SimpleType)
+      ) {
+        return Ok(Some((quote!(xml_schema_types::#rust_type), quote!())));
+      }
+
+      return Ok(None);
+    }
+
+    let Some(simple_type) = &self.simple_type else {
+      return Ok(None);
+    };
+
+    if simple_type.is_plain_restriction() {
+      return Ok(Some((self.get_type_implementation(context)?, quote!())));
+    }
+
+    // generated next to the element, named after its content
+    let name = context.get_inline_type_name("content");
+    let inline_type = simple_type
+      .implement_inline(namespace_definition, prefix, context, &name)
+      .map_err(|e| e.within("simpleType"))?;
+
+    Ok(Some((quote!(#name), inline_type)))
+  }
+
+  /// Structure of a top-level element with a simple type, its text content is read and written
+  /// with the `FromStr` and `Display` implementations of the `rust_type`.
+  /// It implements `Default` when its content does.
+  fn implement_simple_content(
+    &self,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+    rust_type: &TokenStream,
+  ) -> Result<TokenStream, Error> {
+    let element_name = self.get_qualified_name(prefix);
+
+    let namespace = match (prefix, context.get_target_namespace()) {
+      (Some(prefix), Some(namespace)) => quote!(.ns(#prefix, #namespace)),
+      _ => quote!(),
+    };
+
+    let deserialize_implementation = implement_deserialize_from_str(struct_name);
+    let derive_default = self
+      .has_default(context)?
+      .then_some(quote!(Default,))
+      .unwrap_or_default();
+
+    Ok(quote! {
+      #[derive(Clone, Debug, #derive_default PartialEq)]
+      pub struct #struct_name {
+        pub content: #rust_type,
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          let content = value.parse::<#rust_type>().map_err(|e| e.to_string())?;
+          Ok(#struct_name { content })
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          std::fmt::Display::fmt(&self.content, formatter)
+        }
+      }
+
+      #deserialize_implementation
+
+      impl yaserde::YaSerialize for #struct_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          let skip_start_end = writer.skip_start_end();
+
+          if !skip_start_end {
+            let label = writer.get_start_event_name().unwrap_or_else(|| #element_name.to_string());
+            writer.write(xml::writer::XmlEvent::start_element(label.as_str())#namespace).map_err(|e| e.to_string())?;
+          }
+
+          let content = self.content.to_string();
+          writer.write(xml::writer::XmlEvent::characters(&content)).map_err(|e| e.to_string())?;
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+          }
+
+          Ok(())
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    })
+  }
+
+  pub fn get_subtypes_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    if let Some(simple_type) = &self.simple_type {
+      return simple_type
+        .implement_inline(
+          namespace_definition,
+          prefix,
+          context,
+          &context.get_inline_type_name(&self.name),
+        )
+        .map_err(|e| e.within("simpleType"));
+    }
+
+    if self.complex_type.is_none() {
+      return Ok(quote!());
+    }
+
+    self.implement(namespace_definition, prefix, context)
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> Result<TokenStream, Error> {
+    let field_name = if !self.name.is_empty() {
+      &self.name
+    } else if let Some(refers) = self.get_refers() {
+      refers
+    } else {
+      return Ok(quote!());
+    };
+
+    let multiple = self.max_occurences.is_some()
+      && self.max_occurences != Some(MaxOccurences::Number { value: 1 });
+
+    let name = if self.name.to_lowercase() == "type" {
+      "kind".to_string()
+    } else {
+      field_name.to_snake_case()
+    };
+
+    log::info!("Generate element {:?}", name);
+
+    let name = if multiple {
+      format!("{name}_list")
+    } else {
+      name
+    };
+
+    let attribute_name = Ident::new(&name, Span::call_site());
+
+    // a reference to the head of a substitution group accepts any element of the group
+    if let Some(substitution_type) = self.get_substitution_type(context, multiple)? {
+      let rust_type = if multiple {
+        quote!(#substitution_type)
+      } else if self.min_occurences == Some(0) {
+        quote!(Option<#substitution_type>)
+      } else if !self.has_default(context)? {
+        self.add_required_field(context, &attribute_name)?;
+        quote!(Option<#substitution_type>)
+      } else {
+        quote!(#substitution_type)
+      };
+
+      return Ok(quote! {
+        #[yaserde(flatten)]
+        pub #attribute_name: #rust_type,
+      });
+    }
+
+    let rust_type = self.get_type_implementation(context)?;
+    let nillable = self.is_nillable(context)?;
+
+    // a missing nillable element is distinguished from a nil one by the wrapper
+    let rust_type = match (nillable, self.is_basic_type(context)) {
+      (true, true) => quote!(xml_schema::types::XsNillableValue<#rust_type>),
+      (true, false) => quote!(xml_schema::types::XsNillable<#rust_type>),
+      (false, _) => rust_type,
+    };
+
+    // values of repeated and nillable elements are not filled in
+    let value_constraint = if multiple || nillable {
+      None
+    } else {
+      ValueConstraint::new(&self.default, &self.fixed)?
+    };
+
+    let (rust_type, default) = match value_constraint {
+      Some(constraint) => context.get_value_constraints()?.add_field(
+        context,
+        &attribute_name,
+        self.get_field_type(context)?,
+        &rust_type,
+        &constraint,
+      )?,
+      None if multiple => (quote!(Vec<#rust_type>), quote!()),
+      None if self.min_occurences == Some(0) && !nillable => (quote!(Option<#rust_type>), quote!()),
+      // yaserde starts from the default value of a field, which some types do not have
+      None if !nillable && !self.has_default(context)? => {
+        self.add_required_field(context, &attribute_name)?;
+        (quote!(Option<#rust_type>), quote!())
+      }
+      None => (rust_type, quote!()),
+    };
+
+    // a referenced element is named by its local name, in the namespace of its prefix
+    let yaserde_rename = self.get_local_name();
+    let prefix_attribute = self
+      .get_name_prefix(context, prefix)?
+      .map(|prefix| quote!(, prefix=#prefix))
+      .unwrap_or_default();
+
+    Ok(quote! {
+      #[yaserde(rename=#yaserde_rename #prefix_attribute #default)]
+      pub #attribute_name: #rust_type,
+    })
+  }
+
+  /// Register the field of a required element generated as an `Option`, to reject the
+  /// structure without the element.
+  fn add_required_field(&self, context: &XsdContext, field_name: &Ident) -> Result<(), Error> {
+    let description = format!("element {:?}", self.get_local_name());
+    context
+      .get_value_constraints()?
+      .add_required_field(field_name, description);
+    Ok(())
+  }
+
+  /// Rust type of the element content, including the module where it is generated.
+  pub fn get_type_implementation(&self, context: &XsdContext) -> Result<TokenStream, Error> {
+    if let Some(rust_type) = self.get_type_mapping(context) {
+      return Ok(rust_type);
+    }
+
+    let rust_type = if let Some(complex_type) = &self.complex_type {
+      complex_type.get_integrated_implementation(&self.name)
+    } else if let Some(simple_type) = &self.simple_type {
+      simple_type
+        .get_type_implementation(
+          context,
+          &Some(self.name.to_owned()),
+          &context.get_inline_type_name(&self.name),
+        )
+        .map_err(|e| e.within("simpleType"))?
+    } else if let Some(kind) = &self.kind {
+      if self.has_derived_types(context, kind) {
+        RustTypesMapping::get_derived_types(context, kind)?
+      } else {
+        RustTypesMapping::get(context, kind)?
+      }
+    } else if let Some(refers) = self.get_refers() {
+      if let Some((declaration, declaration_context)) = context.get_element(refers)? {
+        return Element::get_declared_type_implementation(
+          context,
+          refers,
+          &declaration,
+          &declaration_context,
+        );
+      }
+
+      // elements of the namespaces mapped to existing modules are not loaded
+      RustTypesMapping::get_reference(context, ComponentKind::Element, refers)?
+    } else {
+      return Err(Error::new(format!("Element {:?} has no type", self.name)));
+    };
+
+    // inline types are generated next to their parent structure
+    let module =
+      (!context.is_in_sub_module() && !self.has_inline_type() && !self.is_basic_type(context))
+        .then_some(quote!(xml_schema_types::))
+        .unwrap_or_default();
+
+    Ok(quote!(#module#rust_type))
+  }
+
+  /// Rust type of a reference to a top-level element: the type of its declaration,
+  /// or the structure generated for the element when its type is declared inline.
+  fn get_declared_type_implementation(
+    context: &XsdContext,
+    refers: &str,
+    declaration: &Element,
+    declaration_context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    if !declaration.has_inline_type() {
+      return declaration.get_type_implementation(declaration_context);
+    }
+
+    let (namespace, name) = context.resolve(refers)?;
+    if namespace != context.get_target_namespace().unwrap_or_default() {
+      return Err(Error::new(format!(
+        "Element {refers:?} of another namespace with an inline type is not supported as a reference"
+      )));
+    }
+
+    // top-level elements are generated in the parent module of the types
+    let module = if context.is_in_sub_module() {
+      "super::"
+    } else {
+      ""
+    };
+
+    RustTypesMapping::type_path(module.to_string(), &name)
+  }
+
+  /// Type of the element which its default or fixed value is checked against.
+  fn get_field_type<'a>(&'a self, context: &XsdContext) -> Result<FieldType<'a>, Error> {
+    if self.get_type_mapping(context).is_some() {
+      return Ok(FieldType::Mapped);
+    }
+
+    match (&self.complex_type, &self.simple_type, &self.kind) {
+      (Some(complex_type), _, _) => Ok(FieldType::Complex(complex_type)),
+      (None, Some(simple_type), _) => Ok(FieldType::Simple(simple_type)),
+      (None, None, Some(kind)) => Ok(FieldType::Named(kind)),
+      (None, None, None) => Err(Error::new(format!(
+        "Element {:?} requires a type for its default or fixed value",
+        self.name
+      ))),
+    }
+  }
+
+  /// Check if the element declares its own complex type, or a simple type with facets,
+  /// generated next to its parent structure.
+  fn has_inline_type(&self) -> bool {
+    self.complex_type.is_some()
+      || self
+        .simple_type
+        .as_ref()
+        .is_some_and(|simple_type| !simple_type.is_plain_restriction())
+  }
+
+  /// Check if other complex types derive from the type of the element,
+  /// its content is then typed by the `xsi:type` attribute.
+  fn has_derived_types(&self, context: &XsdContext, kind: &str) -> bool {
+    !RustTypesMapping::is_xs_type(context, kind)
+      && context
+        .resolve(kind)
+        .map(|(namespace, name)| !context.get_derived_types(&namespace, &name).is_empty())
+        .unwrap_or_default()
+  }
+
+  /// Check if the Rust type of the element implements `Default`, a required element of a type
+  /// without it is generated as an `Option`.
+  pub fn has_default(&self, context: &XsdContext) -> Result<bool, Error> {
+    if self.get_type_mapping(context).is_some() {
+      return Ok(true);
+    }
+
+    match (&self.complex_type, &self.simple_type, &self.kind) {
+      (Some(_), _, _) => Ok(true),
+      (None, Some(simple_type), _) => has_default(context, FieldType::Simple(simple_type)),
+      (None, None, Some(kind)) => has_default(context, FieldType::Named(kind)),
+      (None, None, None) => {
+        let Some(refers) = self.get_refers() else {
+          return Ok(true);
+        };
+
+        // the enumeration of a substitution group defaults to its first element with a default
+        let substitutes = self.get_substitutes(context)?;
+        if !substitutes.is_empty() {
+          for substitute in substitutes {
+            if substitute.element.has_default(&substitute.context)? {
+              return Ok(true);
+            }
+          }
+          return Ok(false);
+        }
+
+        match context.get_element(refers)? {
+          Some((declaration, declaration_context)) => declaration.has_default(&declaration_context),
+          None => Ok(true),
+        }
+      }
+    }
+  }
+
+  /// Rust type configured for the element, by its path or the qualified name of its type.
+  fn get_type_mapping(&self, context: &XsdContext) -> Option<TokenStream> {
+    context
+      .get_field_type_mapping(self.get_local_name())
+      .or_else(|| {
+        self
+          .kind
+          .as_ref()
+          .and_then(|kind| context.get_type_mapping(kind))
+      })
+  }
+
+  /// Check if the element content is an XML Schema built-in type,
+  /// a type configured in the type mappings is (de)serialized as a structure.
+  pub fn is_basic_type(&self, context: &XsdContext) -> bool {
+    if self.get_type_mapping(context).is_some() {
+      return false;
+    }
+
+    if let Some(kind) = &self.kind {
+      return RustTypesMapping::is_xs_type(context, kind);
+    }
+
+    if let Some(refers) = self.get_refers().filter(|_refers| !self.has_type()) {
+      return context.get_element(refers).ok().flatten().is_some_and(
+        |(declaration, declaration_context)| declaration.is_basic_type(&declaration_context),
+      );
+    }
+
+    self
+      .simple_type
+      .as_ref()
+      .filter(|simple_type| simple_type.is_plain_restriction())
+      .and_then(|simple_type| simple_type.restriction.as_ref())
+      .and_then(|restriction| restriction.base.as_ref())
+      .map(|base| RustTypesMapping::is_xs_type(context, base))
+      .unwrap_or_default()
+  }
+
+  /// Read the element at the position of the reader in a `value` of its `rust_type`,
+  /// for the content types implementing their own deserialization.
+  pub fn implement_read_value(&self, context: &XsdContext, rust_type: &TokenStream) -> TokenStream {
+    if self.is_basic_type(context) {
+      quote!(
+        let value = reader.read_inner_value::<#rust_type, _>(|reader| {
+          if let Ok(xml::reader::XmlEvent::Characters(text_content)) = reader.peek() {
+            let value = text_content.parse::<#rust_type>().map_err(|e| e.to_string());
+            reader.next_event()?;
+            value
+          } else {
+            "".parse::<#rust_type>().map_err(|e| e.to_string())
+          }
+        })?;
+      )
+    } else {
+      quote!(
+        let value = <#rust_type as yaserde::YaDeserialize>::deserialize(reader)?;
+        reader.next_event()?;
+      )
+    }
+  }
+
+  /// Write a `value` as the element, for the content types implementing their own serialization.
+  pub fn implement_write_value(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> TokenStream {
+    let element_name = self.get_qualified_name(prefix);
+
+    if self.is_basic_type(context) {
+      quote!(
+        writer.write(xml::writer::XmlEvent::start_element(#element_name)).map_err(|e| e.to_string())?;
+        writer.write(xml::writer::XmlEvent::characters(&value.to_string())).map_err(|e| e.to_string())?;
+        writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+      )
+    } else {
+      quote!(
+        writer.set_start_event_name(Some(#element_name.to_string()));
+        writer.set_skip_start_end(false);
+        yaserde::YaSerialize::serialize(value, writer)?;
+      )
+    }
+  }
+
+  /// Name of the element in XML documents, without namespace prefix.
+  pub fn get_local_name(&self) -> &str {
+    if !self.name.is_empty() {
+      return &self.name;
+    }
+
+    self
+      .get_refers()
+      .map(|refers| refers.rsplit(':').next().unwrap_or_default())
+      .unwrap_or_default()
+  }
+
+  /// Prefix of the name of the element in documents: a reference to a top-level element is
+  /// always qualified, with the prefix of the reference for an element of another namespace,
+  /// a local element only with its `form` or the `elementFormDefault` of its schema.
+  pub fn get_name_prefix(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> Result<Option<String>, Error> {
+    let Some(refers) = self.get_refers().filter(|_refers| self.name.is_empty()) else {
+      return Ok(
+        prefix
+          .clone()
+          .filter(|_prefix| context.is_qualified_element(&self.form)),
+      );
+    };
+
+    let (namespace, _local_name) = context.resolve(refers)?;
+
+    if namespace == context.get_target_namespace().unwrap_or_default() {
+      Ok(prefix.clone())
+    } else {
+      Ok(
+        refers
+          .split_once(':')
+          .map(|(prefix, _local_name)| prefix.to_string()),
+      )
+    }
+  }
+
+  pub fn get_qualified_name(&self, prefix: &Option<String>) -> String {
+    match prefix {
+      Some(prefix) => format!("{prefix}:{}", self.get_local_name()),
+      None => self.get_local_name().to_string(),
+    }
+  }
+
+  /// Qualified names of the heads of the substitution groups the element belongs to.
+  pub fn get_substitution_groups(&self) -> impl Iterator<Item = &str> {
+    self
+      .substitution_group
+      .iter()
+      .flat_map(|substitution_group| substitution_group.split_whitespace())
+  }
+
+  /// Check if the type of the element is declared, otherwise it is the one of its substitution group head.
+  pub fn has_type(&self) -> bool {
+    self.kind.is_some() || self.complex_type.is_some() || self.simple_type.is_some()
+  }
+
+  /// Check if the element, or the top-level element it refers to, accepts `xsi:nil`.
+  fn is_nillable(&self, context: &XsdContext) -> Result<bool, Error> {
+    if self.nillable {
+      return Ok(true);
+    }
+
+    let Some(refers) = self.get_refers() else {
+      return Ok(false);
+    };
+
+    Ok(matches!(
+      context.lookup(ComponentKind::Element, refers)?,
+      Some(Component::Element { nillable: true })
+    ))
+  }
+
+  /// Concrete substitutes of the head of the substitution group the element refers to,
+  /// empty if the element is not such a reference.
+  pub fn get_substitutes(&self, context: &XsdContext) -> Result<Vec<Substitute>, Error> {
+    let Some(refers) = self.get_refers().filter(|_refers| self.name.is_empty()) else {
+      return Ok(vec![]);
+    };
+
+    let (namespace, local_name) = context.resolve(refers)?;
+    Ok(context.get_substitutes(&namespace, &local_name))
+  }
+
+  /// Rust type of the substitution group the element refers to, or of its list if `multiple`.
+  /// `None` if the element does not refer to the head of a substitution group.
+  pub fn get_substitution_type(
+    &self,
+    context: &XsdContext,
+    multiple: bool,
+  ) -> Result<Option<TokenStream>, Error> {
+    let Some(refers) = self.get_refers() else {
+      return Ok(None);
+    };
+
+    if self.get_substitutes(context)?.is_empty() {
+      return Ok(None);
+    }
+
+    let substitution_type = RustTypesMapping::get_substitution_group(context, refers, multiple)?;
+    let module = (!context.is_in_sub_module())
+      .then_some(quote!(xml_schema_types::))
+      .unwrap_or_default();
+
+    Ok(Some(quote!(#module#substitution_type)))
+  }
+
+  fn get_refers(&self) -> Option<&str> {
+    self.refers.as_ref().and_then(|refers| {
+      if refers.is_empty() {
+        None
+      } else {
+        Some(refers.as_str())
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  static DERIVES: &str =
+    "#[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]";
+
+  static DOCS: &str = r#"#[doc = "Loudness measured in Decibels"]"#;
+
+  #[test]
+  fn extern_type() {
+    let element = Element {
+      name: "volume".to_string(),
+      kind: Some("books:volume-type".to_string()),
+      refers: None,
+      min_occurences: None,
+      max_occurences: None,
+      default: None,
+      fixed: None,
+      nillable: false,
+      form: None,
+      is_abstract: false,
+      substitution_group: None,
+      complex_type: None,
+      simple_type: None,
+      annotation: Some(Annotation {
+        id: None,
+        attributes: vec![],
+        documentation: vec!["Loudness measured in Decibels".to_string()],
+      }),
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.implement(&quote!(), &None, &context).unwrap();
+
+    let expected = TokenStream::from_str(&format!(
+      r#"
+        {DOCS}
+        {DERIVES}
+        pub struct Volume {{
+          #[yaserde(flatten)]
+          pub content: xml_schema_types::VolumeType,
+        }}"#
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn xs_string_element() {
+    let element = Element {
+      name: "volume".to_string(),
+      kind: Some("xs:string".to_string()),
+      refers: None,
+      min_occurences: None,
+      max_occurences: None,
+      default: None,
+      fixed: None,
+      nillable: false,
+      form: None,
+      is_abstract: false,
+      substitution_group: None,
+      complex_type: None,
+      simple_type: None,
+      annotation: Some(Annotation {
+        id: None,
+        attributes: vec![],
+        documentation: vec!["Loudness measured in Decibels".to_string()],
+      }),
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.implement(&quote!(), &None, &context).unwrap();
+
+    let expected = TokenStream::from_str(&format!(
+      r#"
+        {DOCS}
+        {DERIVES}
+        pub struct Volume {{
+          #[yaserde(text)]
+          pub content: String,
+        }}"#
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn refers_element_field_implementation() {
+    // <xs:element ref="OwnedType" />
+    let element = Element {
+      name: "".to_string(),
+      kind: None,
+      refers: Some("OwnedType".to_string()),
+      min_occurences: None,
+      max_occurences: None,
+      default: None,
+      fixed: None,
+      nillable: false,
+      form: None,
+      is_abstract: false,
+      substitution_group: None,
+      complex_type: None,
+      simple_type: None,
+      annotation: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = element.get_field_implementation(&context, &None).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "OwnedType")] pub owned_type : xml_schema_types :: OwnedType ,"#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    // <xs:element ref="OwnedType"  minOccurs="0" maxOccurs="unbounded" />
+    let element = Element {
+      name: "".to_string(),
+      kind: None,
+      refers: Some("OwnedType".to_string()),
+      min_occurences: Some(0),
+      max_occurences: Some(MaxOccurences::Unbounded),
+      default: None,
+      fixed: None,
+      nillable: false,
+      form: None,
+      is_abstract: false,
+      substitution_group: None,
+      complex_type: None,
+      simple_type: None,
+      annotation: None,
+    };
+
+    let implementation = element.get_field_implementation(&context, &None).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "OwnedType")] pub owned_type_list : Vec < xml_schema_types :: OwnedType > ,"#
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn nillable_element_field_implementation() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    // <xs:element name="age" type="xs:int" nillable="true" minOccurs="0" default="0" />
+    let element = Element {
+      name: "age".to_string(),
+      kind: Some("xs:int".to_string()),
+      min_occurences: Some(0),
+      default: Some("0".to_string()),
+      nillable: true,
+      ..Default::default()
+    };
+
+    let implementation = element.get_field_implementation(&context, &None).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "age")] pub age : xml_schema :: types :: XsNillableValue < i32 > ,"#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    // <xs:element name="address" type="Address" nillable="true" maxOccurs="unbounded" />
+    let element = Element {
+      name: "address".to_string(),
+      kind: Some("Address".to_string()),
+      max_occurences: Some(MaxOccurences::Unbounded),
+      nillable: true,
+      ..Default::default()
+    };
+
+    let implementation = element.get_field_implementation(&context, &None).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "address")] pub address_list : Vec < xml_schema :: types :: XsNillable < xml_schema_types :: Address > > ,"#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn qualified_element_field_implementation() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    let prefix = Some("ord".to_string());
+
+    // <xs:element name="customer" type="xs:string" />
+    let element = Element {
+      name: "customer".to_string(),
+      kind: Some("xs:string".to_string()),
+      ..Default::default()
+    };
+
+    let implementation = element.get_field_implementation(&context, &prefix).unwrap();
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(rename = "customer")] pub customer : String ,"#).unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    // <xs:element name="customer" type="xs:string" form="qualified" />
+    let element = Element {
+      form: Some(Qualification::Qualidified),
+      ..element
+    };
+
+    let implementation = element.get_field_implementation(&context, &prefix).unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "customer", prefix = "ord")] pub customer : String ,"#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    // <xs:element name="customer" type="xs:string" form="unqualified" /> with elementFormDefault="qualified"
+    let context = context.with_form_defaults(&Some(Qualification::Qualidified), &None);
+    let element = Element {
+      form: Some(Qualification::Unqualified),
+      ..element
+    };
+
+    let implementation = element.get_field_implementation(&context, &prefix).unwrap();
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(rename = "customer")] pub customer : String ,"#).unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
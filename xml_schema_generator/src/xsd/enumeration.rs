@@ -0,0 +1,79 @@
+use crate::xsd::annotation::Annotation;
+use heck::ToUpperCamelCase;
+use proc_macro2::Span;
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Enumeration {
+  #[yaserde(attribute)]
+  pub value: String,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
+}
+
+/// Name of the variant of a value, e.g. an enumeration value or the name of an element.
+pub fn get_variant_name(value: &str) -> String {
+  let name = value.replace('.', "_").to_upper_camel_case();
+
+  let name = if name.is_empty() {
+    "Empty".to_string()
+  } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+    format!("Value{name}")
+  } else {
+    name
+  };
+
+  if name == "Self" {
+    format!("{name}_")
+  } else {
+    name
+  }
+}
+
+/// Build one unique variant identifier per value,
+/// values colliding once camel-cased are suffixed with their position.
+pub fn get_variant_idents<S: AsRef<str>>(values: &[S]) -> Vec<Ident> {
+  let names: Vec<String> = values
+    .iter()
+    .map(|value| get_variant_name(value.as_ref()))
+    .collect();
+
+  names
+    .iter()
+    .enumerate()
+    .map(|(index, name)| {
+      let name = if names.iter().filter(|other| *other == name).count() > 1 {
+        format!("{name}{index}")
+      } else {
+        name.to_owned()
+      };
+
+      Ident::new(&name, Span::call_site())
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn variant_names() {
+    assert_eq!(get_variant_name("none"), "None");
+    assert_eq!(get_variant_name("sampled_out"), "SampledOut");
+    assert_eq!(get_variant_name("1.0"), "Value10");
+    assert_eq!(get_variant_name(""), "Empty");
+    assert_eq!(get_variant_name("self"), "Self_");
+  }
+
+  #[test]
+  fn colliding_variant_names() {
+    let idents: Vec<String> = get_variant_idents(&["a-b", "a_b", "c"])
+      .iter()
+      .map(|ident| ident.to_string())
+      .collect();
+
+    assert_eq!(idents, vec!["AB0", "AB1", "C"]);
+  }
+}
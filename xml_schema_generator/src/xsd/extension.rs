@@ -1,6 +1,18 @@
-use crate::xsd::{
-  attribute::Attribute, group::Group, rust_types_mapping::RustTypesMapping, sequence::Sequence,
-  Implementation, XsdContext,
+use crate::{
+  error::step,
+  xsd::{
+    attribute::Attribute,
+    attribute_group::{implement_attribute_groups, AttributeGroup},
+    choice::Choice,
+    group::Group,
+    registry::ComponentKind,
+    rust_types_mapping::RustTypesMapping,
+    sequence::Sequence,
+    value_constraint::{has_default, FieldType},
+    wildcard::Wildcard,
+    Implementation, XsdContext,
+  },
+  Error,
 };
 use proc_macro2::TokenStream;
 
@@ -15,8 +27,13 @@ pub struct Extension {
   pub base: String,
   #[yaserde(rename = "attribute")]
   pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  #[yaserde(rename = "anyAttribute")]
+  pub any_attribute: Option<Wildcard>,
   #[yaserde(rename = "sequence")]
   pub sequences: Vec<Sequence>,
+  pub choice: Option<Choice>,
   #[yaserde(rename = "group")]
   pub group: Option<Group>,
 }
@@ -27,55 +44,62 @@ impl Implementation for Extension {
     namespace_definition: &TokenStream,
     prefix: &Option<String>,
     context: &XsdContext,
-  ) -> TokenStream {
-    let rust_type = RustTypesMapping::get(context, &self.base);
+  ) -> Result<TokenStream, Error> {
+    // yaserde reads the text content of a structure as a `String` only
+    let rust_type = if RustTypesMapping::is_xs_string(context, &self.base) {
+      quote!(String)
+    } else {
+      RustTypesMapping::get(context, &self.base)?
+    };
 
     let attributes: TokenStream = self
       .attributes
       .iter()
-      .map(|attribute| attribute.implement(namespace_definition, prefix, context))
-      .collect();
+      .enumerate()
+      .map(|(index, attribute)| {
+        attribute
+          .implement(namespace_definition, prefix, context)
+          .map_err(|e| e.within(step("attribute", None, index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let attribute_groups = implement_attribute_groups(
+      &self.attribute_groups,
+      &[],
+      namespace_definition,
+      prefix,
+      context,
+    )?;
 
     let inner_attribute = if format!("{rust_type}") == "String" {
       quote!(#[yaserde(text)])
+    } else if !RustTypesMapping::is_xs_type(context, &self.base)
+      && context
+        .lookup(ComponentKind::Type, &self.base)?
+        .is_some_and(|component| component.has_text_content())
+    {
+      quote!(#[yaserde(flatten)])
     } else {
       TokenStream::new()
     };
 
-    quote!(
+    // yaserde starts from the default value of a field, which some types do not have
+    let rust_type = if has_default(context, FieldType::Named(&self.base))? {
+      rust_type
+    } else {
+      context.get_value_constraints()?.add_required_field(
+        &format_ident!("base"),
+        format!("content of {:?}", self.base),
+      );
+      quote!(Option<#rust_type>)
+    };
+
+    Ok(quote!(
       #inner_attribute
       pub base: #rust_type,
       #attributes
-    )
-  }
-}
-
-impl Extension {
-  pub fn get_field_implementation(
-    &self,
-    context: &XsdContext,
-    prefix: &Option<String>,
-  ) -> TokenStream {
-    let rust_type = RustTypesMapping::get(context, &self.base);
-
-    let group_content = self
-      .group
-      .as_ref()
-      .map(|group| {
-        let group_type = group.get_type_implementation(context, prefix);
-
-        quote!(
-          ,
-          #[serde(flatten)]
-          pub extension : #group_type
-        )
-      })
-      .unwrap_or_default();
-
-    quote!(
-      pub base : #rust_type
-      #group_content
-    )
+      #attribute_groups
+    ))
   }
 }
 
@@ -89,7 +113,10 @@ mod tests {
     let st = Extension {
       base: "xs:string".to_string(),
       attributes: vec![],
+      attribute_groups: vec![],
+      any_attribute: None,
       sequences: vec![],
+      choice: None,
       group: None,
     };
 
@@ -97,7 +124,7 @@ mod tests {
       XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
         .unwrap();
 
-    let implementation = st.implement(&TokenStream::new(), &None, &context);
+    let implementation = st.implement(&TokenStream::new(), &None, &context).unwrap();
 
     let expected = TokenStream::from_str(
       r#"
@@ -121,18 +148,27 @@ mod tests {
           name: Some("attribute_1".to_string()),
           kind: Some("xs:string".to_string()),
           reference: None,
+          form: None,
           required: Required::Required,
           simple_type: None,
+          default: None,
+          fixed: None,
         },
         Attribute {
           name: Some("attribute_2".to_string()),
           kind: Some("xs:boolean".to_string()),
           reference: None,
+          form: None,
           required: Required::Optional,
           simple_type: None,
+          default: None,
+          fixed: None,
         },
       ],
+      attribute_groups: vec![],
+      any_attribute: None,
       sequences: vec![],
+      choice: None,
       group: None,
     };
 
@@ -140,7 +176,7 @@ mod tests {
       XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
         .unwrap();
 
-    let implementation = st.implement(&TokenStream::new(), &None, &context);
+    let implementation = st.implement(&TokenStream::new(), &None, &context).unwrap();
 
     let expected = TokenStream::from_str(
       r#"
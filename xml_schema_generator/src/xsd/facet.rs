@@ -0,0 +1,608 @@
+use crate::{
+  xsd::{restriction::Restriction, rust_types_mapping::RustTypesMapping},
+  Error,
+};
+use proc_macro2::{Ident, TokenStream};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Facet {
+  #[yaserde(attribute)]
+  pub value: String,
+}
+
+/// Order of the values of a built-in type, which the bounds of its restrictions are compared with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoundOrder {
+  /// `xs:float` and `xs:double`, as floating-point numbers.
+  Float,
+  /// `xs:decimal` and the integer types derived from it, exactly.
+  Decimal,
+  /// Other built-in types, whose bounds are not supported.
+  Unordered,
+}
+
+impl BoundOrder {
+  /// Order of a built-in type, by its local name and its Rust type, primitive numbers being ordered.
+  pub fn of_type(name: &str, rust_type: &str) -> Self {
+    match (name, rust_type) {
+      (_, "f32" | "f64") => BoundOrder::Float,
+      _ if RustTypesMapping::is_number(rust_type) => BoundOrder::Decimal,
+      _ => BoundOrder::of_name(name),
+    }
+  }
+
+  fn of_name(name: &str) -> Self {
+    match name {
+      "float" | "double" => BoundOrder::Float,
+      "decimal" | "integer" | "nonPositiveInteger" | "negativeInteger" | "long" | "int"
+      | "short" | "byte" | "nonNegativeInteger" | "unsignedLong" | "unsignedInt"
+      | "unsignedShort" | "unsignedByte" | "positiveInteger" => BoundOrder::Decimal,
+      _ => BoundOrder::Unordered,
+    }
+  }
+
+  /// Compare two values of the type, `None` if one of them is not a number.
+  pub fn compare(&self, left: &str, right: &str) -> Option<Ordering> {
+    match self {
+      BoundOrder::Float => left
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .zip(right.trim().parse::<f64>().ok())
+        .and_then(|(left, right)| left.partial_cmp(&right)),
+      BoundOrder::Decimal => compare_decimals(left, right),
+      BoundOrder::Unordered => None,
+    }
+  }
+}
+
+/// Built-in type at the root of a restriction, whose values bound the restriction.
+pub struct BoundType<'a> {
+  /// Local name of the built-in type, like `decimal`.
+  pub name: &'a str,
+  /// Rust type of the built-in type, the values are parsed as it to be compared with the bounds.
+  pub rust_type: &'a TokenStream,
+}
+
+/// Checks of the facets of a restriction on a `value` of the `rust_type`.
+/// A failed check returns an error naming the type and the facet.
+///
+/// Bounds are compared as the `bound_type`, `value` itself when it has this type: primitive
+/// numbers with literals, other types with their parsed bounds and decimals read as `String`
+/// with `xml_schema::types::compare_decimals`. Other facets are checked on the lexical form of
+/// the value.
+pub fn implement_facet_checks(
+  restriction: &Restriction,
+  struct_name: &Ident,
+  rust_type: &TokenStream,
+  bound_type: Option<BoundType>,
+) -> Result<TokenStream, Error> {
+  let struct_label = struct_name.to_string();
+
+  let check = |condition: TokenStream, facet: &str, facet_value: &str| {
+    quote!(
+      if !(#condition) {
+        return Err(format!("{:?} is not a valid value for {}, {} is {}", lexical, #struct_label, #facet, #facet_value));
+      }
+    )
+  };
+
+  let mut checks = vec![];
+
+  let bounds = [
+    ("minInclusive", &restriction.min_inclusive, quote!(>=)),
+    ("maxInclusive", &restriction.max_inclusive, quote!(<=)),
+    ("minExclusive", &restriction.min_exclusive, quote!(>)),
+    ("maxExclusive", &restriction.max_exclusive, quote!(<)),
+  ];
+
+  if bounds.iter().any(|(_, facet, _)| facet.is_some()) {
+    let Some(BoundType {
+      name: bound_name,
+      rust_type: bound_type,
+    }) = bound_type
+    else {
+      return Err(Error::new(
+        "Bounds can not be checked, the base type is not derived from a built-in type",
+      ));
+    };
+
+    let bound_type_name = bound_type.to_string();
+    let order = BoundOrder::of_type(bound_name, &bound_type_name);
+    if order == BoundOrder::Unordered {
+      return Err(Error::new(format!(
+        "Bounds are not supported for restrictions of xs:{bound_name}"
+      )));
+    }
+
+    let is_number = RustTypesMapping::is_number(&bound_type_name);
+    let is_lexical = bound_type_name == "String";
+    let number_expected = quote!(
+      format!("{:?} is not a valid value for {}, a number is expected", lexical, #struct_label)
+    );
+
+    if is_lexical {
+      checks.push(quote!(
+        let number = lexical.trim();
+        if xml_schema::types::compare_decimals(number, number).is_none() {
+          return Err(#number_expected);
+        }
+      ));
+    } else if !is_number || bound_type_name != rust_type.to_string() {
+      checks.push(quote!(
+        let number: #bound_type = lexical.trim().parse().map_err(|_| #number_expected)?;
+      ));
+    }
+
+    for (name, facet, operator) in bounds {
+      let Some(Facet { value: facet_value }) = facet else {
+        continue;
+      };
+
+      let invalid = || Error::new(format!("Invalid {name} value {facet_value:?}"));
+
+      let condition = if is_number {
+        let literal = RustTypesMapping::get_number_literal(&bound_type_name, facet_value)
+          .and_then(|literal| TokenStream::from_str(&literal).ok())
+          .ok_or_else(invalid)?;
+
+        if bound_type_name == rust_type.to_string() {
+          quote!(*value #operator #literal)
+        } else {
+          quote!(number #operator #literal)
+        }
+      } else {
+        order
+          .compare(facet_value, facet_value)
+          .ok_or_else(invalid)?;
+        let facet_value = facet_value.trim();
+
+        if is_lexical {
+          quote!(xml_schema::types::compare_decimals(number, #facet_value) #operator Some(std::cmp::Ordering::Equal))
+        } else {
+          quote!(number #operator #facet_value.parse::<#bound_type>()?)
+        }
+      };
+
+      checks.push(check(condition, name, facet_value));
+    }
+  }
+
+  let lengths = [
+    ("length", &restriction.length, quote!(==)),
+    ("minLength", &restriction.min_length, quote!(>=)),
+    ("maxLength", &restriction.max_length, quote!(<=)),
+  ];
+
+  for (name, facet, operator) in lengths {
+    let Some(Facet { value: facet_value }) = facet else {
+      continue;
+    };
+
+    let length = parse_length(name, facet_value)?;
+    checks.push(check(
+      quote!(lexical.chars().count() #operator #length),
+      name,
+      facet_value,
+    ));
+  }
+
+  if !restriction.patterns.is_empty() {
+    let patterns = restriction
+      .patterns
+      .iter()
+      .map(|pattern| translate_pattern(&pattern.value))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let facet_value = restriction
+      .patterns
+      .iter()
+      .map(|pattern| format!("{:?}", pattern.value))
+      .collect::<Vec<_>>()
+      .join(" or ");
+
+    checks.push(quote!(
+      static PATTERNS: std::sync::OnceLock<Vec<regex::Regex>> = std::sync::OnceLock::new();
+      let patterns = PATTERNS.get_or_init(|| vec![#(regex::Regex::new(#patterns).unwrap()),*]);
+    ));
+    checks.push(check(
+      quote!(patterns.iter().any(|pattern| pattern.is_match(&lexical))),
+      "pattern",
+      &facet_value,
+    ));
+  }
+
+  let digits = [
+    (
+      "totalDigits",
+      &restriction.total_digits,
+      quote!(integer_digits + fraction_digits),
+    ),
+    (
+      "fractionDigits",
+      &restriction.fraction_digits,
+      quote!(fraction_digits),
+    ),
+  ];
+
+  if digits.iter().any(|(_, facet, _)| facet.is_some()) {
+    checks.push(quote!(
+      let (integer_digits, fraction_digits) = {
+        let digits = lexical.trim().trim_start_matches(['+', '-']);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        (integer.trim_start_matches('0').len(), fraction.trim_end_matches('0').len())
+      };
+    ));
+  }
+
+  for (name, facet, count) in digits {
+    let Some(Facet { value: facet_value }) = facet else {
+      continue;
+    };
+
+    let length = parse_length(name, facet_value)?;
+    checks.push(check(quote!(#count <= #length), name, facet_value));
+  }
+
+  if checks.is_empty() {
+    return Ok(quote!());
+  }
+
+  Ok(quote!(
+    let lexical = value.to_string();
+    #(#checks)*
+  ))
+}
+
+fn parse_length(name: &str, value: &str) -> Result<usize, Error> {
+  value
+    .trim()
+    .parse()
+    .map_err(|_| Error::new(format!("Invalid {name} value {value:?}")))
+}
+
+/// Check the lexical form of a value against the facets of a restriction when generating code,
+/// as the generated checks do. Enumerations are not checked.
+pub fn is_valid_value(
+  restriction: &Restriction,
+  order: BoundOrder,
+  value: &str,
+) -> Result<bool, Error> {
+  let bounds = [
+    (
+      &restriction.min_inclusive,
+      [Ordering::Greater, Ordering::Equal],
+    ),
+    (
+      &restriction.max_inclusive,
+      [Ordering::Less, Ordering::Equal],
+    ),
+    (&restriction.min_exclusive, [Ordering::Greater; 2]),
+    (&restriction.max_exclusive, [Ordering::Less; 2]),
+  ];
+
+  for (facet, expected) in bounds {
+    if let Some(Facet { value: bound }) = facet {
+      if !order
+        .compare(value, bound)
+        .is_some_and(|ordering| expected.contains(&ordering))
+      {
+        return Ok(false);
+      }
+    }
+  }
+
+  let length = value.chars().count();
+  let lengths = [
+    (&restriction.length, Ordering::Equal),
+    (&restriction.min_length, Ordering::Greater),
+    (&restriction.max_length, Ordering::Less),
+  ];
+
+  for (facet, accepted) in lengths {
+    if let Some(Facet { value: facet_value }) = facet {
+      let ordering = length.cmp(&parse_length("length", facet_value)?);
+      if ordering != Ordering::Equal && ordering != accepted {
+        return Ok(false);
+      }
+    }
+  }
+
+  if !restriction.patterns.is_empty() {
+    let mut matched = false;
+    for pattern in &restriction.patterns {
+      let pattern = translate_pattern(&pattern.value)?;
+      matched |= regex::Regex::new(&pattern)
+        .map_err(|e| Error::new(e.to_string()))?
+        .is_match(value);
+    }
+
+    if !matched {
+      return Ok(false);
+    }
+  }
+
+  let digits = value.trim().trim_start_matches(['+', '-']);
+  let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+  let (integer_digits, fraction_digits) = (
+    integer.trim_start_matches('0').len(),
+    fraction.trim_end_matches('0').len(),
+  );
+
+  if let Some(Facet { value: facet_value }) = &restriction.total_digits {
+    if integer_digits + fraction_digits > parse_length("totalDigits", facet_value)? {
+      return Ok(false);
+    }
+  }
+
+  if let Some(Facet { value: facet_value }) = &restriction.fraction_digits {
+    if fraction_digits > parse_length("fractionDigits", facet_value)? {
+      return Ok(false);
+    }
+  }
+
+  Ok(true)
+}
+
+/// Compare the values of two decimal numbers in their lexical form, exactly.
+/// `None` if one of them is not a decimal number.
+fn compare_decimals(left: &str, right: &str) -> Option<Ordering> {
+  fn split_decimal(value: &str) -> Option<(bool, &str, &str)> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+      Some(digits) => (true, digits),
+      None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if integer.is_empty() && fraction.is_empty()
+      || !integer
+        .chars()
+        .chain(fraction.chars())
+        .all(|character| character.is_ascii_digit())
+    {
+      return None;
+    }
+
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+    let negative = negative && !(integer.is_empty() && fraction.is_empty());
+
+    Some((negative, integer, fraction))
+  }
+
+  let (left_negative, left_integer, left_fraction) = split_decimal(left)?;
+  let (right_negative, right_integer, right_fraction) = split_decimal(right)?;
+
+  let ordering = match (left_negative, right_negative) {
+    (false, true) => Ordering::Greater,
+    (true, false) => Ordering::Less,
+    (negative, _) => {
+      let ordering = left_integer
+        .len()
+        .cmp(&right_integer.len())
+        .then_with(|| left_integer.cmp(right_integer))
+        .then_with(|| left_fraction.cmp(right_fraction));
+
+      if negative {
+        ordering.reverse()
+      } else {
+        ordering
+      }
+    }
+  };
+
+  Some(ordering)
+}
+
+/// Translate an XML Schema regular expression into the syntax of the `regex` crate.
+///
+/// XML Schema patterns match the whole value, `^` and `$` are not anchors,
+/// `\i` and `\c` are the initial and following characters of XML names
+/// and `[a-z-[aeiou]]` subtracts a class from another.
+pub fn translate_pattern(pattern: &str) -> Result<String, Error> {
+  const INITIAL_NAME_CHARACTERS: &str = r"\p{L}_:";
+  const NAME_CHARACTERS: &str = r"\p{L}\p{M}\p{Nd}._:\-";
+
+  let mut translated = String::new();
+  let mut characters = pattern.chars().peekable();
+  let mut class_depth = 0;
+
+  while let Some(character) = characters.next() {
+    match character {
+      '\\' => {
+        let Some(escaped) = characters.next() else {
+          return Err(Error::new(format!(
+            "Invalid pattern {pattern:?}: trailing backslash"
+          )));
+        };
+
+        let class = match escaped {
+          'i' | 'I' => INITIAL_NAME_CHARACTERS,
+          'c' | 'C' => NAME_CHARACTERS,
+          _ => {
+            translated.push('\\');
+            translated.push(escaped);
+            continue;
+          }
+        };
+
+        let negated = escaped.is_uppercase();
+        match (class_depth > 0, negated) {
+          (false, false) => translated.push_str(&format!("[{class}]")),
+          (_, true) => translated.push_str(&format!("[^{class}]")),
+          (true, false) => translated.push_str(class),
+        }
+      }
+      '[' => {
+        class_depth += 1;
+        translated.push('[');
+      }
+      ']' if class_depth > 0 => {
+        class_depth -= 1;
+        translated.push(']');
+      }
+      '-' if class_depth > 0 && characters.peek() == Some(&'[') => translated.push_str("--"),
+      '^' | '$' if class_depth == 0 => {
+        translated.push('\\');
+        translated.push(character);
+      }
+      '.' if class_depth == 0 => translated.push_str(r"[^\n\r]"),
+      _ => translated.push(character),
+    }
+  }
+
+  let translated = format!("^(?:{translated})$");
+
+  regex::Regex::new(&translated)
+    .map_err(|e| Error::new(format!("Invalid pattern {pattern:?}: {e}")))?;
+
+  Ok(translated)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+
+  #[test]
+  fn translate_patterns() {
+    assert_eq!(translate_pattern("[A-Z]{3,3}").unwrap(), "^(?:[A-Z]{3,3})$");
+    assert_eq!(translate_pattern("a.b$").unwrap(), r"^(?:a[^\n\r]b\$)$");
+    assert_eq!(
+      translate_pattern(r"\i\c*").unwrap(),
+      r"^(?:[\p{L}_:][\p{L}\p{M}\p{Nd}._:\-]*)$"
+    );
+    assert_eq!(
+      translate_pattern("[a-z-[aeiou]]").unwrap(),
+      "^(?:[a-z--[aeiou]])$"
+    );
+    assert_eq!(translate_pattern("[^0-9]").unwrap(), "^(?:[^0-9])$");
+    assert_eq!(
+      translate_pattern("[a-")
+        .unwrap_err()
+        .message()
+        .lines()
+        .next(),
+      Some("Invalid pattern \"[a-\": regex parse error:")
+    );
+  }
+
+  #[test]
+  fn facet_checks() {
+    let restriction = Restriction {
+      base: Some("xs:decimal".to_string()),
+      min_inclusive: Some(Facet {
+        value: "0".to_string(),
+      }),
+      fraction_digits: Some(Facet {
+        value: "2".to_string(),
+      }),
+      ..Default::default()
+    };
+
+    let struct_name = Ident::new("Amount", Span::call_site());
+    let bound_type = BoundType {
+      name: "decimal",
+      rust_type: &quote!(String),
+    };
+    let implementation = implement_facet_checks(
+      &restriction,
+      &struct_name,
+      &quote!(String),
+      Some(bound_type),
+    )
+    .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        let lexical = value.to_string();
+        let number = lexical.trim();
+        if xml_schema::types::compare_decimals(number, number).is_none() {
+          return Err(format!("{:?} is not a valid value for {}, a number is expected", lexical, "Amount"));
+        }
+        if !(xml_schema::types::compare_decimals(number, "0") >= Some(std::cmp::Ordering::Equal)) {
+          return Err(format!("{:?} is not a valid value for {}, {} is {}", lexical, "Amount", "minInclusive", "0"));
+        }
+        let (integer_digits, fraction_digits) = {
+          let digits = lexical.trim().trim_start_matches(['+', '-']);
+          let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+          (integer.trim_start_matches('0').len(), fraction.trim_end_matches('0').len())
+        };
+        if !(fraction_digits <= 2usize) {
+          return Err(format!("{:?} is not a valid value for {}, {} is {}", lexical, "Amount", "fractionDigits", "2"));
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn invalid_facet() {
+    let restriction = Restriction {
+      base: Some("xs:int".to_string()),
+      max_inclusive: Some(Facet {
+        value: "high".to_string(),
+      }),
+      ..Default::default()
+    };
+
+    let struct_name = Ident::new("Level", Span::call_site());
+
+    let bound_type = BoundType {
+      name: "int",
+      rust_type: &quote!(i32),
+    };
+
+    assert_eq!(
+      implement_facet_checks(&restriction, &struct_name, &quote!(i32), Some(bound_type))
+        .unwrap_err(),
+      Error::new("Invalid maxInclusive value \"high\"")
+    );
+
+    let bound_type = BoundType {
+      name: "date",
+      rust_type: &quote!(String),
+    };
+
+    assert_eq!(
+      implement_facet_checks(
+        &restriction,
+        &struct_name,
+        &quote!(String),
+        Some(bound_type)
+      )
+      .unwrap_err(),
+      Error::new("Bounds are not supported for restrictions of xs:date")
+    );
+  }
+
+  #[test]
+  fn valid_values() {
+    let restriction = Restriction {
+      base: Some("xs:decimal".to_string()),
+      min_exclusive: Some(Facet {
+        value: "0.1".to_string(),
+      }),
+      total_digits: Some(Facet {
+        value: "3".to_string(),
+      }),
+      ..Default::default()
+    };
+
+    let is_valid = |value| is_valid_value(&restriction, BoundOrder::Decimal, value).unwrap();
+    assert!(is_valid("0.11"));
+    assert!(!is_valid("0.1"));
+    assert!(!is_valid("1.111"));
+    assert!(!is_valid("ten"));
+
+    assert_eq!(compare_decimals("-2.5", "-2.45"), Some(Ordering::Less));
+    assert_eq!(compare_decimals("-0", ".0"), Some(Ordering::Equal));
+    assert_eq!(compare_decimals("1e3", "1"), None);
+  }
+}
@@ -0,0 +1,205 @@
+use crate::{
+  xsd::{
+    choice::Choice, mixed, registry::ComponentKind, rust_types_mapping::RustTypesMapping,
+    sequence::Sequence, Implementation, XsdContext,
+  },
+  Error,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Group {
+  #[yaserde(attribute)]
+  pub name: Option<String>,
+  #[yaserde(attribute, rename = "ref")]
+  pub reference: Option<String>,
+  #[yaserde()]
+  pub sequence: Option<Sequence>,
+  #[yaserde()]
+  pub choice: Option<Choice>,
+}
+
+impl Implementation for Group {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let Some(raw_name) = &self.name else {
+      return Ok(quote!());
+    };
+
+    let struct_name = Ident::new(&raw_name.to_upper_camel_case(), Span::call_site());
+    let context = &context
+      .with_path(raw_name)
+      .with_value_constraints(&struct_name);
+
+    let fields = self
+      .sequence
+      .as_ref()
+      .map(|sequence| {
+        sequence
+          .get_field_implementation(context, prefix, &struct_name)
+          .map_err(|e| e.within("sequence"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let choice = self
+      .choice
+      .as_ref()
+      .map(|choice| {
+        choice
+          .get_field_implementation(context, prefix, &struct_name, None)
+          .map_err(|e| e.within("choice"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let sub_types_implementation = self
+      .choice
+      .as_ref()
+      .map(|choice| {
+        choice
+          .get_sub_types_implementation(context, namespace_definition, prefix, &struct_name, None)
+          .map_err(|e| e.within("choice"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let sequence_sub_types = self
+      .sequence
+      .as_ref()
+      .map(|sequence| {
+        sequence
+          .get_sub_types_implementation(context, namespace_definition, prefix, &struct_name)
+          .map_err(|e| e.within("sequence"))
+      })
+      .transpose()?
+      .unwrap_or_default();
+
+    let fields = quote!(
+      #fields
+      #choice
+    );
+    let (derive_default, value_constraints) =
+      context.get_value_constraints()?.implement(&fields)?;
+    let (derive_deserialize, deserialize) = mixed::implement_derive_deserialize(
+      false,
+      &context
+        .get_value_constraints()?
+        .get_required_fields(&fields)?,
+      namespace_definition,
+      &struct_name,
+      &fields,
+    )?;
+
+    Ok(quote!(
+      #[derive(Clone, Debug, #derive_default PartialEq, #derive_deserialize yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #fields
+      }
+
+      #deserialize
+      #value_constraints
+
+      #sub_types_implementation
+      #sequence_sub_types
+    ))
+  }
+}
+
+impl Group {
+  pub fn get_type_implementation(
+    &self,
+    context: &XsdContext,
+    _prefix: &Option<String>,
+  ) -> Result<TokenStream, Error> {
+    if let Some(reference) = &self.reference {
+      RustTypesMapping::get_reference(context, ComponentKind::Group, reference)
+    } else {
+      Err(Error::new("Missing reference for group"))
+    }
+  }
+
+  /// Field of a referenced group in a sequence, the elements of the group being read from
+  /// and written in the element of the structure.
+  pub fn get_field_implementation(&self, context: &XsdContext) -> Result<TokenStream, Error> {
+    let Some(reference) = &self.reference else {
+      return Err(Error::new("Missing reference for group"));
+    };
+
+    let name = reference.split(':').next_back().unwrap_or_default();
+    let attribute_name = Ident::new(&name.to_snake_case(), Span::call_site());
+
+    let group_type = self.get_type_implementation(context, &None)?;
+    let module = (!context.is_in_sub_module())
+      .then_some(quote!(xml_schema_types::))
+      .unwrap_or_default();
+
+    Ok(quote!(
+      #[yaserde(flatten)]
+      pub #attribute_name: #module#group_type,
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use yaserde::de::from_str;
+
+  #[test]
+  fn check_group_implementation() {
+    let xml = r#"
+        <group name="groupthing">
+          <sequence>
+            <element name="CX_X" type="asdfg"/>
+            <element name="CY_X" type="asdfg"/>
+          </sequence>
+        </group>
+    "#;
+
+    let group: Group = from_str(xml).unwrap();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = format!(
+      "{}",
+      group
+        .implement(&TokenStream::new(), &None, &context)
+        .unwrap()
+    );
+
+    assert_eq!(implementation, "# [derive (Clone , Debug , Default , PartialEq , serde :: Deserialize , serde :: Serialize)] \
+pub struct Groupthing { \
+# [yaserde (rename = \"CX_X\")] pub cx_x : xml_schema_types :: Asdfg , \
+# [yaserde (rename = \"CY_X\")] pub cy_x : xml_schema_types :: Asdfg , }");
+  }
+
+  #[test]
+  fn check_group_ref() {
+    let xml = r#"<group ref="bla:groupthing" />"#;
+
+    let group: Group = from_str(xml).unwrap();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let type_implementation = format!(
+      "{}",
+      group.get_type_implementation(&context, &None).unwrap()
+    );
+
+    assert_eq!(type_implementation, "Groupthing");
+  }
+}
@@ -0,0 +1,12 @@
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  root="schema"
+  prefix="xs",
+  namespace="xs: http://www.w3.org/2001/XMLSchema",
+)]
+pub struct Include {
+  #[yaserde(attribute)]
+  pub id: Option<String>,
+  #[yaserde(rename = "schemaLocation", attribute)]
+  pub schema_location: String,
+}
@@ -0,0 +1,193 @@
+use crate::{
+  xsd::{rust_types_mapping::RustTypesMapping, XsdContext},
+  Error,
+};
+use regex::Regex;
+
+/// Check if a value is in the lexical space of a built-in type, and can be read as its Rust type.
+/// Types without constraint on their values, like strings, accept any value.
+pub fn is_valid_built_in(context: &XsdContext, kind: &str, value: &str) -> Result<bool, Error> {
+  let name = kind.split(':').next_back().unwrap_or_default();
+  let rust_type = RustTypesMapping::get(context, kind)?.to_string();
+  let value = value.trim();
+
+  match name {
+    "boolean" => Ok(matches!(value, "true" | "false" | "1" | "0")),
+    "integer" | "nonPositiveInteger" | "negativeInteger" | "nonNegativeInteger"
+    | "positiveInteger" => Ok(is_integer(name, value, &rust_type)),
+    _ if RustTypesMapping::is_number(&rust_type) => {
+      Ok(RustTypesMapping::get_number_literal(&rust_type, value).is_some())
+    }
+    "decimal" => Ok(is_decimal(value, &rust_type)),
+    "dateTime" | "date" | "time" | "duration" | "gYear" | "gYearMonth" | "gMonthDay" | "gDay"
+    | "gMonth" => is_date_time(name, value),
+    _ => Ok(true),
+  }
+}
+
+/// Decimal number, limited to the precision of `XsDecimal` when it is read as it.
+fn is_decimal(value: &str, rust_type: &str) -> bool {
+  let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+  let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+  if (integer.is_empty() && fraction.is_empty())
+    || !integer
+      .chars()
+      .chain(fraction.chars())
+      .all(|character| character.is_ascii_digit())
+  {
+    return false;
+  }
+
+  rust_type != "xml_schema :: types :: XsDecimal"
+    || (fraction.len() <= 28 && integer.trim_start_matches('0').len() + fraction.len() <= 28)
+}
+
+/// Integer with the sign of its type, in the range of its Rust type.
+fn is_integer(name: &str, value: &str, rust_type: &str) -> bool {
+  let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+  if digits.is_empty() || !digits.chars().all(|character| character.is_ascii_digit()) {
+    return false;
+  }
+
+  let is_zero = digits.trim_start_matches('0').is_empty();
+  let is_negative = value.starts_with('-') && !is_zero;
+  let has_sign = match name {
+    "nonPositiveInteger" => is_negative || is_zero,
+    "negativeInteger" => is_negative,
+    "nonNegativeInteger" => !is_negative,
+    "positiveInteger" => !is_negative && !is_zero,
+    _ => true,
+  };
+
+  let is_in_range = if RustTypesMapping::is_number(rust_type) {
+    RustTypesMapping::get_number_literal(rust_type, value).is_some()
+  } else {
+    // the integer types of `xml_schema::types` are unbounded with the `big-integer` feature
+    cfg!(feature = "big-integer") || value.parse::<i64>().is_ok()
+  };
+
+  has_sign && is_in_range
+}
+
+/// Date, time and duration types, as the `xml_schema::types` of the `date-time` feature read them.
+fn is_date_time(name: &str, value: &str) -> Result<bool, Error> {
+  let year = r"(?P<year>-?([1-9]\d{4,}|\d{4}))";
+  let month = r"(?P<month>0[1-9]|1[0-2])";
+  let day = r"(?P<day>0[1-9]|[12]\d|3[01])";
+  let time = r"(([01]\d|2[0-3]):[0-5]\d:[0-5]\d(\.\d+)?|24:00:00(\.0+)?)";
+  let timezone = r"(Z|[+-]((0\d|1[0-3]):[0-5]\d|14:00))?";
+
+  let pattern = match name {
+    "dateTime" => format!("{year}-{month}-{day}T{time}{timezone}"),
+    "date" => format!("{year}-{month}-{day}{timezone}"),
+    "time" => format!("{time}{timezone}"),
+    "gYear" => format!("{year}{timezone}"),
+    "gYearMonth" => format!("{year}-{month}{timezone}"),
+    "gMonthDay" => format!("--{month}-{day}{timezone}"),
+    "gDay" => format!("---{day}{timezone}"),
+    "gMonth" => format!("--{month}{timezone}"),
+    _ => {
+      // at least one component, and one after `T`
+      return Ok(
+        !value.ends_with(['P', 'T'])
+          && Regex::new(r"^-?P(\d+Y)?(\d+M)?(\d+D)?(T(\d+H)?(\d+M)?(\d+(\.\d+)?S)?)?$")
+            .map_err(|e| Error::new(e.to_string()))?
+            .is_match(value),
+      );
+    }
+  };
+
+  let Some(captures) = Regex::new(&format!("^{pattern}$"))
+    .map_err(|e| Error::new(e.to_string()))?
+    .captures(value)
+  else {
+    return Ok(false);
+  };
+
+  let number = |name: &str| {
+    captures
+      .name(name)
+      .and_then(|capture| capture.as_str().parse::<i64>().ok())
+  };
+
+  if captures.name("year").is_some() && number("year").is_none() {
+    return Ok(false);
+  }
+
+  let (Some(month), Some(day)) = (number("month"), number("day")) else {
+    return Ok(true);
+  };
+
+  let days = match month {
+    4 | 6 | 9 | 11 => 30,
+    2 => match number("year") {
+      Some(year) if !(year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)) => 28,
+      _ => 29,
+    },
+    _ => 31,
+  };
+
+  Ok(day <= days)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn date_time_values() {
+    let valid = [
+      ("dateTime", "2024-03-15T13:20:00.50+01:00"),
+      ("date", "2024-02-29"),
+      ("date", "-0044-03-15Z"),
+      ("time", "24:00:00"),
+      ("duration", "-P1Y2M3DT4H5M6.7S"),
+      ("duration", "PT30M"),
+      ("gYear", "12024"),
+      ("gYearMonth", "2024-03Z"),
+      ("gMonthDay", "--02-29"),
+      ("gDay", "---31"),
+      ("gMonth", "--12"),
+    ];
+
+    for (name, value) in valid {
+      assert!(is_date_time(name, value).unwrap(), "{}", value);
+    }
+
+    let invalid = [
+      ("dateTime", "2024-03-15"),
+      ("date", "2023-02-29"),
+      ("date", "02024-01-01"),
+      ("time", "24:00:01"),
+      ("time", "13:20:00+15:00"),
+      ("duration", "P"),
+      ("duration", "P1DT"),
+      ("duration", "P1.5D"),
+      ("gYear", "44"),
+      ("gMonthDay", "--04-31"),
+      ("gMonth", "--13"),
+    ];
+
+    for (name, value) in invalid {
+      assert!(!is_date_time(name, value).unwrap(), "{}", value);
+    }
+  }
+
+  #[test]
+  fn number_values() {
+    assert!(is_decimal("+.5", "String"));
+    assert!(!is_decimal("1e3", "String"));
+    assert!(is_decimal("12345678901234567890123456789", "String"));
+    assert!(!is_decimal(
+      "12345678901234567890123456789",
+      "xml_schema :: types :: XsDecimal"
+    ));
+
+    assert!(is_integer("nonPositiveInteger", "-0", "i64"));
+    assert!(!is_integer("negativeInteger", "0", "i64"));
+    assert!(!is_integer("positiveInteger", "1.0", "u64"));
+    assert!(!is_integer("integer", "99999999999999999999", "i32"));
+    assert!(is_integer("positiveInteger", "18446744073709551615", "u64"));
+  }
+}
@@ -0,0 +1,132 @@
+use crate::{
+  xsd::{
+    rust_types_mapping::RustTypesMapping,
+    simple_type::{implement_deserialize_from_str, implement_serialize_display},
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use proc_macro2::{Ident, TokenStream};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct List {
+  #[yaserde(rename = "itemType", attribute)]
+  pub item_type: String,
+}
+
+impl Implementation for List {
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    let list_type = RustTypesMapping::get(context, &self.item_type)?;
+
+    let deserialize_implementation = implement_deserialize_from_str(struct_name);
+    let serialize_implementation = implement_serialize_display(struct_name, quote!());
+
+    // the items are parsed by `XsList`, an invalid item is an error and an empty list is valid
+    Ok(quote!(
+      #[derive(Clone, Debug, Default, PartialEq)]
+      pub struct #struct_name {
+        pub items: Vec<#list_type>
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          let list: xml_schema::types::XsList<#list_type> = value.parse()? ;
+          Ok(#struct_name { items: list.items })
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+              formatter.write_str(" ")? ;
+            }
+            write!(formatter, "{}", item)? ;
+          }
+
+          Ok(())
+        }
+      }
+
+      #deserialize_implementation
+
+      #serialize_implementation
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+  use std::str::FromStr;
+  use syn::Ident;
+
+  #[test]
+  fn basic_list() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let list_type = List {
+      item_type: "xs:string".to_string(),
+    };
+
+    let struct_name = Ident::new("Parent", Span::call_site());
+
+    let implementation = list_type
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct Parent {
+          pub items: Vec <String>
+        }
+
+        impl std::str::FromStr for Parent {
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {
+            let list: xml_schema::types::XsList<String> = value.parse()? ;
+            Ok(Parent { items: list.items })
+          }
+        }
+
+        impl std::fmt::Display for Parent {
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            for (index, item) in self.items.iter().enumerate() {
+              if index > 0 {
+                formatter.write_str(" ")? ;
+              }
+              write!(formatter, "{}", item)? ;
+            }
+
+            Ok(())
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    let deserialize_implementation = implement_deserialize_from_str(&struct_name);
+    let serialize_implementation = implement_serialize_display(&struct_name, quote!());
+    let expected = quote!(
+      #expected
+      #deserialize_implementation
+      #serialize_implementation
+    );
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
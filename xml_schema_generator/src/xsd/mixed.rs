@@ -0,0 +1,423 @@
+use crate::{
+  xsd::{
+    element::Element, enumeration::get_variant_idents, value_constraint::RequiredField, XsdContext,
+  },
+  Error,
+};
+use proc_macro2::TokenStream;
+use syn::{parse2, Field, FieldsNamed, Ident};
+
+/// Child element of a mixed complex type, with its path from the complex type to locate errors.
+pub struct MixedElement<'a> {
+  pub path: Vec<String>,
+  pub element: &'a Element,
+}
+
+/// Field of the content of a mixed complex type, its text and child elements in document order.
+pub fn get_field_implementation(struct_name: &Ident) -> TokenStream {
+  let (_content_name, list_name) = get_names(struct_name);
+
+  quote!(
+    #[yaserde(flatten)]
+    pub content: #list_name,
+  )
+}
+
+/// Enumeration of the text and the child elements of a mixed complex type,
+/// and the list of its items deserialized and serialized in document order.
+/// Undeclared child elements are kept as they were read in the `Other` variant.
+pub fn get_sub_types_implementation(
+  context: &XsdContext,
+  prefix: &Option<String>,
+  struct_name: &Ident,
+  elements: &[MixedElement],
+) -> Result<TokenStream, Error> {
+  let (content_name, list_name) = get_names(struct_name);
+
+  // the variants of `text` and `other` elements are not confused with the text content
+  // and the undeclared elements
+  let names: Vec<String> = elements
+    .iter()
+    .map(|mixed_element| {
+      let name = mixed_element.element.get_local_name();
+      match get_variant_idents(&[name])[0].to_string().as_str() {
+        "Text" | "Other" => format!("{name}_element"),
+        _ => name.to_string(),
+      }
+    })
+    .collect();
+  let variants = get_variant_idents(&names);
+
+  let rust_types: Vec<TokenStream> = elements
+    .iter()
+    .map(|mixed_element| {
+      mixed_element
+        .element
+        .get_type_implementation(context)
+        .map_err(|e| {
+          mixed_element
+            .path
+            .iter()
+            .rev()
+            .fold(e, |e, step| e.within(step.as_str()))
+        })
+    })
+    .collect::<Result<_, _>>()?;
+
+  let match_variants: TokenStream = elements
+    .iter()
+    .zip(variants.iter())
+    .zip(rust_types.iter())
+    .map(|((mixed_element, variant), rust_type)| {
+      let element_name = mixed_element.element.get_local_name();
+      let read_value = mixed_element
+        .element
+        .implement_read_value(context, rust_type);
+
+      quote!(
+        #element_name => {
+          #read_value
+          items.push(#content_name::#variant(value));
+        }
+      )
+    })
+    .collect();
+
+  let serialize_variants: TokenStream = elements
+    .iter()
+    .zip(variants.iter())
+    .map(|(mixed_element, variant)| {
+      let prefix = mixed_element
+        .element
+        .get_name_prefix(context, prefix)
+        .map_err(|e| {
+          mixed_element
+            .path
+            .iter()
+            .rev()
+            .fold(e, |e, step| e.within(step.as_str()))
+        })?;
+      let serialize_value = mixed_element
+        .element
+        .implement_write_value(context, &prefix);
+
+      Ok(quote!(
+        #content_name::#variant(value) => {
+          #serialize_value
+        }
+      ))
+    })
+    .collect::<Result<_, Error>>()?;
+
+  Ok(quote!(
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum #content_name {
+      Text(String),
+      #(#variants(#rust_types),)*
+      Other(xml_schema::types::XmlElement),
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct #list_name {
+      pub items: Vec<#content_name>,
+    }
+
+    impl yaserde::YaDeserialize for #list_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        let start_depth = reader.depth();
+        let mut items = vec![];
+
+        loop {
+          match reader.peek()?.to_owned() {
+            xml::reader::XmlEvent::StartElement { ref name, .. } => {
+              if reader.depth() == start_depth {
+                reader.next_event()?;
+                continue;
+              }
+
+              match name.local_name.as_str() {
+                #match_variants
+                _ => {
+                  let element = <xml_schema::types::XmlElement as yaserde::YaDeserialize>::deserialize(reader)?;
+                  items.push(#content_name::Other(element));
+                }
+              }
+            }
+            xml::reader::XmlEvent::Characters(text_content) => {
+              reader.next_event()?;
+              items.push(#content_name::Text(text_content));
+            }
+            xml::reader::XmlEvent::EndElement { .. } | xml::reader::XmlEvent::EndDocument => {
+              break;
+            }
+            _ => {
+              reader.next_event()?;
+            }
+          }
+        }
+
+        Ok(#list_name { items })
+      }
+    }
+
+    impl yaserde::YaSerialize for #list_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        for item in &self.items {
+          match item {
+            #content_name::Text(text) => {
+              writer.write(xml::writer::XmlEvent::characters(text)).map_err(|e| e.to_string())?;
+            }
+            #serialize_variants
+            #content_name::Other(element) => {
+              yaserde::YaSerialize::serialize(element, writer)?;
+            }
+          }
+        }
+
+        Ok(())
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  ))
+}
+
+/// Derive of the deserialization of a structure, or its implementation with mixed content or
+/// required fields generated as `Option`.
+/// yaserde reads the flattened fields again from a trimmed document, so a mixed element is read
+/// as a whole: its content without trimming the text, and its other fields with a derived
+/// structure of the same name. Required fields are checked once this structure is read.
+pub fn implement_derive_deserialize(
+  mixed: bool,
+  required_fields: &[RequiredField],
+  namespace_definition: &TokenStream,
+  struct_name: &Ident,
+  fields: &TokenStream,
+) -> Result<(TokenStream, TokenStream), Error> {
+  if !mixed && required_fields.is_empty() {
+    return Ok((quote!(yaserde_derive::YaDeserialize,), quote!()));
+  }
+
+  let fields_named = parse2::<FieldsNamed>(quote!({ #fields }))
+    .map_err(|e| Error::new(format!("Invalid fields of {struct_name}: {e}")))?;
+
+  let other_fields: Vec<&Field> = fields_named
+    .named
+    .iter()
+    .filter(|field| !mixed || field.ident.as_ref().is_some_and(|ident| ident != "content"))
+    .collect();
+  let field_names = other_fields.iter().map(|field| &field.ident);
+
+  let (read_fields, content) = if mixed {
+    (
+      quote!(
+        let element = <xml_schema::types::XmlElement as yaserde::YaDeserialize>::deserialize(reader)?;
+        let content = xml_schema::de::from_str(&yaserde::ser::to_string(&element)?)?;
+        let fields: #struct_name = element.deserialize_as()?;
+      ),
+      quote!(content,),
+    )
+  } else {
+    (
+      quote!(
+        let fields: #struct_name = yaserde::YaDeserialize::deserialize(reader)?;
+      ),
+      quote!(),
+    )
+  };
+
+  let check_required_fields = required_fields.iter().map(|field| {
+    let field_name = &field.name;
+    let description = &field.description;
+
+    quote!(
+      if fields.#field_name.is_none() {
+        return Err(format!("Missing {} in {}", #description, stringify!(#struct_name)));
+      }
+    )
+  });
+
+  let deserialize = quote!(
+    impl yaserde::YaDeserialize for #struct_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        #[derive(yaserde_derive::YaDeserialize)]
+        #namespace_definition
+        struct #struct_name {
+          #(#other_fields,)*
+        }
+
+        #read_fields
+        #(#check_required_fields)*
+
+        Ok(Self {
+          #content
+          #(#field_names: fields.#field_names,)*
+        })
+      }
+    }
+  );
+
+  Ok((quote!(), deserialize))
+}
+
+fn get_names(struct_name: &Ident) -> (Ident, Ident) {
+  (
+    format_ident!("{}Content", struct_name),
+    format_ident!("{}ContentList", struct_name),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+  use std::str::FromStr;
+
+  #[test]
+  fn mixed_content() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let declared_elements: Vec<Element> = ["bold", "text", "other", "other_element"]
+      .iter()
+      .map(|name| Element {
+        name: name.to_string(),
+        kind: Some("xs:string".to_string()),
+        ..Default::default()
+      })
+      .collect();
+    let elements: Vec<MixedElement> = declared_elements
+      .iter()
+      .enumerate()
+      .map(|(index, element)| MixedElement {
+        path: vec!["sequence".to_string(), format!("element[{}]", index + 1)],
+        element,
+      })
+      .collect();
+
+    let struct_name = Ident::new("Paragraph", Span::call_site());
+
+    let field = get_field_implementation(&struct_name);
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub content: ParagraphContentList,
+      "#,
+    )
+    .unwrap();
+    assert_eq!(field.to_string(), expected.to_string());
+
+    let implementation =
+      get_sub_types_implementation(&context, &None, &struct_name, &elements).unwrap();
+    let implementation = implementation.to_string();
+
+    let expected = TokenStream::from_str(
+      r#"
+        pub enum ParagraphContent {
+          Text(String),
+          Bold(String),
+          TextElement(String),
+          OtherElement2(String),
+          OtherElement3(String),
+          Other(xml_schema::types::XmlElement),
+        }
+      "#,
+    )
+    .unwrap();
+    assert!(implementation.contains(&expected.to_string()));
+
+    assert!(implementation.contains("items . push (ParagraphContent :: Text (text_content))"));
+    assert!(implementation.contains("ParagraphContent :: Bold (value) =>"));
+    assert!(implementation.contains("items . push (ParagraphContent :: Other (element))"));
+  }
+
+  #[test]
+  fn mixed_deserialize() {
+    let struct_name = Ident::new("Paragraph", Span::call_site());
+    let fields = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub content: ParagraphContentList,
+        #[yaserde(attribute)]
+        pub lang: Option<String>,
+      "#,
+    )
+    .unwrap();
+
+    let (derive, implementation) =
+      implement_derive_deserialize(false, &[], &TokenStream::new(), &struct_name, &fields).unwrap();
+    assert_eq!(derive.to_string(), "yaserde_derive :: YaDeserialize ,");
+    assert!(implementation.is_empty());
+
+    let (derive, implementation) =
+      implement_derive_deserialize(true, &[], &TokenStream::new(), &struct_name, &fields).unwrap();
+    assert!(derive.is_empty());
+
+    let implementation = implementation.to_string();
+    assert!(implementation
+      .contains("struct Paragraph { # [yaserde (attribute)] pub lang : Option < String > , }"));
+    assert!(implementation.contains("Ok (Self { content , lang : fields . lang , })"));
+  }
+
+  #[test]
+  fn required_fields_deserialize() {
+    let struct_name = Ident::new("Payment", Span::call_site());
+    let fields = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "reference")]
+        pub reference: Option<Max35Text>,
+      "#,
+    )
+    .unwrap();
+    let required_fields = [RequiredField {
+      name: Ident::new("reference", Span::call_site()),
+      description: r#"element "reference""#.to_string(),
+    }];
+
+    let (derive, implementation) = implement_derive_deserialize(
+      false,
+      &required_fields,
+      &TokenStream::new(),
+      &struct_name,
+      &fields,
+    )
+    .unwrap();
+    assert!(derive.is_empty());
+
+    let implementation = implementation.to_string();
+    assert!(implementation
+      .contains("let fields : Payment = yaserde :: YaDeserialize :: deserialize (reader) ? ;"));
+    assert!(implementation.contains("if fields . reference . is_none ()"));
+    assert!(implementation.contains("Ok (Self { reference : fields . reference , })"));
+  }
+
+  #[test]
+  fn mixed_element_error() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let element = Element {
+      name: "bold".to_string(),
+      ..Default::default()
+    };
+    let elements = [MixedElement {
+      path: vec!["sequence".to_string(), "element[1]".to_string()],
+      element: &element,
+    }];
+
+    let error = get_sub_types_implementation(
+      &context,
+      &None,
+      &Ident::new("Paragraph", Span::call_site()),
+      &elements,
+    )
+    .unwrap_err();
+
+    assert_eq!(error.message(), "Element \"bold\" has no type");
+    assert_eq!(error.path(), "sequence/element[1]");
+  }
+}
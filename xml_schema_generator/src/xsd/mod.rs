@@ -0,0 +1,572 @@
+mod annotation;
+mod attribute;
+mod attribute_group;
+mod choice;
+mod complex_content;
+mod complex_type;
+mod derived_types;
+mod element;
+mod enumeration;
+mod extension;
+mod facet;
+mod group;
+mod import;
+mod include;
+mod lexical;
+mod list;
+mod max_occurences;
+mod mixed;
+mod particle;
+mod qualification;
+mod registry;
+mod restriction;
+mod rust_types_mapping;
+mod schema;
+mod sequence;
+mod simple_content;
+mod simple_type;
+mod substitution_group;
+mod type_mappings;
+mod union;
+mod value_constraint;
+mod white_space;
+mod wildcard;
+mod xsd_context;
+
+use crate::{
+  error::step,
+  loader::{is_url, Loader},
+  Error,
+};
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, TokenStream};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+use syn::Visibility;
+use xsd_context::XsdContext;
+use yaserde::de::from_str;
+
+trait Implementation {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    _context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    unimplemented!()
+  }
+
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    _context: &XsdContext,
+    _struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    unimplemented!()
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Xsd {
+  name: String,
+  vis: Visibility,
+  modules: Vec<XsdModule>,
+  type_mappings: Rc<type_mappings::TypeMappings>,
+}
+
+/// A loaded schema file, with the context of its own namespace declarations.
+#[derive(Clone, Debug)]
+struct XsdFile {
+  location: String,
+  path: Option<String>,
+  content: String,
+  context: XsdContext,
+  schema: schema::Schema,
+}
+
+/// All schema files sharing a target namespace, generated in the same Rust module.
+#[derive(Clone, Debug)]
+struct XsdModule {
+  name: Ident,
+  namespace: Option<String>,
+  prefix: Option<String>,
+  files: Vec<XsdFile>,
+}
+
+impl Xsd {
+  /// Load the schema with all the schemas it includes or imports,
+  /// `schemaLocation` being resolved relatively to the including file.
+  pub fn new(
+    name: String,
+    vis: Visibility,
+    location: &str,
+    loader: &Loader,
+    module_namespace_mappings: &BTreeMap<String, String>,
+    type_mappings: &BTreeMap<String, String>,
+  ) -> Result<Self, Error> {
+    let type_mappings = Rc::new(type_mappings::TypeMappings::new(type_mappings)?);
+    let mut files = vec![XsdFile::load(location, loader)?];
+    let mut visited = BTreeSet::from([canonical_location(location)]);
+
+    let mut index = 0;
+    while index < files.len() {
+      let parent = files[index].clone();
+      let target_namespace = &parent.schema.target_namespace;
+
+      for (include_index, include) in parent.schema.includes.iter().enumerate() {
+        let location = resolve_location(&parent.location, &include.schema_location)?;
+        if !visited.insert(canonical_location(&location)) {
+          log::info!("Schema {} already loaded", location);
+          continue;
+        }
+
+        log::info!("Include schema {}", location);
+        let mut file = XsdFile::load(&location, loader)?;
+
+        match &file.schema.target_namespace {
+          // a schema without target namespace takes the one of the including schema
+          None => {
+            if let Some(namespace) = target_namespace {
+              file.context.set_chameleon_namespace(namespace);
+            }
+            file.schema.target_namespace = target_namespace.clone();
+          }
+          Some(namespace) if Some(namespace) != target_namespace.as_ref() => {
+            return Err(
+              Error::new(format!(
+                "Included schema {location} has the target namespace {namespace:?}, expected {target_namespace:?}"
+              ))
+              .within(step("include", None, include_index))
+              .in_file(&parent.location, &parent.content),
+            );
+          }
+          _ => {}
+        }
+
+        files.push(file);
+      }
+
+      for (import_index, import) in parent.schema.imports.iter().enumerate() {
+        if let Some(namespace) = &import.namespace {
+          if module_namespace_mappings.contains_key(namespace) {
+            log::info!("Namespace {} is mapped to an existing module", namespace);
+            continue;
+          }
+        }
+
+        // without location, the schema of the namespace can be found in the catalog
+        let location = match &import.schema_location {
+          Some(schema_location) => resolve_location(&parent.location, schema_location)?,
+          None => match import
+            .namespace
+            .as_deref()
+            .and_then(|namespace| loader.get_namespace_location(namespace))
+          {
+            Some(location) => location,
+            None => {
+              log::warn!(
+                "No schema location to import namespace {:?}",
+                import.namespace
+              );
+              continue;
+            }
+          },
+        };
+        if !visited.insert(canonical_location(&location)) {
+          log::info!("Schema {} already loaded", location);
+          continue;
+        }
+
+        log::info!("Import schema {}", location);
+        let file = XsdFile::load(&location, loader)?;
+
+        if import.namespace != file.schema.target_namespace {
+          return Err(
+            Error::new(format!(
+              "Imported schema {location} has the target namespace {:?}, expected {:?}",
+              file.schema.target_namespace, import.namespace
+            ))
+            .within(step("import", None, import_index))
+            .in_file(&parent.location, &parent.content),
+          );
+        }
+
+        files.push(file);
+      }
+
+      index += 1;
+    }
+
+    // all the top-level components are known before generating code
+    let mut registry = registry::Registry::default();
+    for file in &files {
+      registry
+        .register(&file.schema, &file.context)
+        .map_err(|e| e.in_file(&file.location, &file.content))?;
+    }
+
+    let registry = Rc::new(registry);
+    for file in files.iter_mut() {
+      file.context = file
+        .context
+        .clone()
+        .with_registry(&registry)
+        .with_target_namespace(&file.schema.target_namespace)
+        .with_type_mappings(&type_mappings);
+    }
+
+    let modules = XsdModule::group(files, module_namespace_mappings);
+
+    Ok(Xsd {
+      name,
+      vis,
+      modules,
+      type_mappings,
+    })
+  }
+
+  pub fn implement(&self, target_prefix: &Option<String>) -> Result<TokenStream, Error> {
+    let modules = self.implement_content(target_prefix)?;
+    let mod_name = self.get_module_name();
+    let vis = &self.vis;
+
+    Ok(quote! {
+        mod #mod_name {
+            #modules
+        }
+
+        #vis use #mod_name::*;
+    })
+  }
+
+  /// Content of the generated module, with a sub-module per imported namespace.
+  pub fn implement_content(&self, target_prefix: &Option<String>) -> Result<TokenStream, Error> {
+    Ok(
+      self
+        .implement_modules(target_prefix)?
+        .into_iter()
+        .map(|(name, implementation)| match name {
+          None => implementation,
+          Some(name) => quote!(
+            pub mod #name {
+              #implementation
+            }
+          ),
+        })
+        .collect(),
+    )
+  }
+
+  /// Content of the module generated for each namespace,
+  /// the namespace of the main schema comes first, without sub-module name.
+  pub fn implement_modules(
+    &self,
+    target_prefix: &Option<String>,
+  ) -> Result<Vec<(Option<Ident>, TokenStream)>, Error> {
+    self
+      .modules
+      .iter()
+      .enumerate()
+      .map(|(index, module)| {
+        if index == 0 {
+          Ok((None, module.implement(target_prefix, &self.modules, 1)?))
+        } else {
+          let implementation = module.implement(&module.prefix, &self.modules, 2)?;
+          Ok((Some(module.name.clone()), implementation))
+        }
+      })
+      .collect()
+  }
+
+  pub fn get_module_name(&self) -> Ident {
+    format_ident!("{}", self.name.to_snake_case())
+  }
+}
+
+impl Xsd {
+  /// Local files of all the loaded schemas, including the local copies of remote schemas.
+  pub fn get_local_locations(&self) -> Vec<&str> {
+    self
+      .modules
+      .iter()
+      .flat_map(|module| module.files.iter())
+      .filter_map(|file| file.path.as_deref())
+      .filter(|path| !path.is_empty())
+      .collect()
+  }
+
+  /// Keys of the type mappings which matched no type, element or attribute of the schemas
+  /// generated so far.
+  pub fn get_unused_type_mappings(&self) -> BTreeSet<String> {
+    self.type_mappings.get_unused_keys()
+  }
+}
+
+impl XsdFile {
+  fn new(location: &str, content: &str) -> Result<Self, Error> {
+    let in_file = |message: String| Error::new(message).in_file(location, content);
+
+    let context = XsdContext::new(content).map_err(in_file)?;
+    let schema: schema::Schema = from_str(content).map_err(in_file)?;
+    let context =
+      context.with_form_defaults(&schema.element_form_default, &schema.attribute_form_default);
+
+    Ok(XsdFile {
+      location: location.to_string(),
+      path: Some(location.to_string()).filter(|location| !is_url(location)),
+      content: content.to_string(),
+      context,
+      schema,
+    })
+  }
+
+  /// Load the schema at a location, which stays the base of its relative locations
+  /// even if it is read from a local copy.
+  fn load(location: &str, loader: &Loader) -> Result<Self, Error> {
+    let (content, path) = loader.load(location)?;
+    Ok(XsdFile::new(location, &content)?.with_path(path))
+  }
+
+  fn with_path(mut self, path: Option<String>) -> Self {
+    self.path = path;
+    self
+  }
+
+  fn implement_types(&self, prefix: &Option<String>) -> Result<TokenStream, Error> {
+    self
+      .schema
+      .implement_types(prefix, &self.context)
+      .map_err(|e| e.in_file(&self.location, &self.content))
+  }
+
+  fn implement_elements(&self, prefix: &Option<String>) -> Result<TokenStream, Error> {
+    self
+      .schema
+      .implement_elements(prefix, &self.context)
+      .map_err(|e| e.in_file(&self.location, &self.content))
+  }
+}
+
+impl XsdModule {
+  /// Group files by target namespace, the first module contains the main schema.
+  /// Types of the other modules are referenced through the module names.
+  fn group(files: Vec<XsdFile>, module_namespace_mappings: &BTreeMap<String, String>) -> Vec<Self> {
+    let mut modules: Vec<XsdModule> = vec![];
+
+    for file in files {
+      let namespace = file.schema.target_namespace.clone();
+
+      if let Some(module) = modules
+        .iter_mut()
+        .find(|module| module.namespace == namespace)
+      {
+        module.files.push(file);
+      } else {
+        let used_names: Vec<String> = modules
+          .iter()
+          .map(|module| module.name.to_string())
+          .collect();
+        let name = get_module_name(&namespace, &file.location, &used_names);
+
+        modules.push(XsdModule {
+          name,
+          namespace,
+          prefix: None,
+          files: vec![file],
+        });
+      }
+    }
+
+    let contexts: Vec<XsdContext> = modules
+      .iter()
+      .flat_map(|module| module.files.iter().map(|file| file.context.clone()))
+      .collect();
+
+    let generated_mappings: Vec<(String, String)> = modules
+      .iter()
+      .filter_map(|module| {
+        module
+          .namespace
+          .as_ref()
+          .map(|namespace| (namespace.to_owned(), module.name.to_string()))
+      })
+      .collect();
+
+    for module in modules.iter_mut() {
+      // prefix bound by the importing schemas first, then by the imported one
+      module.prefix = module.namespace.as_ref().and_then(|namespace| {
+        contexts
+          .iter()
+          .chain(module.files.iter().map(|file| &file.context))
+          .find_map(|context| context.get_prefix(namespace))
+      });
+
+      let mut mappings = module_namespace_mappings.clone();
+      for (namespace, name) in &generated_mappings {
+        if Some(namespace) != module.namespace.as_ref() {
+          mappings
+            .entry(namespace.to_owned())
+            .or_insert_with(|| name.to_owned());
+        }
+      }
+
+      for file in module.files.iter_mut() {
+        file.context = file
+          .context
+          .clone()
+          .with_module_namespace_mappings(&mappings);
+      }
+    }
+
+    modules
+  }
+
+  /// Generate the module content, `depth` being the depth of its `xml_schema_types` module.
+  fn implement(
+    &self,
+    prefix: &Option<String>,
+    modules: &[XsdModule],
+    depth: usize,
+  ) -> Result<TokenStream, Error> {
+    let supers: TokenStream = (0..depth).map(|_| quote!(super::)).collect();
+
+    let aliases: TokenStream = modules
+      .iter()
+      .enumerate()
+      .filter(|(_index, module)| module.namespace.is_some() && module.namespace != self.namespace)
+      .map(|(index, module)| {
+        let name = &module.name;
+        if index == 0 {
+          quote!(pub use #supers xml_schema_types as #name;)
+        } else {
+          quote!(pub use #supers #name::xml_schema_types as #name;)
+        }
+      })
+      .collect();
+
+    let types: TokenStream = self
+      .files
+      .iter()
+      .map(|file| file.implement_types(prefix))
+      .collect::<Result<_, _>>()?;
+
+    let elements: TokenStream = self
+      .files
+      .iter()
+      .map(|file| file.implement_elements(prefix))
+      .collect::<Result<_, _>>()?;
+
+    Ok(quote!(
+      pub mod xml_schema_types {
+        #aliases
+        #types
+      }
+
+      #elements
+    ))
+  }
+}
+
+fn resolve_location(parent: &str, location: &str) -> Result<String, String> {
+  if is_url(location) {
+    Ok(location.to_string())
+  } else if is_url(parent) {
+    reqwest::Url::parse(parent)
+      .and_then(|url| url.join(location))
+      .map(|url| url.to_string())
+      .map_err(|e| format!("Unable to resolve {location} from {parent}: {e}"))
+  } else {
+    let path = Path::new(parent)
+      .parent()
+      .map(|directory| directory.join(location))
+      .unwrap_or_else(|| Path::new(location).to_path_buf());
+
+    Ok(path.display().to_string())
+  }
+}
+
+fn canonical_location(location: &str) -> String {
+  if is_url(location) {
+    return location.to_string();
+  }
+
+  fs::canonicalize(location)
+    .map(|path| path.display().to_string())
+    .unwrap_or_else(|_| location.to_string())
+}
+
+/// Module name from the last segment of the namespace, or from the file name without namespace.
+fn get_module_name(namespace: &Option<String>, location: &str, used_names: &[String]) -> Ident {
+  let name = match namespace {
+    Some(namespace) => namespace
+      .split(['/', ':', '#'])
+      .rfind(|segment| !segment.is_empty())
+      .unwrap_or_default()
+      .to_snake_case(),
+    None => Path::new(location)
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().to_snake_case())
+      .unwrap_or_default(),
+  };
+
+  let name = if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+    format!("ns_{name}")
+  } else if syn::parse_str::<Ident>(&name).is_err() {
+    format!("{name}_ns")
+  } else {
+    name
+  };
+
+  let mut unique_name = name.clone();
+  let mut index = 1;
+  while unique_name == "xml_schema_types" || used_names.contains(&unique_name) {
+    index += 1;
+    unique_name = format!("{name}_{index}");
+  }
+
+  format_ident!("{}", unique_name)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn module_names() {
+    let module_name = |namespace: &str| {
+      get_module_name(&Some(namespace.to_string()), "", &["other".to_string()]).to_string()
+    };
+
+    assert_eq!(module_name("http://www.w3.org/2000/09/xmldsig#"), "xmldsig");
+    assert_eq!(
+      module_name("urn:oasis:names:tc:SAML:2.0:assertion"),
+      "assertion"
+    );
+    assert_eq!(module_name("http://example.com/other"), "other_2");
+    assert_eq!(module_name("http://example.com/2.0"), "ns_2_0");
+    assert_eq!(module_name("http://example.com/type"), "type_ns");
+    assert_eq!(
+      get_module_name(&None, "schemas/common-types.xsd", &[]).to_string(),
+      "common_types"
+    );
+  }
+
+  #[test]
+  fn resolve_locations() {
+    assert_eq!(
+      resolve_location("schemas/main.xsd", "common.xsd"),
+      Ok("schemas/common.xsd".to_string())
+    );
+    assert_eq!(
+      resolve_location("http://example.com/schemas/main.xsd", "../common.xsd"),
+      Ok("http://example.com/common.xsd".to_string())
+    );
+    assert_eq!(
+      resolve_location("main.xsd", "http://example.com/common.xsd"),
+      Ok("http://example.com/common.xsd".to_string())
+    );
+  }
+}
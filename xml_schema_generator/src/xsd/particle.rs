@@ -0,0 +1,129 @@
+use crate::xsd::{
+  choice::Choice, element::Element, group::Group, sequence::Sequence, wildcard::Wildcard,
+};
+use std::io::Read;
+use xml::attribute::OwnedAttribute;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, YaDeserialize};
+
+/// Child of a sequence or of a choice, kept in document order.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Particle {
+  Element(Box<Element>),
+  Choice(Choice),
+  Sequence(Sequence),
+  Group(Group),
+  Any(Wildcard),
+}
+
+impl Particle {
+  /// Name of the XSD component, used in the paths of errors.
+  pub fn get_kind(&self) -> &'static str {
+    match self {
+      Particle::Element(_) => "element",
+      Particle::Choice(_) => "choice",
+      Particle::Sequence(_) => "sequence",
+      Particle::Group(_) => "group",
+      Particle::Any(_) => "any",
+    }
+  }
+}
+
+/// Particles with their position among the siblings of the same kind, e.g. the index of
+/// `element[2]` in the path of an error.
+pub fn get_indexed_particles(particles: &[Particle]) -> Vec<(usize, &Particle)> {
+  particles
+    .iter()
+    .enumerate()
+    .map(|(position, particle)| {
+      let index = particles[..position]
+        .iter()
+        .filter(|other| other.get_kind() == particle.get_kind())
+        .count();
+      (index, particle)
+    })
+    .collect()
+}
+
+/// Value of an unqualified attribute of a model group.
+pub fn get_attribute<'a>(attributes: &'a [OwnedAttribute], name: &str) -> Option<&'a str> {
+  attributes
+    .iter()
+    .find(|attribute| attribute.name.local_name == name && attribute.name.namespace.is_none())
+    .map(|attribute| attribute.value.as_str())
+}
+
+/// Read the attributes and the particles of a sequence or a choice, from its start element.
+/// Its end element is left to the parent structure, like the derived deserializers do.
+pub fn read_model_group<R: Read>(
+  reader: &mut Deserializer<R>,
+) -> Result<(Vec<OwnedAttribute>, Vec<Particle>), String> {
+  let attributes = match reader.next_event()? {
+    XmlEvent::StartElement { attributes, .. } => attributes,
+    event => return Err(format!("Expected a model group, found {event:?}")),
+  };
+
+  let mut particles = vec![];
+
+  loop {
+    match reader.peek()?.to_owned() {
+      XmlEvent::StartElement { name, .. } => {
+        let particle = match name.local_name.as_str() {
+          "element" => Particle::Element(Box::new(Element::deserialize(reader)?)),
+          "choice" => Particle::Choice(Choice::deserialize(reader)?),
+          "sequence" => Particle::Sequence(Sequence::deserialize(reader)?),
+          "group" => Particle::Group(Group::deserialize(reader)?),
+          "any" => Particle::Any(Wildcard::deserialize(reader)?),
+          // annotations
+          _ => {
+            reader.next_event()?;
+            reader.skip_element(|_event| {})?;
+            continue;
+          }
+        };
+
+        particles.push(particle);
+
+        // end element of the particle
+        reader.next_event()?;
+      }
+      XmlEvent::EndElement { .. } | XmlEvent::EndDocument => break,
+      _ => {
+        reader.next_event()?;
+      }
+    }
+  }
+
+  Ok((attributes, particles))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn particles_in_document_order() {
+    let sequence: Sequence = yaserde::de::from_str(
+      r#"<xs:sequence xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:annotation><xs:documentation>Letter</xs:documentation></xs:annotation>
+        <xs:element name="sender" type="xs:string"/>
+        <xs:choice>
+          <xs:element name="email" type="xs:string"/>
+        </xs:choice>
+        <xs:element name="body" type="xs:string"/>
+        <xs:any/>
+      </xs:sequence>"#,
+    )
+    .unwrap();
+
+    let particles: Vec<(usize, &str)> = get_indexed_particles(&sequence.particles)
+      .into_iter()
+      .map(|(index, particle)| (index, particle.get_kind()))
+      .collect();
+
+    assert_eq!(
+      particles,
+      vec![(0, "element"), (0, "choice"), (1, "element"), (0, "any")]
+    );
+  }
+}
@@ -0,0 +1,487 @@
+use crate::{
+  error::step,
+  xsd::{
+    attribute::Attribute, attribute_group::AttributeGroup, complex_type::ComplexType,
+    element::Element, group::Group, schema::Schema, simple_type::SimpleType, XsdContext,
+  },
+  Error,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Symbol spaces of the top-level components, a name can be used once in each of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentKind {
+  Type,
+  Element,
+  Attribute,
+  Group,
+  AttributeGroup,
+}
+
+/// Top-level component registered with its qualified name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Component {
+  SimpleType,
+  ComplexType { simple_content: bool },
+  Element { nillable: bool },
+  Attribute,
+  Group,
+  AttributeGroup,
+}
+
+/// Concrete element of a substitution group, with the context of the schema defining its type.
+#[derive(Clone, Debug)]
+pub struct Substitute {
+  pub namespace: String,
+  /// Prefix bound to the namespace of the element in its schema.
+  pub prefix: Option<String>,
+  /// Declaration of the element, with the type of its head if it has no type.
+  pub element: Element,
+  pub context: XsdContext,
+}
+
+/// Concrete complex type which can be used in place of its base types with `xsi:type`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DerivedType {
+  pub namespace: String,
+  pub name: String,
+  /// Prefix bound to the namespace of the type in its schema.
+  pub prefix: Option<String>,
+}
+
+/// Index of the top-level components of all the loaded schemas, by namespace and local name.
+/// It is built before generating code to validate references.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+  namespaces: BTreeSet<String>,
+  components: BTreeMap<(ComponentKind, String, String), Component>,
+  attributes: BTreeMap<(String, String), (Attribute, XsdContext)>,
+  attribute_groups: BTreeMap<(String, String), (AttributeGroup, XsdContext)>,
+  groups: BTreeMap<(String, String), (Group, XsdContext)>,
+  elements: BTreeMap<(String, String), (Element, XsdContext)>,
+  complex_types: BTreeMap<(String, String), (ComplexType, XsdContext)>,
+  simple_types: BTreeMap<(String, String), (SimpleType, XsdContext)>,
+}
+
+impl Registry {
+  /// Register the components of a schema, the namespace of a schema without target namespace is empty.
+  /// Attributes, attribute groups, elements and types are kept with the `context` of their schema,
+  /// to be expanded where they are used, to find the substitution groups and derived types,
+  /// and the types which values are checked against.
+  pub fn register(&mut self, schema: &Schema, context: &XsdContext) -> Result<(), Error> {
+    let namespace = schema.target_namespace.clone().unwrap_or_default();
+    self.namespaces.insert(namespace.clone());
+
+    let components = schema
+      .simple_type
+      .iter()
+      .map(|simple_type| ("simpleType", Some(&simple_type.name), Component::SimpleType))
+      .chain(schema.complex_type.iter().map(|complex_type| {
+        let simple_content = complex_type.simple_content.is_some();
+        (
+          "complexType",
+          Some(&complex_type.name),
+          Component::ComplexType { simple_content },
+        )
+      }))
+      .chain(schema.elements.iter().map(|element| {
+        let nillable = element.nillable;
+        (
+          "element",
+          Some(&element.name),
+          Component::Element { nillable },
+        )
+      }))
+      .chain(
+        schema
+          .attributes
+          .iter()
+          .map(|attribute| ("attribute", attribute.name.as_ref(), Component::Attribute)),
+      )
+      .chain(
+        schema
+          .group
+          .iter()
+          .map(|group| ("group", group.name.as_ref(), Component::Group)),
+      )
+      .chain(schema.attribute_group.iter().map(|attribute_group| {
+        (
+          "attributeGroup",
+          attribute_group.name.as_ref(),
+          Component::AttributeGroup,
+        )
+      }));
+
+    let mut indexes = BTreeMap::new();
+    for (tag, name, component) in components {
+      let index = indexes.entry(tag).or_insert(0);
+      let current_step = step(tag, name.map(|name| name.as_str()), *index);
+      *index += 1;
+
+      let Some(name) = name.filter(|name| !name.is_empty()) else {
+        return Err(Error::new(format!("Top-level {tag} requires a name")).within(current_step));
+      };
+
+      let key = (component.kind(), namespace.clone(), name.to_owned());
+      if self.components.insert(key, component).is_some() {
+        return Err(
+          Error::new(format!(
+            "{} {name:?} is already defined in namespace {namespace:?}",
+            component.kind()
+          ))
+          .within(current_step),
+        );
+      }
+    }
+
+    for attribute in &schema.attributes {
+      if let Some(name) = &attribute.name {
+        self.attributes.insert(
+          (namespace.clone(), name.to_owned()),
+          (attribute.clone(), context.clone()),
+        );
+      }
+    }
+
+    for attribute_group in &schema.attribute_group {
+      if let Some(name) = &attribute_group.name {
+        self.attribute_groups.insert(
+          (namespace.clone(), name.to_owned()),
+          (attribute_group.clone(), context.clone()),
+        );
+      }
+    }
+
+    for group in &schema.group {
+      if let Some(name) = &group.name {
+        self.groups.insert(
+          (namespace.clone(), name.to_owned()),
+          (group.clone(), context.clone()),
+        );
+      }
+    }
+
+    for element in &schema.elements {
+      self.elements.insert(
+        (namespace.clone(), element.name.to_owned()),
+        (element.clone(), context.clone()),
+      );
+    }
+
+    for complex_type in &schema.complex_type {
+      self.complex_types.insert(
+        (namespace.clone(), complex_type.name.to_owned()),
+        (complex_type.clone(), context.clone()),
+      );
+    }
+
+    for simple_type in &schema.simple_type {
+      self.simple_types.insert(
+        (namespace.clone(), simple_type.name.to_owned()),
+        (simple_type.clone(), context.clone()),
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Check if a schema with this target namespace is loaded.
+  pub fn has_namespace(&self, namespace: &str) -> bool {
+    self.namespaces.contains(namespace)
+  }
+
+  pub fn get(&self, kind: ComponentKind, namespace: &str, name: &str) -> Option<Component> {
+    self
+      .components
+      .get(&(kind, namespace.to_owned(), name.to_owned()))
+      .copied()
+  }
+
+  /// Namespaces and local names of the components of a kind.
+  pub fn names(&self, kind: ComponentKind) -> impl Iterator<Item = (&str, &str)> {
+    self
+      .components
+      .keys()
+      .filter(move |(component_kind, _, _)| *component_kind == kind)
+      .map(|(_, namespace, name)| (namespace.as_str(), name.as_str()))
+  }
+
+  pub fn get_attribute(&self, namespace: &str, name: &str) -> Option<&(Attribute, XsdContext)> {
+    self
+      .attributes
+      .get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  pub fn get_attribute_group(
+    &self,
+    namespace: &str,
+    name: &str,
+  ) -> Option<&(AttributeGroup, XsdContext)> {
+    self
+      .attribute_groups
+      .get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  pub fn get_group(&self, namespace: &str, name: &str) -> Option<&(Group, XsdContext)> {
+    self.groups.get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  pub fn get_element(&self, namespace: &str, name: &str) -> Option<&(Element, XsdContext)> {
+    self.elements.get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  pub fn get_complex_type(
+    &self,
+    namespace: &str,
+    name: &str,
+  ) -> Option<&(ComplexType, XsdContext)> {
+    self
+      .complex_types
+      .get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  pub fn get_simple_type(&self, namespace: &str, name: &str) -> Option<&(SimpleType, XsdContext)> {
+    self
+      .simple_types
+      .get(&(namespace.to_owned(), name.to_owned()))
+  }
+
+  /// Concrete elements which can substitute a top-level element, the element itself included
+  /// when it is not abstract. Members are found through the substitution groups of all the
+  /// top-level elements, members of abstract members included. The list is empty when no
+  /// element substitutes this one.
+  pub fn get_substitutes(&self, namespace: &str, name: &str) -> Vec<Substitute> {
+    let mut group = vec![(namespace.to_owned(), name.to_owned())];
+
+    let mut index = 0;
+    while index < group.len() {
+      let head = group[index].clone();
+
+      for (key, (element, context)) in &self.elements {
+        if !group.contains(key)
+          && element
+            .get_substitution_groups()
+            .any(|reference| context.resolve(reference).ok().as_ref() == Some(&head))
+        {
+          group.push(key.clone());
+        }
+      }
+
+      index += 1;
+    }
+
+    if group.len() == 1 {
+      return vec![];
+    }
+
+    group
+      .into_iter()
+      .filter_map(|key| {
+        let (element, context) = self.elements.get(&key)?;
+        if element.is_abstract {
+          return None;
+        }
+
+        let (declaration, declaration_context) = self.get_type_declaration(element, context);
+        let (namespace, _name) = key;
+
+        Some(Substitute {
+          prefix: context.get_prefix(&namespace),
+          namespace,
+          element: Element {
+            kind: declaration.kind.clone(),
+            complex_type: declaration.complex_type.clone(),
+            simple_type: declaration.simple_type.clone(),
+            ..element.clone()
+          },
+          context: declaration_context.clone(),
+        })
+      })
+      .collect()
+  }
+
+  /// Concrete complex types derived from a complex type, by extension or restriction of their
+  /// complex content, the type itself included when it is not abstract. The list is empty when
+  /// no type derives from this one.
+  pub fn get_derived_types(&self, namespace: &str, name: &str) -> Vec<DerivedType> {
+    let mut derived_types = vec![(namespace.to_owned(), name.to_owned())];
+
+    let mut index = 0;
+    while index < derived_types.len() {
+      let base = derived_types[index].clone();
+
+      for (key, (complex_type, context)) in &self.complex_types {
+        if !derived_types.contains(key)
+          && complex_type
+            .get_base()
+            .and_then(|reference| context.resolve(reference).ok())
+            .as_ref()
+            == Some(&base)
+        {
+          derived_types.push(key.clone());
+        }
+      }
+
+      index += 1;
+    }
+
+    if derived_types.len() == 1 {
+      return vec![];
+    }
+
+    derived_types
+      .into_iter()
+      .filter_map(|(namespace, name)| {
+        let (complex_type, context) = self.complex_types.get(&(namespace.clone(), name.clone()))?;
+        if complex_type.is_abstract {
+          return None;
+        }
+
+        Some(DerivedType {
+          prefix: context.get_prefix(&namespace),
+          namespace,
+          name,
+        })
+      })
+      .collect()
+  }
+
+  /// Declaration of the type of an element, the one of its substitution group head when it has no type.
+  fn get_type_declaration<'a>(
+    &'a self,
+    element: &'a Element,
+    context: &'a XsdContext,
+  ) -> (&'a Element, &'a XsdContext) {
+    let mut declaration = (element, context);
+    let mut visited = BTreeSet::new();
+
+    while !declaration.0.has_type() {
+      let (element, context) = declaration;
+      let Some(head) = element
+        .get_substitution_groups()
+        .next()
+        .and_then(|reference| context.resolve(reference).ok())
+      else {
+        break;
+      };
+
+      match self.elements.get(&head) {
+        Some((head_element, head_context)) if visited.insert(head) => {
+          declaration = (head_element, head_context)
+        }
+        _ => break,
+      }
+    }
+
+    declaration
+  }
+}
+
+impl Component {
+  pub fn kind(&self) -> ComponentKind {
+    match self {
+      Component::SimpleType | Component::ComplexType { .. } => ComponentKind::Type,
+      Component::Element { .. } => ComponentKind::Element,
+      Component::Attribute => ComponentKind::Attribute,
+      Component::Group => ComponentKind::Group,
+      Component::AttributeGroup => ComponentKind::AttributeGroup,
+    }
+  }
+
+  /// Simple types and complex types with simple content are read from the text of the element.
+  pub fn has_text_content(&self) -> bool {
+    matches!(
+      self,
+      Component::SimpleType
+        | Component::ComplexType {
+          simple_content: true
+        }
+    )
+  }
+}
+
+impl fmt::Display for ComponentKind {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    let label = match self {
+      ComponentKind::Type => "Type",
+      ComponentKind::Element => "Element",
+      ComponentKind::Attribute => "Attribute",
+      ComponentKind::Group => "Group",
+      ComponentKind::AttributeGroup => "Attribute group",
+    };
+
+    formatter.write_str(label)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use yaserde::de::from_str;
+
+  static SCHEMA: &str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" targetNamespace="http://example.com">
+      <xs:simpleType name="Code">
+        <xs:restriction base="xs:string"/>
+      </xs:simpleType>
+      <xs:complexType name="Price">
+        <xs:simpleContent>
+          <xs:extension base="xs:string"/>
+        </xs:simpleContent>
+      </xs:complexType>
+      <xs:complexType name="Book"/>
+      <xs:element name="Book" type="Book"/>
+      <xs:group name="Content"/>
+    </xs:schema>
+  "#;
+
+  #[test]
+  fn register_components() {
+    let schema: Schema = from_str(SCHEMA).unwrap();
+    let context = XsdContext::new(SCHEMA).unwrap();
+
+    let mut registry = Registry::default();
+    registry.register(&schema, &context).unwrap();
+
+    let namespace = "http://example.com";
+    assert!(registry.has_namespace(namespace));
+    assert_eq!(
+      registry.get(ComponentKind::Type, namespace, "Code"),
+      Some(Component::SimpleType)
+    );
+    assert_eq!(
+      registry.get(ComponentKind::Type, namespace, "Price"),
+      Some(Component::ComplexType {
+        simple_content: true
+      })
+    );
+    assert_eq!(
+      registry.get(ComponentKind::Element, namespace, "Book"),
+      Some(Component::Element { nillable: false })
+    );
+    assert_eq!(
+      registry.get(ComponentKind::Group, namespace, "Content"),
+      Some(Component::Group)
+    );
+    assert_eq!(
+      registry.get(ComponentKind::Type, namespace, "Content"),
+      None
+    );
+    assert_eq!(registry.get(ComponentKind::Type, "", "Code"), None);
+  }
+
+  #[test]
+  fn duplicated_component() {
+    let schema: Schema = from_str(SCHEMA).unwrap();
+    let context = XsdContext::new(SCHEMA).unwrap();
+
+    let mut registry = Registry::default();
+    registry.register(&schema, &context).unwrap();
+
+    assert_eq!(
+      registry.register(&schema, &context).unwrap_err().to_string(),
+      "simpleType[@name=Code]: Type \"Code\" is already defined in namespace \"http://example.com\""
+    );
+  }
+}
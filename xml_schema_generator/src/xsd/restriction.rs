@@ -0,0 +1,429 @@
+use crate::{
+  xsd::{
+    attribute::Attribute,
+    attribute_group::AttributeGroup,
+    choice::Choice,
+    enumeration::{get_variant_idents, Enumeration},
+    facet::{implement_facet_checks, BoundType, Facet},
+    group::Group,
+    rust_types_mapping::RustTypesMapping,
+    sequence::Sequence,
+    simple_type::{implement_deserialize_from_str, implement_serialize_display, SimpleType},
+    white_space::WhiteSpace,
+    wildcard::Wildcard,
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use proc_macro2::{Ident, TokenStream};
+use std::collections::BTreeSet;
+
+/// Restrictions of a simple type and of its bases, with the context of their schema,
+/// and the built-in type at their root.
+type BaseRestrictions = (Vec<(Restriction, XsdContext)>, String);
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Restriction {
+  #[yaserde(rename = "base", attribute)]
+  pub base: Option<String>,
+  #[yaserde(rename = "enumeration")]
+  pub enumerations: Vec<Enumeration>,
+  #[yaserde(rename = "minInclusive")]
+  pub min_inclusive: Option<Facet>,
+  #[yaserde(rename = "maxInclusive")]
+  pub max_inclusive: Option<Facet>,
+  #[yaserde(rename = "minExclusive")]
+  pub min_exclusive: Option<Facet>,
+  #[yaserde(rename = "maxExclusive")]
+  pub max_exclusive: Option<Facet>,
+  #[yaserde(rename = "length")]
+  pub length: Option<Facet>,
+  #[yaserde(rename = "minLength")]
+  pub min_length: Option<Facet>,
+  #[yaserde(rename = "maxLength")]
+  pub max_length: Option<Facet>,
+  #[yaserde(rename = "pattern")]
+  pub patterns: Vec<Facet>,
+  #[yaserde(rename = "totalDigits")]
+  pub total_digits: Option<Facet>,
+  #[yaserde(rename = "fractionDigits")]
+  pub fraction_digits: Option<Facet>,
+  #[yaserde(rename = "whiteSpace")]
+  pub white_space: Option<Facet>,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  #[yaserde(rename = "anyAttribute")]
+  pub any_attribute: Option<Wildcard>,
+  pub sequence: Option<Sequence>,
+  pub choice: Option<Choice>,
+  pub group: Option<Group>,
+}
+
+impl Implementation for Restriction {
+  fn implement_childs(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    if self.enumerations.is_empty() {
+      return self.implement_newtype(context, struct_name);
+    }
+
+    let white_space = self.get_white_space(context)?;
+    let normalize = white_space.implement_normalize();
+
+    let variants = get_variant_idents(
+      &self
+        .enumerations
+        .iter()
+        .map(|enumeration| &enumeration.value)
+        .collect::<Vec<_>>(),
+    );
+    let values: Vec<String> = self
+      .enumerations
+      .iter()
+      .map(|enumeration| white_space.normalize(&enumeration.value))
+      .collect();
+
+    let definitions: TokenStream = self
+      .enumerations
+      .iter()
+      .zip(variants.iter())
+      .enumerate()
+      .map(|(index, (enumeration, variant))| {
+        let docs = enumeration
+          .annotation
+          .as_ref()
+          .map(|annotation| annotation.implement(namespace_definition, prefix, context))
+          .transpose()?
+          .unwrap_or_default();
+
+        let default = (index == 0)
+          .then_some(quote!(#[default]))
+          .unwrap_or_default();
+        let value = white_space.normalize(&enumeration.value);
+
+        Ok::<_, Error>(quote!(
+          #docs
+          #default
+          #[yaserde(rename = #value)]
+          #variant,
+        ))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let struct_label = struct_name.to_string();
+    let deserialize_implementation = implement_deserialize_from_str(struct_name);
+
+    Ok(quote!(
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub enum #struct_name {
+        #definitions
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #normalize
+          match value {
+            #(#values => Ok(#struct_name::#variants),)*
+            _ => Err(format!("{:?} is not a valid value for {}", value, #struct_label)),
+          }
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          match self {
+            #(#struct_name::#variants => formatter.write_str(#values),)*
+          }
+        }
+      }
+
+      #deserialize_implementation
+    ))
+  }
+}
+
+impl Restriction {
+  /// Check if the restriction has facets, other than enumerations.
+  pub fn has_facets(&self) -> bool {
+    [
+      &self.min_inclusive,
+      &self.max_inclusive,
+      &self.min_exclusive,
+      &self.max_exclusive,
+      &self.length,
+      &self.min_length,
+      &self.max_length,
+      &self.total_digits,
+      &self.fraction_digits,
+      &self.white_space,
+    ]
+    .iter()
+    .any(|facet| facet.is_some())
+      || !self.patterns.is_empty()
+  }
+
+  /// Normalization of the whitespace of values, from the `whiteSpace` facet or the built-in base type.
+  pub fn get_white_space(&self, context: &XsdContext) -> Result<WhiteSpace, Error> {
+    match (&self.white_space, &self.base) {
+      (Some(facet), _) => WhiteSpace::from_facet(&facet.value),
+      (None, Some(base)) => Ok(WhiteSpace::of_type(context, base)),
+      (None, None) => Ok(WhiteSpace::Preserve),
+    }
+  }
+
+  /// Newtype of the base type, its constructor checks the facets.
+  /// A restriction of a user-defined type wraps it, its value is parsed and checked as the base
+  /// type first, then its lexical form is checked against the facets of the restriction.
+  /// `Default` is not implemented, no value is valid for all the facets in general.
+  fn implement_newtype(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    let Some(base) = &self.base else {
+      return Err(Error::new("Missing base for restriction"));
+    };
+
+    let is_xs_type = RustTypesMapping::is_xs_type(context, base);
+    // string values are normalized by the whiteSpace facet of the restriction
+    let rust_type = if is_xs_type && RustTypesMapping::is_xs_string(context, base) {
+      quote!(String)
+    } else {
+      RustTypesMapping::get(context, base)?
+    };
+
+    let base_restrictions = self.get_base_restrictions(context)?;
+    let built_in = base_restrictions
+      .as_ref()
+      .map(|(restrictions, built_in)| {
+        let (_, built_in_context) = &restrictions[restrictions.len() - 1];
+        let name = built_in.split(':').next_back().unwrap_or_default();
+        let built_in_type = if is_xs_type {
+          rust_type.clone()
+        } else {
+          RustTypesMapping::get(built_in_context, built_in)?
+        };
+        Ok::<_, Error>((name, built_in_type))
+      })
+      .transpose()?;
+
+    let bound_type = built_in.as_ref().map(|(name, built_in_type)| BoundType {
+      name,
+      rust_type: built_in_type,
+    });
+    let checks = implement_facet_checks(self, struct_name, &rust_type, bound_type)?;
+
+    let struct_label = struct_name.to_string();
+    let normalize = self.get_white_space(context)?.implement_normalize();
+    // values of other built-in types than strings are collapsed, which trimming them is enough for
+    let (normalize, parse_value) = if rust_type.to_string() == "String" {
+      (normalize, quote!(value.to_string()))
+    } else if is_xs_type {
+      (
+        quote!(),
+        quote!(value.trim().parse::<#rust_type>().map_err(|_| format!("{:?} is not a valid value for {}", value, #struct_label))?),
+      )
+    } else {
+      (normalize, quote!(value.parse::<#rust_type>()?))
+    };
+
+    let deserialize_implementation = implement_deserialize_from_str(struct_name);
+    let serialize_implementation = implement_serialize_display(struct_name, quote!());
+
+    Ok(quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      pub struct #struct_name(#rust_type);
+
+      impl #struct_name {
+        pub fn new(value: #rust_type) -> Result<Self, String> {
+          {
+            let value = &value;
+            #checks
+          }
+          Ok(#struct_name(value))
+        }
+
+        pub fn value(&self) -> &#rust_type {
+          &self.0
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #normalize
+          #struct_name::new(#parse_value)
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          write!(formatter, "{}", self.0)
+        }
+      }
+
+      #deserialize_implementation
+
+      #serialize_implementation
+    ))
+  }
+
+  /// Restrictions of the user-defined simple types this one derives from, this one first,
+  /// with the context of their schema, and the built-in type at their root.
+  /// `None` if a base is a list, a union, or a type not defined in the loaded schemas.
+  pub fn get_base_restrictions(
+    &self,
+    context: &XsdContext,
+  ) -> Result<Option<BaseRestrictions>, Error> {
+    let mut restrictions = vec![(self.clone(), context.clone())];
+    let mut bases = BTreeSet::new();
+
+    loop {
+      let (restriction, context) = &restrictions[restrictions.len() - 1];
+      let Some(base) = restriction.base.clone() else {
+        return Err(Error::new("Missing base for restriction"));
+      };
+
+      if RustTypesMapping::is_xs_type(context, &base) {
+        return Ok(Some((restrictions, base)));
+      }
+
+      if context.get_type_mapping(&base).is_some() {
+        return Ok(None);
+      }
+
+      if !bases.insert(context.resolve(&base)?) {
+        return Err(Error::new(format!(
+          "Simple type {base:?} is derived from itself"
+        )));
+      }
+
+      match context.get_simple_type(&base)? {
+        Some((
+          SimpleType {
+            restriction: Some(restriction),
+            list: None,
+            union: None,
+            ..
+          },
+          definition_context,
+        )) => restrictions.push((restriction, definition_context)),
+        _ => return Ok(None),
+      }
+    }
+  }
+
+  pub fn get_type_implementation(
+    &self,
+    context: &XsdContext,
+    _prefix: &Option<String>,
+  ) -> Result<TokenStream, Error> {
+    if let Some(base) = &self.base {
+      RustTypesMapping::get(context, base)
+    } else {
+      Err(Error::new("Missing base for restriction"))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+  use std::str::FromStr;
+
+  #[test]
+  fn enumeration_restriction() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![
+        Enumeration {
+          value: "r".to_string(),
+          annotation: None,
+        },
+        Enumeration {
+          value: "s".to_string(),
+          annotation: None,
+        },
+      ],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("AlignmentType", Span::call_site());
+
+    let implementation = restriction
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaSerialize)]
+        pub enum AlignmentType {
+          #[default]
+          #[yaserde(rename = "r")]
+          R,
+          #[yaserde(rename = "s")]
+          S,
+        }
+
+        impl std::str::FromStr for AlignmentType {
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+              "r" => Ok(AlignmentType::R),
+              "s" => Ok(AlignmentType::S),
+              _ => Err(format!("{:?} is not a valid value for {}", value, "AlignmentType")),
+            }
+          }
+        }
+
+        impl std::fmt::Display for AlignmentType {
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+              AlignmentType::R => formatter.write_str("r"),
+              AlignmentType::S => formatter.write_str("s"),
+            }
+          }
+        }
+
+        impl yaserde::YaDeserialize for AlignmentType {
+          fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+            loop {
+              match reader.peek()? .to_owned() {
+                xml::reader::XmlEvent::StartElement{..} => {
+                  reader.next_event()? ;
+                }
+                xml::reader::XmlEvent::Characters(ref text_content) => {
+                  reader.next_event()? ;
+                  return text_content.parse();
+                }
+                _ => {break;}
+              }
+            }
+
+            "".parse()
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
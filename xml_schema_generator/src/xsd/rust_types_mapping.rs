@@ -0,0 +1,725 @@
+use crate::{
+  xsd::{derived_types, registry::ComponentKind, substitution_group, XsdContext},
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+#[cfg(any(feature = "date-time", feature = "integer-types"))]
+use proc_macro2::{Ident, Span};
+use syn::{parse_str, TypePath};
+
+#[derive(Debug)]
+pub struct RustTypesMapping {}
+
+impl RustTypesMapping {
+  pub fn get(context: &XsdContext, kind: &str) -> Result<TokenStream, Error> {
+    if let Some(rust_type) = context.get_type_mapping(kind) {
+      return Ok(rust_type);
+    }
+
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      if context.match_xml_schema_prefix(items.first().unwrap()) {
+        RustTypesMapping::basic_type(items.last().unwrap())
+      } else {
+        RustTypesMapping::get_reference(context, ComponentKind::Type, kind)
+      }
+    } else if items.len() == 1 {
+      if context.has_xml_schema_prefix() {
+        RustTypesMapping::get_reference(context, ComponentKind::Type, kind)
+      } else {
+        RustTypesMapping::basic_type(items.last().unwrap())
+      }
+    } else {
+      Err(Error::new(format!("Unknown type {kind:?}")))
+    }
+  }
+
+  /// Rust type of a reference to a top-level component, checked in the loaded schemas.
+  pub fn get_reference(
+    context: &XsdContext,
+    kind: ComponentKind,
+    reference: &str,
+  ) -> Result<TokenStream, Error> {
+    let items: Vec<&str> = reference.split(':').collect();
+    if items.len() > 2 {
+      return Err(Error::new(format!("Unknown type {reference:?}")));
+    }
+
+    context.lookup(kind, reference)?;
+    RustTypesMapping::extern_type(context, items)
+  }
+
+  /// Rust type of the substitution group of a referenced element, or of its list if `multiple`.
+  pub fn get_substitution_group(
+    context: &XsdContext,
+    reference: &str,
+    multiple: bool,
+  ) -> Result<TokenStream, Error> {
+    context.lookup(ComponentKind::Element, reference)?;
+
+    let mut items: Vec<String> = reference.split(':').map(str::to_string).collect();
+    if let Some(name) = items.last_mut() {
+      *name = substitution_group::get_name(name, multiple);
+    }
+
+    RustTypesMapping::extern_type(context, items.iter().map(String::as_str).collect())
+  }
+
+  /// Rust type of the enumeration of the types derived from a referenced complex type.
+  pub fn get_derived_types(context: &XsdContext, reference: &str) -> Result<TokenStream, Error> {
+    context.lookup(ComponentKind::Type, reference)?;
+
+    let mut items: Vec<String> = reference.split(':').map(str::to_string).collect();
+    if let Some(name) = items.last_mut() {
+      *name = derived_types::get_name(name);
+    }
+
+    RustTypesMapping::extern_type(context, items.iter().map(String::as_str).collect())
+  }
+
+  /// Rust type of a top-level type by its namespace and local name.
+  pub fn get_qualified(
+    context: &XsdContext,
+    namespace: &str,
+    name: &str,
+  ) -> Result<TokenStream, Error> {
+    let module = context
+      .get_namespace_module(namespace)
+      .map(|module| format!("{module}::"))
+      .unwrap_or_default();
+
+    RustTypesMapping::type_path(module, name)
+  }
+
+  pub fn is_xs_type(context: &XsdContext, kind: &str) -> bool {
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      context.match_xml_schema_prefix(items.first().unwrap())
+    } else {
+      items.len() == 1 && !context.has_xml_schema_prefix()
+    }
+  }
+
+  /// Check if the type is a built-in type with a string value, like `xs:string` or `xs:token`.
+  /// As the text content of a structure, its value is read as a `String`.
+  pub fn is_xs_string(context: &XsdContext, kind: &str) -> bool {
+    RustTypesMapping::is_xs_type(context, kind)
+      && context.get_type_mapping(kind).is_none()
+      && RustTypesMapping::basic_type(kind.split(':').next_back().unwrap_or_default()).is_ok_and(
+        |rust_type| {
+          matches!(
+            rust_type.to_string().as_str(),
+            "String"
+              | "xml_schema :: types :: XsNormalizedString"
+              | "xml_schema :: types :: XsToken"
+          )
+        },
+      )
+  }
+
+  /// Check if the Rust type is a primitive number type.
+  pub fn is_number(rust_type: &str) -> bool {
+    matches!(
+      rust_type,
+      "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f32" | "f64"
+    )
+  }
+
+  /// Rust literal of a number of a primitive type, `None` if the value is not valid for this type.
+  pub fn get_number_literal(rust_type: &str, value: &str) -> Option<String> {
+    fn integer<T: std::str::FromStr + ToString>(value: &str) -> Option<String> {
+      value.parse::<T>().ok().map(|value| value.to_string())
+    }
+
+    let value = value.trim();
+    match rust_type {
+      "i8" => integer::<i8>(value),
+      "u8" => integer::<u8>(value),
+      "i16" => integer::<i16>(value),
+      "u16" => integer::<u16>(value),
+      "i32" => integer::<i32>(value),
+      "u32" => integer::<u32>(value),
+      "i64" => integer::<i64>(value),
+      "u64" => integer::<u64>(value),
+      "f32" => value
+        .parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(|value| format!("{value:?}")),
+      "f64" => value
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(|value| format!("{value:?}")),
+      _ => None,
+    }
+  }
+
+  /// Rust type of a built-in type of XML Schema Part 2.
+  fn basic_type(item: &str) -> Result<TokenStream, Error> {
+    let rust_type = match item {
+      "bool" => quote!(bool),
+      "boolean" => quote!(bool),
+      "byte" => quote!(i8),
+      "unsignedByte" => quote!(u8),
+      "short" => quote!(i16),
+      "unsignedShort" => quote!(u16),
+      "int" => quote!(i32),
+      "integer" => RustTypesMapping::integer_type(),
+      "positiveInteger" | "nonNegativeInteger" | "negativeInteger" | "nonPositiveInteger" => {
+        RustTypesMapping::sign_restricted_integer_type(item)
+      }
+      "unsignedInt" => quote!(u32),
+      "long" => quote!(i64),
+      "unsignedLong" => quote!(u64),
+      "float" => quote!(f32),
+      "double" => quote!(f64),
+      "decimal" => RustTypesMapping::decimal_type(),
+      "string" | "QName" | "NOTATION" | "anyURI" => quote!(String),
+      "normalizedString" => quote!(xml_schema::types::XsNormalizedString),
+      "token" | "language" | "Name" | "NCName" | "NMTOKEN" | "ID" | "IDREF" | "ENTITY" => {
+        quote!(xml_schema::types::XsToken)
+      }
+      "IDREFS" | "NMTOKENS" | "ENTITIES" => quote!(xml_schema::types::XsList<String>),
+      "hexBinary" => quote!(String),
+      "base64Binary" => quote!(String),
+      "dateTime" | "date" | "time" | "duration" | "gYearMonth" | "gMonthDay" | "gDay"
+      | "gMonth" => RustTypesMapping::date_time_type(item),
+      "gYear" => RustTypesMapping::year_type(),
+      "anySimpleType" | "anyType" => quote!(String),
+      _ => return Err(Error::new(format!("Type {item:?} not implemented"))),
+    };
+
+    Ok(rust_type)
+  }
+
+  /// Date and time types, with the lexical form of the value as `String`,
+  /// or as the `xml_schema::types` preserving it with the `date-time` feature.
+  #[cfg(not(feature = "date-time"))]
+  fn date_time_type(_item: &str) -> TokenStream {
+    quote!(String)
+  }
+
+  #[cfg(feature = "date-time")]
+  fn date_time_type(item: &str) -> TokenStream {
+    let rust_type = Ident::new(
+      &format!("Xs{}", item.to_upper_camel_case()),
+      Span::call_site(),
+    );
+    quote!(xml_schema::types::#rust_type)
+  }
+
+  /// `xs:gYear`, as `u16`, or as `xml_schema::types::XsGYear` with the `date-time` feature,
+  /// which keeps its timezone and supports negative and longer years.
+  #[cfg(not(feature = "date-time"))]
+  fn year_type() -> TokenStream {
+    quote!(u16)
+  }
+
+  #[cfg(feature = "date-time")]
+  fn year_type() -> TokenStream {
+    quote!(xml_schema::types::XsGYear)
+  }
+
+  /// `xs:decimal`, with its lexical form as `String`,
+  /// or as `xml_schema::types::XsDecimal` with the `decimal` feature.
+  #[cfg(not(feature = "decimal"))]
+  fn decimal_type() -> TokenStream {
+    quote!(String)
+  }
+
+  #[cfg(feature = "decimal")]
+  fn decimal_type() -> TokenStream {
+    quote!(xml_schema::types::XsDecimal)
+  }
+
+  /// `xs:integer`, as `i32`, or unbounded as `xml_schema::types::XsInteger` with the `big-integer` feature.
+  #[cfg(not(feature = "big-integer"))]
+  fn integer_type() -> TokenStream {
+    quote!(i32)
+  }
+
+  #[cfg(feature = "big-integer")]
+  fn integer_type() -> TokenStream {
+    quote!(xml_schema::types::XsInteger)
+  }
+
+  /// Integer types restricted by their sign, as the primitive type covering their range,
+  /// or as the range-checked `xml_schema::types` with the `integer-types` feature.
+  #[cfg(not(feature = "integer-types"))]
+  fn sign_restricted_integer_type(item: &str) -> TokenStream {
+    match item {
+      "positiveInteger" | "nonNegativeInteger" => quote!(u64),
+      _ => quote!(i64),
+    }
+  }
+
+  #[cfg(feature = "integer-types")]
+  fn sign_restricted_integer_type(item: &str) -> TokenStream {
+    let rust_type = Ident::new(
+      &format!("Xs{}", item.to_upper_camel_case()),
+      Span::call_site(),
+    );
+    quote!(xml_schema::types::#rust_type)
+  }
+
+  fn extern_type(context: &XsdContext, items: Vec<&str>) -> Result<TokenStream, Error> {
+    let struct_name = if items.last().unwrap().is_empty() {
+      "String".to_string()
+    } else {
+      (*items
+        .last()
+        .unwrap()
+        .replace('.', "_")
+        .to_upper_camel_case())
+      .to_string()
+    };
+
+    let default_module = context
+      .get_module("")
+      .map(|module| format!("{module}::"))
+      .unwrap_or_default();
+
+    let module = if items.len() == 2 {
+      let prefix = items.first().unwrap();
+      if let Some(module) = context.get_module(prefix) {
+        module + "::"
+      } else {
+        default_module
+      }
+    } else {
+      default_module
+    };
+
+    RustTypesMapping::type_path(module, &struct_name)
+  }
+
+  pub fn type_path(module: String, name: &str) -> Result<TokenStream, Error> {
+    let struct_name = format!("{module}{}", name.replace('.', "_").to_upper_camel_case());
+    let struct_name = parse_str::<TypePath>(&struct_name)
+      .map_err(|e| Error::new(format!("Invalid type name {struct_name:?}: {e}")))?;
+    Ok(quote!(#struct_name))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+
+  #[test]
+  fn rust_mapping_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(
+      RustTypesMapping::get(&context, "xs:boolean")
+        .unwrap()
+        .to_string()
+        == "bool"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:byte")
+        .unwrap()
+        .to_string()
+        == "i8"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:unsignedByte")
+        .unwrap()
+        .to_string()
+        == "u8"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:short")
+        .unwrap()
+        .to_string()
+        == "i16"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:unsignedShort")
+        .unwrap()
+        .to_string()
+        == "u16"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:int")
+        .unwrap()
+        .to_string()
+        == "i32"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:unsignedInt")
+        .unwrap()
+        .to_string()
+        == "u32"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:long")
+        .unwrap()
+        .to_string()
+        == "i64"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:unsignedLong")
+        .unwrap()
+        .to_string()
+        == "u64"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:float")
+        .unwrap()
+        .to_string()
+        == "f32"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:double")
+        .unwrap()
+        .to_string()
+        == "f64"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:string")
+        .unwrap()
+        .to_string()
+        == "String"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:string")
+        .unwrap()
+        .to_string()
+        == "String"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:ID")
+        .unwrap()
+        .to_string()
+        == "xml_schema :: types :: XsToken"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:IDREF")
+        .unwrap()
+        .to_string()
+        == "xml_schema :: types :: XsToken"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:normalizedString")
+        .unwrap()
+        .to_string()
+        == "xml_schema :: types :: XsNormalizedString"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:IDREFS")
+        .unwrap()
+        .to_string()
+        == "xml_schema :: types :: XsList < String >"
+    );
+    assert!(
+      RustTypesMapping::get(&context, "xs:anyType")
+        .unwrap()
+        .to_string()
+        == "String"
+    );
+
+    assert!(
+      RustTypesMapping::get(&context, "other:type")
+        .unwrap()
+        .to_string()
+        == "Type"
+    );
+
+    let context =
+      XsdContext::new(r#"<schema xmlns="http://www.w3.org/2001/XMLSchema"></schema>"#).unwrap();
+
+    assert!(
+      RustTypesMapping::get(&context, "boolean")
+        .unwrap()
+        .to_string()
+        == "bool"
+    );
+  }
+
+  #[test]
+  fn date_time_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let date_time = RustTypesMapping::get(&context, "xs:dateTime")
+      .unwrap()
+      .to_string();
+    let month_day = RustTypesMapping::get(&context, "xs:gMonthDay")
+      .unwrap()
+      .to_string();
+    let year = RustTypesMapping::get(&context, "xs:gYear")
+      .unwrap()
+      .to_string();
+
+    if cfg!(feature = "date-time") {
+      assert_eq!(date_time, "xml_schema :: types :: XsDateTime");
+      assert_eq!(month_day, "xml_schema :: types :: XsGMonthDay");
+      assert_eq!(year, "xml_schema :: types :: XsGYear");
+    } else {
+      assert_eq!(date_time, "String");
+      assert_eq!(month_day, "String");
+      assert_eq!(year, "u16");
+    }
+
+    assert!(RustTypesMapping::get(&context, "xs:date").is_ok());
+    assert!(RustTypesMapping::get(&context, "xs:time").is_ok());
+  }
+
+  #[test]
+  fn all_built_in_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    for item in [
+      "anySimpleType",
+      "string",
+      "boolean",
+      "decimal",
+      "float",
+      "double",
+      "duration",
+      "dateTime",
+      "time",
+      "date",
+      "gYearMonth",
+      "gYear",
+      "gMonthDay",
+      "gDay",
+      "gMonth",
+      "hexBinary",
+      "base64Binary",
+      "anyURI",
+      "QName",
+      "NOTATION",
+      "normalizedString",
+      "token",
+      "language",
+      "NMTOKEN",
+      "NMTOKENS",
+      "Name",
+      "NCName",
+      "ID",
+      "IDREF",
+      "IDREFS",
+      "ENTITY",
+      "ENTITIES",
+      "integer",
+      "nonPositiveInteger",
+      "negativeInteger",
+      "long",
+      "int",
+      "short",
+      "byte",
+      "nonNegativeInteger",
+      "unsignedLong",
+      "unsignedInt",
+      "unsignedShort",
+      "unsignedByte",
+      "positiveInteger",
+    ] {
+      assert!(
+        RustTypesMapping::get(&context, &format!("xs:{item}")).is_ok(),
+        "{}",
+        item
+      );
+    }
+
+    for item in ["NMTOKENS", "ENTITIES"] {
+      assert_eq!(
+        RustTypesMapping::get(&context, &format!("xs:{item}"))
+          .unwrap()
+          .to_string(),
+        "xml_schema :: types :: XsList < String >"
+      );
+    }
+    assert!(RustTypesMapping::is_xs_string(&context, "xs:NCName"));
+    assert!(RustTypesMapping::is_xs_string(&context, "xs:QName"));
+  }
+
+  #[test]
+  fn numeric_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let get = |kind: &str| RustTypesMapping::get(&context, kind).unwrap().to_string();
+
+    if cfg!(feature = "decimal") {
+      assert_eq!(get("xs:decimal"), "xml_schema :: types :: XsDecimal");
+    } else {
+      assert_eq!(get("xs:decimal"), "String");
+    }
+
+    if cfg!(feature = "big-integer") {
+      assert_eq!(get("xs:integer"), "xml_schema :: types :: XsInteger");
+    } else {
+      assert_eq!(get("xs:integer"), "i32");
+    }
+
+    if cfg!(feature = "integer-types") {
+      assert_eq!(
+        get("xs:positiveInteger"),
+        "xml_schema :: types :: XsPositiveInteger"
+      );
+      assert_eq!(
+        get("xs:nonNegativeInteger"),
+        "xml_schema :: types :: XsNonNegativeInteger"
+      );
+      assert_eq!(
+        get("xs:negativeInteger"),
+        "xml_schema :: types :: XsNegativeInteger"
+      );
+      assert_eq!(
+        get("xs:nonPositiveInteger"),
+        "xml_schema :: types :: XsNonPositiveInteger"
+      );
+    } else {
+      assert_eq!(get("xs:positiveInteger"), "u64");
+      assert_eq!(get("xs:nonNegativeInteger"), "u64");
+      assert_eq!(get("xs:negativeInteger"), "i64");
+      assert_eq!(get("xs:nonPositiveInteger"), "i64");
+    }
+  }
+
+  #[test]
+  fn rust_bad_mapping_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "xs:unknown").unwrap_err(),
+      Error::new("Type \"unknown\" not implemented")
+    );
+    assert_eq!(
+      RustTypesMapping::get(&context, "a:b:c").unwrap_err(),
+      Error::new("Unknown type \"a:b:c\"")
+    );
+  }
+
+  #[test]
+  fn extern_types() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns:example="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    let mut mapping = BTreeMap::new();
+    mapping.insert(
+      "http://example.com".to_string(),
+      "rust_example_module".to_string(),
+    );
+
+    let context = context.with_module_namespace_mappings(&mapping);
+    assert_eq!(
+      RustTypesMapping::get(&context, "example:MyType")
+        .unwrap()
+        .to_string(),
+      "rust_example_module :: MyType"
+    );
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "example:")
+        .unwrap()
+        .to_string(),
+      "rust_example_module :: String"
+    );
+  }
+
+  #[test]
+  fn extern_types_in_default_namespace() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    let mut mapping = BTreeMap::new();
+    mapping.insert(
+      "http://example.com".to_string(),
+      "rust_example_module".to_string(),
+    );
+
+    let context = context.with_module_namespace_mappings(&mapping);
+    assert_eq!(
+      RustTypesMapping::get(&context, "MyType")
+        .unwrap()
+        .to_string(),
+      "rust_example_module :: MyType"
+    );
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "").unwrap().to_string(),
+      "rust_example_module :: String"
+    );
+  }
+
+  #[test]
+  fn is_xs_string() {
+    let context = XsdContext::new(
+      r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns="http://example.com"
+        >
+      </xs:schema>
+    "#,
+    )
+    .unwrap();
+
+    assert!(RustTypesMapping::is_xs_string(&context, "xs:string"));
+    assert!(RustTypesMapping::is_xs_string(&context, "xs:token"));
+    assert!(!RustTypesMapping::is_xs_string(&context, "xs:int"));
+    assert!(!RustTypesMapping::is_xs_string(&context, "MyType"));
+
+    let context = XsdContext::new(
+      r#"
+      <schema
+        xmlns="http://www.w3.org/2001/XMLSchema"
+        xmlns:example="http://example.com"
+        >
+      </schema>
+    "#,
+    )
+    .unwrap();
+
+    assert!(RustTypesMapping::is_xs_string(&context, "string"));
+    assert!(!RustTypesMapping::is_xs_string(&context, "example:MyType"));
+  }
+
+  #[test]
+  fn is_xs_type() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(RustTypesMapping::is_xs_type(&context, "xs:boolean"));
+    assert!(!RustTypesMapping::is_xs_type(&context, "MyType"));
+    assert!(!RustTypesMapping::is_xs_type(&context, "example:MyType"));
+
+    let context =
+      XsdContext::new(r#"<schema xmlns="http://www.w3.org/2001/XMLSchema"></schema>"#).unwrap();
+
+    assert!(RustTypesMapping::is_xs_type(&context, "boolean"));
+  }
+}
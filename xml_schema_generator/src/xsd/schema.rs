@@ -0,0 +1,280 @@
+use crate::{
+  error::step,
+  xsd::{
+    attribute, attribute_group, complex_type, derived_types, element, group, import, include,
+    qualification, registry::ComponentKind, simple_type, substitution_group, Implementation,
+    XsdContext,
+  },
+  Error,
+};
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  root="schema"
+  prefix="xs",
+  namespace="xs: http://www.w3.org/2001/XMLSchema",
+)]
+pub struct Schema {
+  #[yaserde(rename = "targetNamespace", attribute)]
+  pub target_namespace: Option<String>,
+  #[yaserde(rename = "elementFormDefault", attribute)]
+  pub element_form_default: Option<qualification::Qualification>,
+  #[yaserde(rename = "attributeFormDefault", attribute)]
+  pub attribute_form_default: Option<qualification::Qualification>,
+  #[yaserde(rename = "include")]
+  pub includes: Vec<include::Include>,
+  #[yaserde(rename = "import")]
+  pub imports: Vec<import::Import>,
+  #[yaserde(rename = "element")]
+  pub elements: Vec<element::Element>,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Vec<simple_type::SimpleType>,
+  #[yaserde(rename = "complexType")]
+  pub complex_type: Vec<complex_type::ComplexType>,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<attribute::Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_group: Vec<attribute_group::AttributeGroup>,
+  #[yaserde(rename = "group")]
+  pub group: Vec<group::Group>,
+}
+
+impl Implementation for Schema {
+  fn implement(
+    &self,
+    _namespace_definition: &TokenStream,
+    target_prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let types = self.implement_types(target_prefix, context)?;
+    let elements = self.implement_elements(target_prefix, context)?;
+
+    Ok(quote!(
+      pub mod xml_schema_types {
+        #types
+      }
+
+      #elements
+    ))
+  }
+}
+
+impl Schema {
+  /// Top-level elements, generated next to the `xml_schema_types` module.
+  pub fn implement_elements(
+    &self,
+    target_prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let namespace_definition =
+      generate_namespace_definition(target_prefix, &self.target_namespace, context)?;
+
+    log::info!("Generate elements");
+    self
+      .elements
+      .iter()
+      .enumerate()
+      .map(|(index, element)| {
+        element
+          .implement(&namespace_definition, target_prefix, context)
+          .map_err(|e| e.within(step("element", Some(&element.name), index)))
+      })
+      .collect()
+  }
+
+  /// Simple types, complex types and groups, generated in the `xml_schema_types` module.
+  pub fn implement_types(
+    &self,
+    target_prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let namespace_definition =
+      generate_namespace_definition(target_prefix, &self.target_namespace, context)?;
+
+    let mut context = context.clone();
+    context.set_is_in_sub_module(true);
+
+    log::info!("Generate simple types");
+    let simple_types: TokenStream = self
+      .simple_type
+      .iter()
+      .enumerate()
+      .map(|(index, simple_type)| {
+        simple_type
+          .implement(&namespace_definition, target_prefix, &context)
+          .map_err(|e| e.within(step("simpleType", Some(&simple_type.name), index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    log::info!("Generate complex types");
+    let complex_types: TokenStream = self
+      .complex_type
+      .iter()
+      .enumerate()
+      .map(|(index, complex_type)| {
+        complex_type
+          .implement(&namespace_definition, target_prefix, &context)
+          .map_err(|e| e.within(step("complexType", Some(&complex_type.name), index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    log::info!("Generate groups");
+    let groups: TokenStream = self
+      .group
+      .iter()
+      .enumerate()
+      .map(|(index, group)| {
+        group
+          .implement(&namespace_definition, target_prefix, &context)
+          .map_err(|e| e.within(step("group", group.name.as_deref(), index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    log::info!("Generate derived types");
+    let derived_types: TokenStream = self
+      .complex_type
+      .iter()
+      .enumerate()
+      .map(|(index, complex_type)| {
+        derived_types::implement(&context, target_prefix, complex_type)
+          .map_err(|e| e.within(step("complexType", Some(&complex_type.name), index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    log::info!("Generate substitution groups");
+    let substitution_groups: TokenStream = self
+      .elements
+      .iter()
+      .enumerate()
+      .map(|(index, element)| {
+        element
+          .get_substitution_groups()
+          .try_for_each(|head| context.lookup(ComponentKind::Element, head).map(|_| ()))
+          .and_then(|_| substitution_group::implement(&context, target_prefix, element))
+          .map_err(|e| e.within(step("element", Some(&element.name), index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(quote!(
+      #simple_types
+      #complex_types
+      #derived_types
+      #groups
+      #substitution_groups
+    ))
+  }
+}
+
+/// Namespace of the generated structures, with the namespaces of the other modules whose
+/// elements can be referenced.
+fn generate_namespace_definition(
+  target_prefix: &Option<String>,
+  target_namespace: &Option<String>,
+  context: &XsdContext,
+) -> Result<TokenStream, Error> {
+  let module_namespaces: TokenStream = context
+    .get_module_prefixes()
+    .into_iter()
+    .filter(|(prefix, namespace)| {
+      Some(prefix) != target_prefix.as_ref() && Some(namespace) != target_namespace.as_ref()
+    })
+    .map(|(prefix, namespace)| {
+      let namespace = format!("{prefix}: {namespace}");
+      quote!(#[yaserde(namespace=#namespace)])
+    })
+    .collect();
+
+  let target_namespace_definition = match (target_prefix, target_namespace) {
+    (None, None) => quote!(),
+    (None, Some(_target_namespace)) => {
+      return Err(Error::new(
+        "undefined prefix attribute, a target namespace is defined",
+      ))
+    }
+    (Some(_prefix), None) => return Err(Error::new(
+      "a prefix attribute, but no target namespace is defined, please remove the prefix parameter",
+    )),
+    (Some(prefix), Some(target_namespace)) => {
+      let namespace = format!("{prefix}: {target_namespace}");
+      quote!(#[yaserde(prefix=#prefix, namespace=#namespace)])
+    }
+  };
+
+  Ok(quote!(
+    #target_namespace_definition
+    #module_namespaces
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_schema_implementation() {
+    let schema = Schema::default();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = format!(
+      "{}",
+      schema
+        .implement(&TokenStream::new(), &None, &context)
+        .unwrap()
+    );
+    assert_eq!(implementation, "pub mod xml_schema_types { }");
+  }
+
+  #[test]
+  fn missing_prefix() {
+    let schema = Schema {
+      target_namespace: Some("http://example.com".to_string()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(schema
+      .implement(&TokenStream::new(), &None, &context)
+      .is_err());
+  }
+
+  #[test]
+  fn missing_target_namespace() {
+    let schema = Schema::default();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(schema
+      .implement(&TokenStream::new(), &Some("ex".to_string()), &context)
+      .is_err());
+  }
+
+  #[test]
+  fn generate_namespace() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let definition = generate_namespace_definition(
+      &Some("prefix".to_string()),
+      &Some("http://example.com".to_string()),
+      &context,
+    )
+    .unwrap();
+
+    let implementation = format!("{definition}");
+
+    assert_eq!(
+      implementation,
+      r#"# [yaserde (prefix = "prefix" , namespace = "prefix: http://example.com")]"#
+    );
+  }
+}
@@ -0,0 +1,237 @@
+use crate::{
+  error::step,
+  xsd::{
+    choice::Choice,
+    element::Element,
+    particle::{get_indexed_particles, read_model_group, Particle},
+    wildcard::Wildcard,
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use log::info;
+use proc_macro2::{Ident, TokenStream};
+use std::io::Read;
+use yaserde::{de::Deserializer, YaDeserialize};
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Sequence {
+  pub particles: Vec<Particle>,
+}
+
+impl YaDeserialize for Sequence {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let (_attributes, particles) = read_model_group(reader)?;
+    Ok(Sequence { particles })
+  }
+}
+
+impl Implementation for Sequence {
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    info!("Generate elements");
+    self.get_field_implementation(context, prefix, struct_name)
+  }
+}
+
+impl Sequence {
+  pub fn get_sub_types_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    info!("Generate sub types implementation");
+    let choice_count = self.get_choices().count();
+
+    let sub_types: TokenStream = self
+      .get_supported_particles()?
+      .into_iter()
+      .map(|inlined| {
+        match inlined.particle {
+          Particle::Element(element) => {
+            element.get_subtypes_implementation(namespace_definition, prefix, context)
+          }
+          Particle::Choice(choice) => choice.get_sub_types_implementation(
+            context,
+            namespace_definition,
+            prefix,
+            struct_name,
+            (choice_count > 1).then_some(inlined.index),
+          ),
+          _ => Ok(quote!()),
+        }
+        .map_err(|e| inlined.locate(e))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let wildcard = self.get_wildcard_implementation(context, struct_name)?;
+
+    Ok(quote!(
+      #sub_types
+      #wildcard
+    ))
+  }
+
+  /// Constraints of the `xs:any` wildcard, generated next to the structure.
+  fn get_wildcard_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    self
+      .get_wildcard()?
+      .map(|wildcard| {
+        wildcard
+          .implement_any(context, struct_name)
+          .map(|(_field, constraints)| constraints)
+          .map_err(|e| e.within(step("any", None, 0)))
+      })
+      .transpose()
+      .map(Option::unwrap_or_default)
+  }
+
+  pub fn get_field_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    let choice_count = self.get_choices().count();
+
+    // fields are serialized in their order, which has to be the one of the schema
+    self
+      .get_supported_particles()?
+      .into_iter()
+      .map(|inlined| {
+        match inlined.particle {
+          Particle::Element(element) => element.get_field_implementation(context, prefix),
+          Particle::Choice(choice) => choice.get_field_implementation(
+            context,
+            prefix,
+            struct_name,
+            (choice_count > 1).then_some(inlined.index),
+          ),
+          // the elements of a referenced group are read in the element of the structure
+          Particle::Group(group) => group.get_field_implementation(context),
+          // elements matched by the wildcard are the ones not read by the other fields
+          Particle::Any(wildcard) => wildcard
+            .implement_any(context, struct_name)
+            .map(|(field, _constraints)| field),
+          Particle::Sequence(_) => Ok(quote!()),
+        }
+        .map_err(|e| inlined.locate(e))
+      })
+      .collect()
+  }
+
+  /// Elements of the sequence and of its nested sequences, with their position among the
+  /// elements.
+  pub fn get_elements(&self) -> impl Iterator<Item = (usize, &Element)> {
+    self
+      .get_inlined_particles()
+      .into_iter()
+      .filter_map(|inlined| match inlined.particle {
+        Particle::Element(element) => Some((inlined.index, element.as_ref())),
+        _ => None,
+      })
+  }
+
+  /// Choices of the sequence and of its nested sequences, with their position among the
+  /// choices.
+  pub fn get_choices(&self) -> impl Iterator<Item = (usize, &Choice)> {
+    self
+      .get_inlined_particles()
+      .into_iter()
+      .filter_map(|inlined| match inlined.particle {
+        Particle::Choice(choice) => Some((inlined.index, choice)),
+        _ => None,
+      })
+  }
+
+  /// Particles in document order, the particles of nested sequences being inlined in the
+  /// sequence.
+  fn get_supported_particles(&self) -> Result<Vec<InlinedParticle<'_>>, Error> {
+    self.get_wildcard()?;
+    Ok(self.get_inlined_particles())
+  }
+
+  fn get_inlined_particles(&self) -> Vec<InlinedParticle<'_>> {
+    let mut particles: Vec<InlinedParticle> = vec![];
+
+    for (index, particle) in get_indexed_particles(&self.particles) {
+      let inlined = match particle {
+        Particle::Sequence(sequence) => sequence
+          .get_inlined_particles()
+          .into_iter()
+          .map(|mut inlined| {
+            inlined.path.insert(0, step("sequence", None, index));
+            inlined
+          })
+          .collect(),
+        _ => vec![InlinedParticle {
+          path: vec![step(particle.get_kind(), None, index)],
+          index,
+          particle,
+        }],
+      };
+
+      for mut inlined in inlined {
+        inlined.index = particles
+          .iter()
+          .filter(|other| other.particle.get_kind() == inlined.particle.get_kind())
+          .count();
+        particles.push(inlined);
+      }
+    }
+
+    particles
+  }
+
+  fn get_wildcard(&self) -> Result<Option<&Wildcard>, Error> {
+    let mut wildcards =
+      self
+        .get_inlined_particles()
+        .into_iter()
+        .filter_map(|inlined| match inlined.particle {
+          Particle::Any(wildcard) => Some((inlined.path.clone(), wildcard)),
+          _ => None,
+        });
+
+    let wildcard = wildcards.next().map(|(_path, wildcard)| wildcard);
+    if let Some((path, _wildcard)) = wildcards.next() {
+      let error = Error::new("Only one xs:any wildcard is supported in a sequence");
+      return Err(locate(error, &path));
+    }
+
+    Ok(wildcard)
+  }
+}
+
+/// Particle of a sequence or of its nested sequences, with its path from the sequence to
+/// locate errors and its position among the inlined particles of the same kind.
+struct InlinedParticle<'a> {
+  path: Vec<String>,
+  index: usize,
+  particle: &'a Particle,
+}
+
+impl InlinedParticle<'_> {
+  fn locate(&self, error: Error) -> Error {
+    locate(error, &self.path)
+  }
+}
+
+/// Locate an error raised by a particle of the sequence.
+fn locate(error: Error, path: &[String]) -> Error {
+  path
+    .iter()
+    .rev()
+    .fold(error, |error, step| error.within(step.clone()))
+}
@@ -1,4 +1,7 @@
-use crate::xsd::{extension::Extension, Implementation, XsdContext};
+use crate::{
+  xsd::{extension::Extension, Implementation, XsdContext},
+  Error,
+};
 use proc_macro2::TokenStream;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
@@ -14,19 +17,10 @@ impl Implementation for SimpleContent {
     namespace_definition: &TokenStream,
     prefix: &Option<String>,
     context: &XsdContext,
-  ) -> TokenStream {
+  ) -> Result<TokenStream, Error> {
     self
       .extension
       .implement(namespace_definition, prefix, context)
-  }
-}
-
-impl SimpleContent {
-  pub fn get_field_implementation(
-    &self,
-    context: &XsdContext,
-    prefix: &Option<String>,
-  ) -> TokenStream {
-    self.extension.get_field_implementation(context, prefix)
+      .map_err(|e| e.within("extension"))
   }
 }
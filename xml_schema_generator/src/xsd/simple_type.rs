@@ -0,0 +1,270 @@
+use crate::{
+  xsd::{list::List, restriction::Restriction, union::Union, Implementation, XsdContext},
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct SimpleType {
+  #[yaserde(attribute)]
+  pub name: String,
+  pub restriction: Option<Restriction>,
+  pub list: Option<List>,
+  pub union: Option<Union>,
+}
+
+impl Implementation for SimpleType {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> Result<TokenStream, Error> {
+    let struct_name = Ident::new(&self.name.to_upper_camel_case(), Span::call_site());
+
+    if let Some(list) = &self.list {
+      return list
+        .implement_childs(namespace_definition, prefix, context, &struct_name)
+        .map_err(|e| e.within("list"));
+    }
+
+    if let Some(restriction) = &self.restriction {
+      if !restriction.enumerations.is_empty() || restriction.has_facets() {
+        return restriction
+          .implement_childs(namespace_definition, prefix, context, &struct_name)
+          .map_err(|e| e.within("restriction"));
+      }
+    }
+
+    if let Some(union) = &self.union {
+      return union
+        .implement_childs(namespace_definition, prefix, context, &struct_name)
+        .map_err(|e| e.within("union"));
+    }
+
+    // read from its text as the other simple types, e.g. as a member of a union
+    Ok(quote!(
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #namespace_definition
+      pub struct #struct_name {
+        #[yaserde(text)]
+        pub content: std::string::String,
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          Ok(#struct_name {
+            content: value.to_string(),
+          })
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          formatter.write_str(&self.content)
+        }
+      }
+    ))
+  }
+}
+
+impl SimpleType {
+  /// Rust type of an inline simple type: the base type of a restriction without facets,
+  /// or the type generated as `name` by `implement_inline`.
+  pub fn get_type_implementation(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+    name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    match &self.restriction {
+      Some(restriction) if self.is_plain_restriction() => restriction
+        .get_type_implementation(context, prefix)
+        .map_err(|e| e.within("restriction")),
+      _ if self.restriction.is_some() || self.list.is_some() || self.union.is_some() => {
+        Ok(quote!(#name))
+      }
+      _ => Err(Error::new(
+        "An inline simpleType requires a restriction, a list or a union",
+      )),
+    }
+  }
+
+  /// Type generated for an inline simple type, next to the structure using it.
+  pub fn implement_inline(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    if self.is_plain_restriction() {
+      return Ok(quote!());
+    }
+
+    let simple_type = SimpleType {
+      name: name.to_string(),
+      ..self.clone()
+    };
+
+    simple_type.implement(namespace_definition, prefix, context)
+  }
+
+  /// Check if the simple type is a restriction without enumerations nor facets,
+  /// its values are the ones of its base type.
+  pub fn is_plain_restriction(&self) -> bool {
+    self.list.is_none()
+      && self.union.is_none()
+      && self
+        .restriction
+        .as_ref()
+        .is_some_and(|restriction| restriction.enumerations.is_empty() && !restriction.has_facets())
+  }
+}
+
+/// Deserialize a simple type from its text content, using its `FromStr` implementation.
+pub fn implement_deserialize_from_str(struct_name: &Ident) -> TokenStream {
+  quote!(
+    impl yaserde::YaDeserialize for #struct_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        loop {
+          match reader.peek()?.to_owned() {
+            xml::reader::XmlEvent::StartElement{..} => {
+              reader.next_event()?;
+            }
+            xml::reader::XmlEvent::Characters(ref text_content) => {
+              reader.next_event()?;
+              return text_content.parse();
+            }
+            _ => {break;}
+          }
+        }
+
+        "".parse()
+      }
+    }
+  )
+}
+
+/// Serialize a simple type as text, using its `Display` implementation.
+/// The `prelude` can serialize the value beforehand and return.
+pub fn implement_serialize_display(struct_name: &Ident, prelude: TokenStream) -> TokenStream {
+  let struct_label = struct_name.to_string();
+
+  quote!(
+    impl yaserde::YaSerialize for #struct_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        #prelude
+
+        let skip_start_end = writer.skip_start_end();
+
+        if !skip_start_end {
+          let label = writer.get_start_event_name().unwrap_or_else(|| #struct_label.to_string());
+          writer.write(xml::writer::XmlEvent::start_element(label.as_str())).map_err(|e| e.to_string())?;
+        }
+
+        let content = self.to_string();
+        writer.write(xml::writer::XmlEvent::characters(&content)).map_err(|e| e.to_string())?;
+
+        if !skip_start_end {
+          writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  static DERIVES: &str =
+    "# [derive (Clone , Debug , Default , PartialEq , yaserde_derive :: YaDeserialize , yaserde_derive :: YaSerialize)] ";
+
+  #[test]
+  fn simple_type() {
+    let st = SimpleType {
+      name: "test".to_string(),
+      restriction: None,
+      list: None,
+      union: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = st.implement(&quote!(), &None, &context).unwrap();
+
+    let expected = TokenStream::from_str(&format!(
+      r#"{DERIVES}
+        pub struct Test {{
+          #[yaserde(text)]
+          pub content: std::string::String,
+        }}
+
+        impl std::str::FromStr for Test {{
+          type Err = String;
+
+          fn from_str(value: &str) -> Result<Self, Self::Err> {{
+            Ok(Test {{
+              content: value.to_string(),
+            }})
+          }}
+        }}
+
+        impl std::fmt::Display for Test {{
+          fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{
+            formatter.write_str(&self.content)
+          }}
+        }}"#,
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  // <!-- Whitespace-separated list of strings -->
+  // <xs:simpleType name="StringVectorType">
+  //   <xs:list itemType="xs:string"/>
+  // </xs:simpleType>
+
+  // <!-- Whitespace-separated list of unsigned integers -->
+  // <xs:simpleType name="UIntVectorType">
+  //   <xs:list itemType="xs:unsignedInt"/>
+  // </xs:simpleType>
+
+  // #[test]
+  // fn list_type() {
+  //   let st = SimpleType {
+  //     name: "string-list".to_string(),
+  //     restriction: None,
+  //     list: Some(List{
+  //       item_type: "xs:string".to_string()
+  //     }),
+  //     union: None,
+  //   };
+
+  //   let context = XsdContext {
+  //     xml_schema_prefix: Some("xs".to_string()),
+  //   };
+
+  //   let ts = st
+  //     .get_implementation(&quote!(), &None, &context)
+  //     .to_string();
+  //   println!("{}", ts);
+  //   assert!(ts == format!("{}pub struct StringList {{ # [ yaserde ( text ) ] pub content : String , }}", DERIVES));
+  // }
+}
@@ -0,0 +1,327 @@
+use crate::{
+  xsd::{element::Element, enumeration::get_variant_idents, registry::Substitute, XsdContext},
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use syn::Ident;
+
+/// Name of the enumeration of the substitution group of an element, or of its list if `multiple`.
+pub fn get_name(name: &str, multiple: bool) -> String {
+  let suffix = if multiple { "List" } else { "" };
+  format!(
+    "{}Substitution{suffix}",
+    name.replace('.', "_").to_upper_camel_case()
+  )
+}
+
+/// Enumeration of the concrete elements of the substitution group of a top-level element,
+/// and the list of its items for repeated references, both dispatched by element namespace and
+/// local name.
+/// Nothing is generated if no element substitutes the element.
+pub fn implement(
+  context: &XsdContext,
+  prefix: &Option<String>,
+  element: &Element,
+) -> Result<TokenStream, Error> {
+  let namespace = context.get_target_namespace().unwrap_or_default();
+  let substitutes = context.get_substitutes(namespace, &element.name);
+  if substitutes.is_empty() {
+    return Ok(quote!());
+  }
+
+  let enum_name = format_ident!("{}", get_name(&element.name, false));
+  let list_name = format_ident!("{}", get_name(&element.name, true));
+
+  let variants = get_variants(&substitutes, namespace);
+
+  let rust_types: Vec<TokenStream> = substitutes
+    .iter()
+    .map(|substitute| {
+      let name = &substitute.element.name;
+
+      if substitute.element.complex_type.is_some() || substitute.element.simple_type.is_some() {
+        return Err(Error::new(format!(
+          "Element {name:?} of the substitution group has an inline type, only named types are supported"
+        )));
+      }
+
+      substitute
+        .element
+        .get_type_implementation(&substitute.context)
+        .map_err(|e| {
+          Error::new(format!(
+            "Element {name:?} of the substitution group: {}",
+            e.message()
+          ))
+        })
+    })
+    .collect::<Result<_, _>>()?;
+
+  let match_variants = |action: &dyn Fn(TokenStream) -> TokenStream| -> TokenStream {
+    substitutes
+      .iter()
+      .zip(variants.iter())
+      .zip(rust_types.iter())
+      .map(|((substitute, variant), rust_type)| {
+        let element_namespace = &substitute.namespace;
+        let element_name = &substitute.element.name;
+        let read_value = substitute
+          .element
+          .implement_read_value(&substitute.context, rust_type);
+        let action = action(quote!(#enum_name::#variant(value)));
+
+        quote!(
+          (#element_namespace, #element_name) => {
+            #read_value
+            #action
+          }
+        )
+      })
+      .collect()
+  };
+
+  let single_variants = match_variants(&|value| quote!(return Ok(#value);));
+  let list_variants = match_variants(&|value| quote!(items.push(#value);));
+
+  // elements of other namespaces are written with the prefix bound in their schema
+  let serialize_variants: TokenStream = substitutes
+    .iter()
+    .zip(variants.iter())
+    .map(|(substitute, variant)| {
+      let prefix = if substitute.namespace == namespace {
+        prefix
+      } else {
+        &substitute.prefix
+      };
+      let serialize_value = substitute
+        .element
+        .implement_write_value(&substitute.context, prefix);
+
+      quote!(
+        #enum_name::#variant(value) => {
+          #serialize_value
+        }
+      )
+    })
+    .collect();
+
+  // the first element with a default value, elements of restrictions with facets have none
+  let mut default_variant = None;
+  for (substitute, variant) in substitutes.iter().zip(variants.iter()) {
+    if substitute.element.has_default(&substitute.context)? {
+      default_variant = Some(variant);
+      break;
+    }
+  }
+  let default_implementation = default_variant
+    .map(|variant| {
+      quote!(
+        impl Default for #enum_name {
+          fn default() -> Self {
+            #enum_name::#variant(Default::default())
+          }
+        }
+      )
+    })
+    .unwrap_or_default();
+
+  let single_deserialize = implement_deserialize(
+    single_variants,
+    quote!(Err(format!(
+      "No element of {} found",
+      stringify!(#enum_name)
+    ))),
+  );
+  let list_deserialize = implement_deserialize(list_variants, quote!(Ok(#list_name { items })));
+
+  Ok(quote!(
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum #enum_name {
+      #(#variants(#rust_types),)*
+    }
+
+    #default_implementation
+
+    impl yaserde::YaDeserialize for #enum_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        #single_deserialize
+      }
+    }
+
+    impl yaserde::YaSerialize for #enum_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        match self {
+          #serialize_variants
+        }
+
+        Ok(())
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct #list_name {
+      pub items: Vec<#enum_name>,
+    }
+
+    impl yaserde::YaDeserialize for #list_name {
+      fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+        let mut items = vec![];
+        #list_deserialize
+      }
+    }
+
+    impl yaserde::YaSerialize for #list_name {
+      fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+        for item in &self.items {
+          yaserde::YaSerialize::serialize(item, writer)?;
+        }
+
+        Ok(())
+      }
+
+      fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+        Ok((source_attributes, source_namespace))
+      }
+    }
+  ))
+}
+
+/// Variant names of the substitutes. Elements of different namespaces can share a local name:
+/// the ones outside of the namespace of the head get the prefix of their namespace as a suffix,
+/// e.g. `CircleSketch` for `sketch:circle`.
+fn get_variants(substitutes: &[Substitute], namespace: &str) -> Vec<Ident> {
+  let names: Vec<String> = substitutes
+    .iter()
+    .map(|substitute| {
+      let name = &substitute.element.name;
+      let collides = substitutes
+        .iter()
+        .any(|other| other.namespace != substitute.namespace && &other.element.name == name);
+
+      match &substitute.prefix {
+        Some(prefix) if collides && substitute.namespace != namespace => {
+          format!("{name}_{prefix}")
+        }
+        _ => name.to_owned(),
+      }
+    })
+    .collect();
+
+  get_variant_idents(&names)
+}
+
+/// References to a substitution group are flattened into their parent structure: the reader
+/// receives the parent element with all child elements not consumed by the other fields.
+fn implement_deserialize(match_variants: TokenStream, result: TokenStream) -> TokenStream {
+  quote!(
+    let start_depth = reader.depth();
+
+    loop {
+      match reader.peek()?.to_owned() {
+        xml::reader::XmlEvent::StartElement { ref name, .. } => {
+          if reader.depth() == start_depth {
+            reader.next_event()?;
+            continue;
+          }
+
+          match (
+            name.namespace.as_deref().unwrap_or_default(),
+            name.local_name.as_str(),
+          ) {
+            #match_variants
+            _ => {
+              reader.next_event()?;
+              reader.skip_element(|_event| {})?;
+            }
+          }
+        }
+        xml::reader::XmlEvent::EndElement { .. } | xml::reader::XmlEvent::EndDocument => {
+          break;
+        }
+        _ => {
+          reader.next_event()?;
+        }
+      }
+    }
+
+    #result
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::{registry::Registry, schema::Schema};
+  use std::rc::Rc;
+
+  static SCHEMA: &str = r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:gml="http://example.com/gml"
+      targetNamespace="http://example.com/gml"
+      >
+      <xs:element name="_Geometry" type="gml:GeometryType" abstract="true"/>
+      <xs:element name="_Curve" substitutionGroup="gml:_Geometry" abstract="true"/>
+      <xs:element name="Point" type="gml:PointType" substitutionGroup="gml:_Geometry"/>
+      <xs:element name="LineString" substitutionGroup="gml:_Curve"/>
+      <xs:element name="Label" type="xs:string"/>
+      <xs:complexType name="GeometryType"/>
+      <xs:complexType name="PointType"/>
+    </xs:schema>
+  "#;
+
+  fn context() -> XsdContext {
+    let context = XsdContext::new(SCHEMA)
+      .unwrap()
+      .with_target_namespace(&Some("http://example.com/gml".to_string()));
+
+    let mut registry = Registry::default();
+    registry
+      .register(&yaserde::de::from_str::<Schema>(SCHEMA).unwrap(), &context)
+      .unwrap();
+
+    let mut context = context.with_registry(&Rc::new(registry));
+    context.set_is_in_sub_module(true);
+    context
+  }
+
+  #[test]
+  fn substitution_group() {
+    let context = context();
+    let element = Element {
+      name: "_Geometry".to_string(),
+      ..Default::default()
+    };
+
+    let implementation = implement(&context, &Some("gml".to_string()), &element)
+      .unwrap()
+      .to_string();
+
+    let expected = quote!(
+      pub enum GeometrySubstitution {
+        Point(PointType),
+        LineString(GeometryType),
+      }
+    );
+    assert!(implementation.contains(&expected.to_string()));
+
+    assert!(implementation.contains(&quote!(pub struct GeometrySubstitutionList).to_string()));
+    assert!(implementation.contains(r#"("http://example.com/gml" , "LineString") =>"#));
+    assert!(implementation.contains(r#"Some ("gml:Point" . to_string ())"#));
+  }
+
+  #[test]
+  fn no_substitution_group() {
+    let element = Element {
+      name: "Label".to_string(),
+      ..Default::default()
+    };
+
+    assert!(implement(&context(), &None, &element).unwrap().is_empty());
+  }
+}
@@ -0,0 +1,165 @@
+use crate::Error;
+use proc_macro2::TokenStream;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{parse_str, Type};
+
+/// Rust types replacing the generated or built-in ones.
+///
+/// A key is either the qualified name of a type, e.g. `xs:anyURI`, `ns:Money` or
+/// `{http://www.w3.org/2001/XMLSchema}anyURI`, or the path of an element or attribute
+/// from a top-level component, e.g. `Book/isbn` or `Book/@lang`.
+#[derive(Clone, Debug, Default)]
+pub struct TypeMappings {
+  types: Vec<(String, TokenStream)>,
+  paths: BTreeMap<String, TokenStream>,
+  /// Keys matched while generating, the other ones are reported.
+  used: RefCell<BTreeSet<String>>,
+}
+
+impl TypeMappings {
+  pub fn new(mappings: &BTreeMap<String, String>) -> Result<Self, Error> {
+    let mut type_mappings = TypeMappings::default();
+
+    for (key, rust_type) in mappings {
+      let parsed_type = parse_str::<Type>(rust_type).map_err(|e| {
+        Error::new(format!(
+          "Invalid Rust type {rust_type:?} in the type mapping of {key:?}: {e}"
+        ))
+      })?;
+
+      if key.contains('/') && !key.starts_with('{') {
+        type_mappings
+          .paths
+          .insert(key.to_string(), quote!(#parsed_type));
+      } else {
+        if key.starts_with('{') && !key.contains('}') {
+          return Err(Error::new(format!(
+            "Invalid qualified name {key:?} in type mappings"
+          )));
+        }
+        type_mappings
+          .types
+          .push((key.to_string(), quote!(#parsed_type)));
+      }
+    }
+
+    Ok(type_mappings)
+  }
+
+  /// Rust type of a type, `resolve` giving the namespace and local name of a qualified name.
+  /// Keys with a prefix which can not be resolved match no type.
+  pub fn get_type<F>(&self, kind: &str, resolve: F) -> Option<TokenStream>
+  where
+    F: Fn(&str) -> Option<(String, String)>,
+  {
+    let resolved_kind = resolve(kind)?;
+
+    let (key, rust_type) = self.types.iter().find(|(key, _rust_type)| {
+      let resolved_key = match key.strip_prefix('{').and_then(|key| key.split_once('}')) {
+        Some((namespace, local_name)) => Some((namespace.to_string(), local_name.to_string())),
+        None => resolve(key),
+      };
+      resolved_key.as_ref() == Some(&resolved_kind)
+    })?;
+
+    self.used.borrow_mut().insert(key.clone());
+    Some(rust_type.clone())
+  }
+
+  /// Rust type of the element or attribute at this path.
+  pub fn get_path(&self, path: &str) -> Option<TokenStream> {
+    let rust_type = self.paths.get(path)?;
+
+    self.used.borrow_mut().insert(path.to_string());
+    Some(rust_type.clone())
+  }
+
+  /// Keys which matched no type, element or attribute so far, e.g. misspelled ones.
+  pub fn get_unused_keys(&self) -> BTreeSet<String> {
+    let used = self.used.borrow();
+
+    self
+      .types
+      .iter()
+      .map(|(key, _rust_type)| key)
+      .chain(self.paths.keys())
+      .filter(|key| !used.contains(*key))
+      .cloned()
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn resolve(reference: &str) -> Option<(String, String)> {
+    match reference.split_once(':') {
+      Some(("xs", local_name)) => Some((
+        "http://www.w3.org/2001/XMLSchema".to_string(),
+        local_name.to_string(),
+      )),
+      Some(("ns", local_name)) => Some(("http://example.com".to_string(), local_name.to_string())),
+      Some(_) => None,
+      None => Some((String::new(), reference.to_string())),
+    }
+  }
+
+  #[test]
+  fn type_mappings() {
+    let mappings = TypeMappings::new(&BTreeMap::from([
+      ("xs:anyURI".to_string(), "crate::Uri".to_string()),
+      (
+        "{http://example.com}Money".to_string(),
+        "crate::money::Money".to_string(),
+      ),
+      ("other:Thing".to_string(), "crate::Thing".to_string()),
+      ("Book/@lang".to_string(), "crate::Language".to_string()),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+      mappings.get_type("xs:anyURI", resolve).unwrap().to_string(),
+      "crate :: Uri"
+    );
+    assert_eq!(
+      mappings.get_type("ns:Money", resolve).unwrap().to_string(),
+      "crate :: money :: Money"
+    );
+    assert!(mappings.get_type("xs:string", resolve).is_none());
+    assert!(mappings.get_type("other:Thing", resolve).is_none());
+    assert_eq!(
+      mappings.get_path("Book/@lang").unwrap().to_string(),
+      "crate :: Language"
+    );
+    assert!(mappings.get_path("Book/lang").is_none());
+
+    assert_eq!(
+      mappings.get_unused_keys(),
+      BTreeSet::from(["other:Thing".to_string()])
+    );
+  }
+
+  #[test]
+  fn invalid_type_mappings() {
+    let error = TypeMappings::new(&BTreeMap::from([(
+      "xs:anyURI".to_string(),
+      "url::".to_string(),
+    )]))
+    .unwrap_err();
+    assert!(error
+      .to_string()
+      .starts_with("Invalid Rust type \"url::\" in the type mapping of \"xs:anyURI\""));
+
+    let error = TypeMappings::new(&BTreeMap::from([(
+      "{http://example.com".to_string(),
+      "crate::Money".to_string(),
+    )]))
+    .unwrap_err();
+    assert_eq!(
+      error,
+      Error::new("Invalid qualified name \"{http://example.com\" in type mappings")
+    );
+  }
+}
@@ -0,0 +1,255 @@
+use crate::{
+  error::step,
+  xsd::{
+    enumeration::get_variant_idents,
+    rust_types_mapping::RustTypesMapping,
+    simple_type::{implement_deserialize_from_str, implement_serialize_display, SimpleType},
+    value_constraint::{has_default, FieldType},
+    Implementation, XsdContext,
+  },
+  Error,
+};
+use proc_macro2::{Ident, TokenStream};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Union {
+  #[yaserde(rename = "memberTypes", attribute)]
+  pub member_types: String,
+  #[yaserde(rename = "simpleType")]
+  pub simple_types: Vec<SimpleType>,
+}
+
+/// Member type of a union, read with its `FromStr` implementation and written with its `Display`.
+struct Member {
+  variant: Ident,
+  rust_type: TokenStream,
+  has_default: bool,
+}
+
+impl Implementation for Union {
+  fn implement_childs(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<TokenStream, Error> {
+    let members = self.get_members(context, struct_name)?;
+    if members.is_empty() {
+      return Err(Error::new(format!(
+        "No member type for union {struct_name}"
+      )));
+    }
+
+    let definitions: TokenStream = members
+      .iter()
+      .map(
+        |Member {
+           variant, rust_type, ..
+         }| quote!(#variant(#rust_type),),
+      )
+      .collect();
+
+    let parse_members: TokenStream = members
+      .iter()
+      .map(
+        |Member {
+           variant, rust_type, ..
+         }| {
+          quote!(
+            if let Ok(member) = value.parse::<#rust_type>() {
+              return Ok(#struct_name::#variant(member));
+            }
+          )
+        },
+      )
+      .collect();
+
+    let display_members: TokenStream = members
+      .iter()
+      .map(|Member { variant, .. }| {
+        quote!(
+          #struct_name::#variant(member) => write!(formatter, "{}", member),
+        )
+      })
+      .collect();
+
+    // the first member with a default value, none for restrictions with facets
+    let default_implementation = members
+      .iter()
+      .find(|member| member.has_default)
+      .map(|Member { variant, .. }| {
+        quote!(
+          impl Default for #struct_name {
+            fn default() -> Self {
+              #struct_name::#variant(Default::default())
+            }
+          }
+        )
+      })
+      .unwrap_or_default();
+    let struct_label = struct_name.to_string();
+    let deserialize_implementation = implement_deserialize_from_str(struct_name);
+    let serialize_implementation = implement_serialize_display(struct_name, quote!());
+
+    let inline_types: TokenStream = self
+      .simple_types
+      .iter()
+      .enumerate()
+      .map(|(index, simple_type)| {
+        let simple_type = SimpleType {
+          name: Self::get_inline_type_name(struct_name, index).to_string(),
+          ..simple_type.clone()
+        };
+        simple_type
+          .implement(namespace_definition, prefix, context)
+          .map_err(|e| e.within(step("simpleType", None, index)))
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      pub enum #struct_name {
+        #definitions
+      }
+
+      #default_implementation
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #parse_members
+          Err(format!("{:?} does not match any member type of {}", value, #struct_label))
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          match self {
+            #display_members
+          }
+        }
+      }
+
+      #deserialize_implementation
+
+      #serialize_implementation
+
+      #inline_types
+    ))
+  }
+}
+
+impl Union {
+  /// Members in declaration order: `memberTypes` first, then anonymous `simpleType` children.
+  fn get_members(&self, context: &XsdContext, struct_name: &Ident) -> Result<Vec<Member>, Error> {
+    let mut members: Vec<(String, TokenStream, bool)> = self
+      .member_types
+      .split_whitespace()
+      .map(|member_type| {
+        let local_name = member_type.split(':').next_back().unwrap_or_default();
+        Ok((
+          local_name.to_string(),
+          RustTypesMapping::get(context, member_type)?,
+          has_default(context, FieldType::Named(member_type))?,
+        ))
+      })
+      .collect::<Result<_, Error>>()?;
+
+    for (index, simple_type) in self.simple_types.iter().enumerate() {
+      let inline_type = Self::get_inline_type_name(struct_name, index);
+      members.push((
+        format!("Member{}", index + 1),
+        quote!(#inline_type),
+        // generated as a structure with its text `content` without facets
+        simple_type.is_plain_restriction() || has_default(context, FieldType::Simple(simple_type))?,
+      ));
+    }
+
+    let names: Vec<&str> = members.iter().map(|(name, _, _)| name.as_str()).collect();
+
+    Ok(
+      get_variant_idents(&names)
+        .into_iter()
+        .zip(members)
+        .map(|(variant, (_, rust_type, has_default))| Member {
+          variant,
+          rust_type,
+          has_default,
+        })
+        .collect(),
+    )
+  }
+
+  fn get_inline_type_name(struct_name: &Ident, index: usize) -> Ident {
+    format_ident!("{}Member{}", struct_name, index + 1)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proc_macro2::Span;
+  use std::str::FromStr;
+
+  #[test]
+  fn union_members() {
+    let context = XsdContext::new(
+      r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:tns="http://example.com"></xs:schema>"#,
+    )
+    .unwrap();
+
+    let union = Union {
+      member_types: "xs:int tns:size-keyword".to_string(),
+      simple_types: vec![SimpleType::default()],
+    };
+
+    let struct_name = Ident::new("Size", Span::call_site());
+    let implementation = union
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Size {
+          Int(i32),
+          SizeKeyword(SizeKeyword),
+          Member1(SizeMember1),
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert!(implementation
+      .to_string()
+      .starts_with(&expected.to_string()));
+
+    let expected = TokenStream::from_str(
+      r#"
+        if let Ok(member) = value.parse:: <i32>() {
+          return Ok(Size::Int(member));
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert!(implementation.to_string().contains(&expected.to_string()));
+  }
+
+  #[test]
+  fn union_without_member() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Size", Span::call_site());
+    let error = Union::default()
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .unwrap_err();
+
+    assert_eq!(error, Error::new("No member type for union Size"));
+  }
+}
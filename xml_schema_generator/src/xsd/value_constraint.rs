@@ -0,0 +1,1025 @@
+use crate::{
+  xsd::{
+    attribute::Attribute,
+    complex_type::ComplexType,
+    facet::{is_valid_value, BoundOrder},
+    lexical::is_valid_built_in,
+    restriction::Restriction,
+    rust_types_mapping::RustTypesMapping,
+    simple_type::{implement_deserialize_from_str, implement_serialize_display, SimpleType},
+    XsdContext,
+  },
+  Error,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Span, TokenStream};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+use syn::{parse2, FieldsNamed, Ident};
+
+/// `default` or `fixed` value of an element or an attribute.
+/// The field is not optional: it is filled in with the value when absent from the document.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueConstraint {
+  Default(String),
+  Fixed(String),
+}
+
+impl ValueConstraint {
+  pub fn new(default: &Option<String>, fixed: &Option<String>) -> Result<Option<Self>, Error> {
+    match (default, fixed) {
+      (None, None) => Ok(None),
+      (Some(default), None) => Ok(Some(ValueConstraint::Default(default.clone()))),
+      (None, Some(fixed)) => Ok(Some(ValueConstraint::Fixed(fixed.clone()))),
+      (Some(_), Some(_)) => Err(Error::new("default and fixed cannot be both present")),
+    }
+  }
+
+  fn value(&self) -> &str {
+    match self {
+      ValueConstraint::Default(value) | ValueConstraint::Fixed(value) => value,
+    }
+  }
+}
+
+/// Type of a field, the value constraint of the field is checked against it when generating.
+#[derive(Clone, Copy, Debug)]
+pub enum FieldType<'a> {
+  /// Type referenced by its qualified name.
+  Named(&'a str),
+  /// Inline simple type of the field.
+  Simple(&'a SimpleType),
+  /// Inline complex type of an element.
+  Complex(&'a ComplexType),
+  /// Type of a top-level attribute, referenced by its qualified name.
+  Attribute(&'a str),
+  /// Rust type of a type mapping, its values are read with its `FromStr` implementation.
+  Mapped,
+}
+
+/// Fields of a structure with a value constraint, registered when its fields are implemented,
+/// shared by the contexts of these fields.
+#[derive(Clone, Debug)]
+pub struct ValueConstraints {
+  struct_name: Ident,
+  fields: Rc<RefCell<Vec<ConstrainedField>>>,
+  required_fields: Rc<RefCell<Vec<RequiredField>>>,
+}
+
+/// Required field generated as an `Option`, its type having no `Default` value to start
+/// deserializing from. The structure is rejected when it is missing.
+#[derive(Clone, Debug)]
+pub struct RequiredField {
+  pub name: Ident,
+  /// Description of the missing content, e.g. `element "amount"`.
+  pub description: String,
+}
+
+#[derive(Debug)]
+struct ConstrainedField {
+  name: Ident,
+  /// Function initializing a field with a default value.
+  default_function: Option<Ident>,
+  implementation: TokenStream,
+}
+
+/// How the value of a field is built from its lexical form.
+enum Value {
+  String,
+  Bool(bool),
+  /// Primitive number, as a literal.
+  Number(TokenStream),
+  /// Type implementing `FromStr`, like the built-in types of `xml_schema::types`
+  /// and the generated simple types.
+  Parsed,
+  /// Simple type without facets, generated as a structure with its text `content`.
+  Content,
+  /// Complex type, or type defined outside of the loaded schemas,
+  /// deserialized from an element with the value as content.
+  Deserialized,
+}
+
+impl ValueConstraints {
+  pub fn new(struct_name: &Ident) -> Self {
+    ValueConstraints {
+      struct_name: struct_name.clone(),
+      fields: Rc::default(),
+      required_fields: Rc::default(),
+    }
+  }
+
+  /// Register a required field generated as an `Option`, checked after deserializing.
+  pub fn add_required_field(&self, field_name: &Ident, description: String) {
+    let mut required_fields = self.required_fields.borrow_mut();
+    if required_fields
+      .iter()
+      .all(|registered| &registered.name != field_name)
+    {
+      required_fields.push(RequiredField {
+        name: field_name.clone(),
+        description,
+      });
+    }
+  }
+
+  /// Registered required fields which are part of the `fields` of the structure.
+  pub fn get_required_fields(&self, fields: &TokenStream) -> Result<Vec<RequiredField>, Error> {
+    let field_names = get_field_names(&self.struct_name, fields)?;
+
+    Ok(
+      self
+        .required_fields
+        .borrow()
+        .iter()
+        .filter(|field| field_names.contains(&field.name))
+        .cloned()
+        .collect(),
+    )
+  }
+
+  /// Check the value constraint of a field against its type, and register the implementations
+  /// it requires. Returns the Rust type of the field and the `yaserde` attribute of its default
+  /// value:
+  /// - a field with a default value is initialized by a function named after the structure and
+  ///   the field, used by the `Default` implementation of the structure and when deserializing;
+  /// - a field with a fixed value gets a unit type, checking the value when deserializing
+  ///   and always serializing it.
+  pub fn add_field(
+    &self,
+    context: &XsdContext,
+    field_name: &Ident,
+    field_type: FieldType,
+    rust_type: &TokenStream,
+    constraint: &ValueConstraint,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    let value = constraint.value();
+    let built_value = get_value(context, field_type, rust_type, value)?.ok_or_else(|| {
+      Error::new(format!(
+        "Invalid value {value:?} for type {}",
+        rust_type.to_string().replace(' ', "")
+      ))
+    })?;
+
+    let (field, rust_type, default_attribute) = match constraint {
+      ValueConstraint::Default(_) => {
+        let function_name = Ident::new(
+          &format!(
+            "default_{}_{field_name}",
+            self.struct_name.to_string().to_snake_case()
+          ),
+          Span::call_site(),
+        );
+        let function_label = function_name.to_string();
+        let default_value = implement_value(rust_type, value, &built_value);
+
+        let field = ConstrainedField {
+          name: field_name.clone(),
+          default_function: Some(function_name.clone()),
+          implementation: quote!(
+            fn #function_name() -> #rust_type {
+              #default_value
+            }
+          ),
+        };
+
+        (
+          field,
+          rust_type.clone(),
+          quote!(, default = #function_label),
+        )
+      }
+      ValueConstraint::Fixed(_) => {
+        let fixed_name = Ident::new(
+          &format!(
+            "{}{}",
+            self.struct_name,
+            field_name.to_string().to_upper_camel_case()
+          ),
+          Span::call_site(),
+        );
+
+        let field = ConstrainedField {
+          name: field_name.clone(),
+          default_function: None,
+          implementation: implement_fixed_type(&fixed_name, rust_type, value, &built_value),
+        };
+
+        (field, quote!(#fixed_name), quote!())
+      }
+    };
+
+    let mut fields = self.fields.borrow_mut();
+    if fields
+      .iter()
+      .all(|registered| registered.name != field.name)
+    {
+      fields.push(field);
+    }
+
+    Ok((rust_type, default_attribute))
+  }
+
+  /// Implementations required by the registered fields which are part of the `fields`
+  /// of the structure, and the `Default` derive when no field has a default value.
+  /// Otherwise `Default` is implemented with the default values.
+  pub fn implement(&self, fields: &TokenStream) -> Result<(TokenStream, TokenStream), Error> {
+    let constrained_fields = self.fields.borrow();
+    if constrained_fields.is_empty() {
+      return Ok((quote!(Default,), quote!()));
+    }
+
+    let struct_name = &self.struct_name;
+    let field_names = get_field_names(struct_name, fields)?;
+
+    let constrained_fields: Vec<&ConstrainedField> = constrained_fields
+      .iter()
+      .filter(|field| field_names.contains(&field.name))
+      .collect();
+
+    let implementations = constrained_fields.iter().map(|field| &field.implementation);
+
+    if constrained_fields
+      .iter()
+      .all(|field| field.default_function.is_none())
+    {
+      return Ok((quote!(Default,), quote!(#(#implementations)*)));
+    }
+
+    let default_fields = field_names.iter().map(|field_name| {
+      let default_function = constrained_fields
+        .iter()
+        .find(|field| &field.name == field_name)
+        .and_then(|field| field.default_function.as_ref());
+
+      match default_function {
+        Some(function_name) => quote!(#field_name: #function_name(),),
+        None => quote!(#field_name: Default::default(),),
+      }
+    });
+
+    Ok((
+      quote!(),
+      quote!(
+        #(#implementations)*
+
+        impl Default for #struct_name {
+          fn default() -> Self {
+            #struct_name {
+              #(#default_fields)*
+            }
+          }
+        }
+      ),
+    ))
+  }
+}
+
+/// Names of the fields of a structure.
+fn get_field_names(struct_name: &Ident, fields: &TokenStream) -> Result<Vec<Ident>, Error> {
+  let fields_named = parse2::<FieldsNamed>(quote!({ #fields }))
+    .map_err(|e| Error::new(format!("Invalid fields for {struct_name}: {e}")))?;
+
+  Ok(
+    fields_named
+      .named
+      .into_iter()
+      .filter_map(|field| field.ident)
+      .collect(),
+  )
+}
+
+/// Check a value against the type of its field, returning how the field is built from it,
+/// `None` if the value is not valid.
+fn get_value(
+  context: &XsdContext,
+  field_type: FieldType,
+  rust_type: &TokenStream,
+  value: &str,
+) -> Result<Option<Value>, Error> {
+  if !is_valid(context, field_type, value, &mut vec![])? {
+    return Ok(None);
+  }
+
+  let built_value = match rust_type.to_string().as_str() {
+    "String" => Value::String,
+    "bool" => Value::Bool(matches!(value.trim(), "true" | "1")),
+    primitive if RustTypesMapping::is_number(primitive) => {
+      match RustTypesMapping::get_number_literal(primitive, value)
+        .and_then(|literal| TokenStream::from_str(&literal).ok())
+      {
+        Some(literal) => Value::Number(literal),
+        None => return Ok(None),
+      }
+    }
+    _ => get_construction(context, field_type)?,
+  };
+
+  Ok(Some(built_value))
+}
+
+/// How a field of a type which is not a primitive type is built from its value.
+fn get_construction(context: &XsdContext, field_type: FieldType) -> Result<Value, Error> {
+  match field_type {
+    FieldType::Named(kind) => {
+      if RustTypesMapping::is_xs_type(context, kind) || context.get_type_mapping(kind).is_some() {
+        return Ok(Value::Parsed);
+      }
+
+      match context.get_simple_type(kind)? {
+        Some((simple_type, _)) if is_content_type(&simple_type) => Ok(Value::Content),
+        Some(_) => Ok(Value::Parsed),
+        None => Ok(Value::Deserialized),
+      }
+    }
+    FieldType::Simple(simple_type) => match &simple_type.restriction {
+      // a restriction without facets has the type of its base
+      Some(Restriction {
+        base: Some(base), ..
+      }) if simple_type.is_plain_restriction() => get_construction(context, FieldType::Named(base)),
+      _ => Ok(Value::Parsed),
+    },
+    FieldType::Complex(_) => Ok(Value::Deserialized),
+    FieldType::Attribute(reference) => match context.get_attribute(reference)? {
+      Some((attribute, definition_context)) => {
+        get_construction(&definition_context, get_attribute_type(&attribute)?)
+      }
+      None => Ok(Value::Deserialized),
+    },
+    FieldType::Mapped => Ok(Value::Parsed),
+  }
+}
+
+/// Check if a top-level simple type is generated as a structure with its text `content`.
+fn is_content_type(simple_type: &SimpleType) -> bool {
+  simple_type.list.is_none()
+    && simple_type.union.is_none()
+    && simple_type
+      .restriction
+      .as_ref()
+      .is_none_or(|restriction| restriction.enumerations.is_empty() && !restriction.has_facets())
+}
+
+fn get_attribute_type(attribute: &Attribute) -> Result<FieldType<'_>, Error> {
+  match (
+    &attribute.kind,
+    &attribute.reference,
+    &attribute.simple_type,
+  ) {
+    (Some(kind), None, None) => Ok(FieldType::Named(kind)),
+    (None, Some(reference), None) => Ok(FieldType::Attribute(reference)),
+    (None, None, Some(simple_type)) => Ok(FieldType::Simple(simple_type)),
+    _ => Err(Error::new(format!(
+      "Attribute {:?} requires exactly one of type, ref or simpleType",
+      attribute.name.as_deref().unwrap_or_default()
+    ))),
+  }
+}
+
+/// Check a value against a type. Types defined outside of the loaded schemas and mapped types
+/// can not be checked, their values are accepted. The `path` holds the types being checked,
+/// to detect types derived from themselves.
+fn is_valid(
+  context: &XsdContext,
+  field_type: FieldType,
+  value: &str,
+  path: &mut Vec<(String, String)>,
+) -> Result<bool, Error> {
+  match field_type {
+    FieldType::Named(kind) => {
+      if context.get_type_mapping(kind).is_some() {
+        return Ok(true);
+      }
+
+      if RustTypesMapping::is_xs_type(context, kind) {
+        return is_valid_built_in(context, kind, value);
+      }
+
+      let name = context.resolve(kind)?;
+      if path.contains(&name) {
+        return Err(Error::new(format!(
+          "Simple type {kind:?} is derived from itself"
+        )));
+      }
+
+      path.push(name);
+      let is_valid =
+        if let Some((simple_type, definition_context)) = context.get_simple_type(kind)? {
+          is_valid(
+            &definition_context,
+            FieldType::Simple(&simple_type),
+            value,
+            path,
+          )
+        } else if let Some((complex_type, definition_context)) = context.get_complex_type(kind)? {
+          is_valid(
+            &definition_context,
+            FieldType::Complex(&complex_type),
+            value,
+            path,
+          )
+        } else {
+          Ok(true)
+        };
+      path.pop();
+
+      is_valid
+    }
+    FieldType::Simple(simple_type) => {
+      if let Some(list) = &simple_type.list {
+        for item in value.split_whitespace() {
+          if !is_valid(context, FieldType::Named(&list.item_type), item, path)? {
+            return Ok(false);
+          }
+        }
+        return Ok(true);
+      }
+
+      if let Some(union) = &simple_type.union {
+        for member in union.member_types.split_whitespace() {
+          if is_valid(context, FieldType::Named(member), value, path)? {
+            return Ok(true);
+          }
+        }
+        for member in &union.simple_types {
+          if is_valid(context, FieldType::Simple(member), value, path)? {
+            return Ok(true);
+          }
+        }
+        return Ok(false);
+      }
+
+      match &simple_type.restriction {
+        Some(restriction) => is_valid_restriction(context, restriction, value, path),
+        None => Ok(true),
+      }
+    }
+    FieldType::Complex(complex_type) => {
+      if let Some(simple_content) = &complex_type.simple_content {
+        return is_valid(
+          context,
+          FieldType::Named(&simple_content.extension.base),
+          value,
+          path,
+        );
+      }
+
+      let is_mixed = complex_type.mixed
+        || complex_type
+          .complex_content
+          .as_ref()
+          .is_some_and(|complex_content| complex_content.mixed);
+
+      if is_mixed {
+        Ok(true)
+      } else {
+        Err(Error::new(
+          "Default and fixed values require a simple type or mixed content",
+        ))
+      }
+    }
+    FieldType::Attribute(reference) => match context.get_attribute(reference)? {
+      Some((attribute, definition_context)) => is_valid(
+        &definition_context,
+        get_attribute_type(&attribute)?,
+        value,
+        path,
+      ),
+      None => Ok(true),
+    },
+    FieldType::Mapped => Ok(true),
+  }
+}
+
+/// Check if the Rust type of a field implements `Default`. Restricted simple types with facets
+/// have no value valid for all of them to start from, and unions only have the `Default` of
+/// their first member which has one. Complex types, and types defined outside of the loaded
+/// schemas, implement it.
+pub fn has_default(context: &XsdContext, field_type: FieldType) -> Result<bool, Error> {
+  has_default_within(context, field_type, &mut vec![])
+}
+
+fn has_default_within(
+  context: &XsdContext,
+  field_type: FieldType,
+  path: &mut Vec<(String, String)>,
+) -> Result<bool, Error> {
+  match field_type {
+    FieldType::Named(kind) => {
+      if RustTypesMapping::is_xs_type(context, kind) || context.get_type_mapping(kind).is_some() {
+        return Ok(true);
+      }
+
+      // a top-level simple type without facets is a structure with its text `content`
+      let Some((simple_type, definition_context)) = context
+        .get_simple_type(kind)?
+        .filter(|(simple_type, _)| !is_content_type(simple_type))
+      else {
+        return Ok(true);
+      };
+
+      let name = context.resolve(kind)?;
+      if path.contains(&name) {
+        return Err(Error::new(format!(
+          "Simple type {kind:?} is derived from itself"
+        )));
+      }
+
+      path.push(name);
+      let has_default =
+        has_default_within(&definition_context, FieldType::Simple(&simple_type), path);
+      path.pop();
+
+      has_default
+    }
+    FieldType::Simple(simple_type) => {
+      if let Some(union) = &simple_type.union {
+        for member in union.member_types.split_whitespace() {
+          if has_default_within(context, FieldType::Named(member), path)? {
+            return Ok(true);
+          }
+        }
+        for member in &union.simple_types {
+          if member.is_plain_restriction()
+            || has_default_within(context, FieldType::Simple(member), path)?
+          {
+            return Ok(true);
+          }
+        }
+        return Ok(false);
+      }
+
+      match &simple_type.restriction {
+        // a restriction without facets has the type of its base
+        Some(Restriction {
+          base: Some(base), ..
+        }) if simple_type.is_plain_restriction() => {
+          has_default_within(context, FieldType::Named(base), path)
+        }
+        // enumerations default to their first value
+        Some(restriction) if simple_type.list.is_none() => {
+          Ok(!restriction.enumerations.is_empty() || !restriction.has_facets())
+        }
+        _ => Ok(true),
+      }
+    }
+    FieldType::Complex(_) | FieldType::Mapped => Ok(true),
+    FieldType::Attribute(reference) => match context.get_attribute(reference)? {
+      Some((attribute, definition_context)) => {
+        has_default_within(&definition_context, get_attribute_type(&attribute)?, path)
+      }
+      None => Ok(true),
+    },
+  }
+}
+
+/// Check a value against the base, the enumerations and the facets of a restriction,
+/// after normalizing its whitespace.
+fn is_valid_restriction(
+  context: &XsdContext,
+  restriction: &Restriction,
+  value: &str,
+  path: &mut Vec<(String, String)>,
+) -> Result<bool, Error> {
+  let Some(base) = &restriction.base else {
+    return Err(Error::new("Missing base for restriction"));
+  };
+
+  let white_space = restriction.get_white_space(context)?;
+  let value = white_space.normalize(value);
+
+  if !restriction.enumerations.is_empty()
+    && !restriction
+      .enumerations
+      .iter()
+      .any(|enumeration| white_space.normalize(&enumeration.value) == value)
+  {
+    return Ok(false);
+  }
+
+  if !is_valid(context, FieldType::Named(base), &value, path)? {
+    return Ok(false);
+  }
+
+  if !restriction.has_facets() {
+    return Ok(true);
+  }
+
+  let order = match restriction.get_base_restrictions(context)? {
+    Some((restrictions, built_in)) => {
+      let (_, built_in_context) = &restrictions[restrictions.len() - 1];
+      let rust_type = RustTypesMapping::get(built_in_context, &built_in)?;
+      BoundOrder::of_type(
+        built_in.split(':').next_back().unwrap_or_default(),
+        &rust_type.to_string(),
+      )
+    }
+    None => BoundOrder::Unordered,
+  };
+
+  is_valid_value(restriction, order, &value)
+}
+
+/// Expression building the value of a field from its lexical form, checked when generating.
+fn implement_value(rust_type: &TokenStream, value: &str, built_value: &Value) -> TokenStream {
+  match built_value {
+    Value::String => quote!(#value.to_string()),
+    Value::Bool(value) => quote!(#value),
+    Value::Number(literal) => quote!(#literal),
+    Value::Parsed => quote!(#value.parse().expect("valid default value")),
+    Value::Content => quote!(#rust_type {
+      content: #value.to_string(),
+    }),
+    Value::Deserialized => {
+      let content = format!("<Value>{}</Value>", xml::escape::escape_str_pcdata(value));
+      quote!(yaserde::de::from_str::<#rust_type>(#content).expect("valid default value"))
+    }
+  }
+}
+
+/// Unit type of a fixed value, it only accepts this value.
+fn implement_fixed_type(
+  struct_name: &Ident,
+  rust_type: &TokenStream,
+  value: &str,
+  built_value: &Value,
+) -> TokenStream {
+  let is_matching = match built_value {
+    Value::String | Value::Content => quote!(value == Self::VALUE),
+    Value::Bool(true) => quote!(matches!(value.trim(), "true" | "1")),
+    Value::Bool(false) => quote!(matches!(value.trim(), "false" | "0")),
+    Value::Number(literal) => quote!(value.trim().parse::<#rust_type>().ok() == Some(#literal)),
+    Value::Parsed => quote!(matches!(
+      (value.parse::<#rust_type>(), Self::VALUE.parse::<#rust_type>()),
+      (Ok(value), Ok(fixed)) if value == fixed
+    )),
+    Value::Deserialized => quote!({
+      let read = |value: &str| {
+        let content = format!("<Value>{}</Value>", xml::escape::escape_str_pcdata(value));
+        yaserde::de::from_str::<#rust_type>(&content)
+      };
+      matches!((read(value), read(Self::VALUE)), (Ok(value), Ok(fixed)) if value == fixed)
+    }),
+  };
+
+  let struct_label = struct_name.to_string();
+  let deserialize_implementation = implement_deserialize_from_str(struct_name);
+  let serialize_implementation = implement_serialize_display(struct_name, quote!());
+
+  quote!(
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct #struct_name;
+
+    impl #struct_name {
+      pub const VALUE: &'static str = #value;
+    }
+
+    impl std::str::FromStr for #struct_name {
+      type Err = String;
+
+      fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if #is_matching {
+          Ok(#struct_name)
+        } else {
+          Err(format!("{:?} does not match the fixed value {:?} of {}", value, Self::VALUE, #struct_label))
+        }
+      }
+    }
+
+    impl std::fmt::Display for #struct_name {
+      fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(Self::VALUE)
+      }
+    }
+
+    #deserialize_implementation
+
+    #serialize_implementation
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::xsd::{registry::Registry, schema::Schema};
+
+  static SCHEMA: &str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:simpleType name="Priority">
+        <xs:restriction base="xs:token">
+          <xs:enumeration value="low"/>
+          <xs:enumeration value="high"/>
+        </xs:restriction>
+      </xs:simpleType>
+      <xs:simpleType name="Code">
+        <xs:restriction base="xs:string"/>
+      </xs:simpleType>
+      <xs:simpleType name="Rating">
+        <xs:restriction base="xs:int">
+          <xs:minInclusive value="1"/>
+          <xs:maxInclusive value="5"/>
+        </xs:restriction>
+      </xs:simpleType>
+      <xs:simpleType name="Ratings">
+        <xs:list itemType="Rating"/>
+      </xs:simpleType>
+      <xs:simpleType name="Edition">
+        <xs:union memberTypes="xs:positiveInteger">
+          <xs:simpleType>
+            <xs:restriction base="xs:string">
+              <xs:enumeration value="first"/>
+            </xs:restriction>
+          </xs:simpleType>
+        </xs:union>
+      </xs:simpleType>
+      <xs:simpleType name="Score">
+        <xs:union memberTypes="Rating xs:int"/>
+      </xs:simpleType>
+      <xs:simpleType name="Grade">
+        <xs:union memberTypes="Rating"/>
+      </xs:simpleType>
+      <xs:simpleType name="Loop">
+        <xs:restriction base="Loop"/>
+      </xs:simpleType>
+      <xs:complexType name="Size">
+        <xs:simpleContent>
+          <xs:extension base="xs:positiveInteger">
+            <xs:attribute name="unit" type="xs:string"/>
+          </xs:extension>
+        </xs:simpleContent>
+      </xs:complexType>
+      <xs:complexType name="Body">
+        <xs:sequence>
+          <xs:element name="paragraph" type="xs:string"/>
+        </xs:sequence>
+      </xs:complexType>
+      <xs:attribute name="level" type="Priority"/>
+    </xs:schema>
+  "#;
+
+  fn get_context() -> XsdContext {
+    let context = XsdContext::new(SCHEMA).unwrap();
+    let mut registry = Registry::default();
+    registry
+      .register(&yaserde::de::from_str::<Schema>(SCHEMA).unwrap(), &context)
+      .unwrap();
+
+    context.with_registry(&Rc::new(registry))
+  }
+
+  fn add_field(
+    value_constraints: &ValueConstraints,
+    field_name: &str,
+    field_type: FieldType,
+    rust_type: TokenStream,
+    constraint: ValueConstraint,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    value_constraints.add_field(
+      &get_context(),
+      &Ident::new(field_name, Span::call_site()),
+      field_type,
+      &rust_type,
+      &constraint,
+    )
+  }
+
+  #[test]
+  fn without_value_constraint() {
+    let value_constraints = ValueConstraints::new(&Ident::new("Document", Span::call_site()));
+    let fields = quote!(
+      #[yaserde(attribute)]
+      pub language: Option<String>,
+    );
+
+    let (derive_default, implementations) = value_constraints.implement(&fields).unwrap();
+
+    assert_eq!(derive_default.to_string(), "Default ,");
+    assert!(implementations.is_empty());
+  }
+
+  #[test]
+  fn default_value() {
+    let value_constraints = ValueConstraints::new(&Ident::new("Document", Span::call_site()));
+
+    let (rust_type, default_attribute) = add_field(
+      &value_constraints,
+      "draft",
+      FieldType::Named("xs:boolean"),
+      quote!(bool),
+      ValueConstraint::Default("1".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(rust_type.to_string(), "bool");
+    assert_eq!(
+      default_attribute.to_string(),
+      ", default = \"default_document_draft\""
+    );
+
+    let fields = quote!(
+      #[yaserde(attribute #default_attribute)]
+      pub draft: #rust_type,
+      #[yaserde(rename = "title")]
+      pub title: String,
+    );
+
+    let (derive_default, implementations) = value_constraints.implement(&fields).unwrap();
+    assert!(derive_default.is_empty());
+
+    let expected = TokenStream::from_str(
+      r#"
+        fn default_document_draft() -> bool {
+          true
+        }
+
+        impl Default for Document {
+          fn default() -> Self {
+            Document {
+              draft: default_document_draft(),
+              title: Default::default(),
+            }
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementations.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn fixed_value() {
+    let value_constraints = ValueConstraints::new(&Ident::new("Document", Span::call_site()));
+
+    let (rust_type, default_attribute) = add_field(
+      &value_constraints,
+      "version",
+      FieldType::Named("xs:string"),
+      quote!(String),
+      ValueConstraint::Fixed("1.0".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(rust_type.to_string(), "DocumentVersion");
+    assert!(default_attribute.is_empty());
+
+    let fields = quote!(
+      #[yaserde(attribute)]
+      pub version: #rust_type,
+    );
+
+    let (derive_default, implementations) = value_constraints.implement(&fields).unwrap();
+
+    assert_eq!(derive_default.to_string(), "Default ,");
+    assert!(implementations.to_string().starts_with(
+      "# [derive (Clone , Debug , Default , PartialEq)] pub struct DocumentVersion ;"
+    ));
+  }
+
+  #[test]
+  fn invalid_value() {
+    let value_constraints = ValueConstraints::new(&Ident::new("Document", Span::call_site()));
+
+    let invalid_values = [
+      ("xs:unsignedInt", quote!(u32), "many", "u32"),
+      ("xs:boolean", quote!(bool), "yes", "bool"),
+      ("xs:date", quote!(String), "2023-02-29", "String"),
+      (
+        "xs:decimal",
+        quote!(xml_schema::types::XsDecimal),
+        "1e3",
+        "xml_schema::types::XsDecimal",
+      ),
+    ];
+
+    for (kind, rust_type, value, label) in invalid_values {
+      assert_eq!(
+        add_field(
+          &value_constraints,
+          "pages",
+          FieldType::Named(kind),
+          rust_type,
+          ValueConstraint::Default(value.to_string())
+        )
+        .unwrap_err(),
+        Error::new(format!("Invalid value {value:?} for type {label}"))
+      );
+    }
+
+    assert_eq!(
+      ValueConstraint::new(&Some("1".to_string()), &Some("2".to_string())).unwrap_err(),
+      Error::new("default and fixed cannot be both present")
+    );
+  }
+
+  #[test]
+  fn user_defined_types() {
+    let value_constraints = ValueConstraints::new(&Ident::new("Document", Span::call_site()));
+    let default = |field_type, rust_type, value: &str| {
+      add_field(
+        &value_constraints,
+        "field",
+        field_type,
+        rust_type,
+        ValueConstraint::Default(value.to_string()),
+      )
+    };
+
+    let valid_values = [
+      (
+        FieldType::Named("Priority"),
+        " high ",
+        "\" high \" . parse ()",
+      ),
+      (FieldType::Attribute("level"), "low", "\"low\" . parse ()"),
+      (
+        FieldType::Named("Code"),
+        "A1",
+        "Code { content : \"A1\" . to_string () , }",
+      ),
+      (FieldType::Named("Rating"), "5", "\"5\" . parse ()"),
+      (FieldType::Named("Ratings"), "1 3", "\"1 3\" . parse ()"),
+      (FieldType::Named("Edition"), "first", "\"first\" . parse ()"),
+      (FieldType::Named("Edition"), "2", "\"2\" . parse ()"),
+      (
+        FieldType::Named("Size"),
+        "10",
+        "yaserde :: de :: from_str :: < Size >",
+      ),
+    ];
+
+    for (field_type, value, expected) in valid_values {
+      let rust_type = match field_type {
+        FieldType::Named(name) => Ident::new(name, Span::call_site()),
+        _ => Ident::new("Priority", Span::call_site()),
+      };
+      default(field_type, quote!(#rust_type), value).unwrap();
+
+      let fields = quote!(pub field: #rust_type,);
+      let (_, implementations) = value_constraints.implement(&fields).unwrap();
+      assert!(
+        implementations.to_string().contains(expected),
+        "{}",
+        implementations
+      );
+      value_constraints.fields.borrow_mut().clear();
+    }
+
+    let invalid_values = [
+      (FieldType::Named("Priority"), "medium"),
+      (FieldType::Attribute("level"), "medium"),
+      (FieldType::Named("Rating"), "6"),
+      (FieldType::Named("Ratings"), "1 2 3 4 5 6"),
+      (FieldType::Named("Edition"), "0"),
+      (FieldType::Named("Size"), "-10"),
+    ];
+
+    for (field_type, value) in invalid_values {
+      assert_eq!(
+        default(field_type, quote!(Value), value).unwrap_err(),
+        Error::new(format!("Invalid value {value:?} for type Value"))
+      );
+    }
+
+    assert_eq!(
+      default(FieldType::Named("Body"), quote!(Body), "text").unwrap_err(),
+      Error::new("Default and fixed values require a simple type or mixed content")
+    );
+    assert_eq!(
+      default(FieldType::Named("Loop"), quote!(Loop), "text").unwrap_err(),
+      Error::new("Simple type \"Loop\" is derived from itself")
+    );
+  }
+
+  #[test]
+  fn types_with_default() {
+    let context = get_context();
+    let simple_type = |restriction: &str| {
+      yaserde::de::from_str::<SimpleType>(&format!(
+        r#"<xs:simpleType xmlns:xs="http://www.w3.org/2001/XMLSchema">{restriction}</xs:simpleType>"#
+      ))
+      .unwrap()
+    };
+
+    for kind in [
+      "xs:int", "Priority", "Code", "Ratings", "Edition", "Score", "Size", "Body",
+    ] {
+      assert!(
+        has_default(&context, FieldType::Named(kind)).unwrap(),
+        "{}",
+        kind
+      );
+    }
+    assert!(has_default(&context, FieldType::Attribute("level")).unwrap());
+
+    assert!(!has_default(&context, FieldType::Named("Rating")).unwrap());
+    assert!(!has_default(&context, FieldType::Named("Grade")).unwrap());
+
+    let facets =
+      simple_type(r#"<xs:restriction base="xs:string"><xs:length value="2"/></xs:restriction>"#);
+    assert!(!has_default(&context, FieldType::Simple(&facets)).unwrap());
+    let plain = simple_type(r#"<xs:restriction base="Rating"/>"#);
+    assert!(!has_default(&context, FieldType::Simple(&plain)).unwrap());
+  }
+}
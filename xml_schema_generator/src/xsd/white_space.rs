@@ -0,0 +1,119 @@
+use crate::{
+  xsd::{rust_types_mapping::RustTypesMapping, XsdContext},
+  Error,
+};
+use proc_macro2::TokenStream;
+
+/// Normalization of the whitespace of a value, from the `whiteSpace` facet of its type.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WhiteSpace {
+  #[default]
+  Preserve,
+  /// Tabs, line feeds and carriage returns are replaced with spaces.
+  Replace,
+  /// Replaced, then sequences of spaces are collapsed to one, and leading and trailing spaces are removed.
+  Collapse,
+}
+
+impl WhiteSpace {
+  pub fn from_facet(value: &str) -> Result<Self, Error> {
+    match value.trim() {
+      "preserve" => Ok(WhiteSpace::Preserve),
+      "replace" => Ok(WhiteSpace::Replace),
+      "collapse" => Ok(WhiteSpace::Collapse),
+      _ => Err(Error::new(format!(
+        "Invalid whiteSpace facet {value:?}, expected preserve, replace or collapse"
+      ))),
+    }
+  }
+
+  /// Normalization of a built-in type, the one of `xs:string` is kept for other types.
+  pub fn of_type(context: &XsdContext, kind: &str) -> Self {
+    if !RustTypesMapping::is_xs_type(context, kind) {
+      return WhiteSpace::Preserve;
+    }
+
+    match kind.split(':').next_back().unwrap_or_default() {
+      "string" | "anySimpleType" | "anyType" => WhiteSpace::Preserve,
+      "normalizedString" => WhiteSpace::Replace,
+      _ => WhiteSpace::Collapse,
+    }
+  }
+
+  pub fn normalize(&self, value: &str) -> String {
+    match self {
+      WhiteSpace::Preserve => value.to_string(),
+      WhiteSpace::Replace => value.replace(['\t', '\n', '\r'], " "),
+      WhiteSpace::Collapse => value
+        .split([' ', '\t', '\n', '\r'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" "),
+    }
+  }
+
+  /// Statement normalizing the `value: &str` in generated code.
+  pub fn implement_normalize(&self) -> TokenStream {
+    match self {
+      WhiteSpace::Preserve => quote!(),
+      WhiteSpace::Replace => quote!(
+        let value: &str = &value.replace(['\t', '\n', '\r'], " ");
+      ),
+      WhiteSpace::Collapse => quote!(
+        let value: &str = &value
+          .split([' ', '\t', '\n', '\r'])
+          .filter(|part| !part.is_empty())
+          .collect::<Vec<_>>()
+          .join(" ");
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn built_in_white_space() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert_eq!(
+      WhiteSpace::of_type(&context, "xs:string"),
+      WhiteSpace::Preserve
+    );
+    assert_eq!(
+      WhiteSpace::of_type(&context, "xs:normalizedString"),
+      WhiteSpace::Replace
+    );
+    assert_eq!(
+      WhiteSpace::of_type(&context, "xs:token"),
+      WhiteSpace::Collapse
+    );
+    assert_eq!(
+      WhiteSpace::of_type(&context, "xs:NMTOKENS"),
+      WhiteSpace::Collapse
+    );
+    assert_eq!(
+      WhiteSpace::of_type(&context, "MyType"),
+      WhiteSpace::Preserve
+    );
+  }
+
+  #[test]
+  fn normalize() {
+    let value = " a\tb \r\n c  ";
+
+    assert_eq!(WhiteSpace::Preserve.normalize(value), value);
+    assert_eq!(WhiteSpace::Replace.normalize(value), " a b    c  ");
+    assert_eq!(WhiteSpace::Collapse.normalize(value), "a b c");
+    assert_eq!(WhiteSpace::Collapse.normalize("a\u{a0} b"), "a\u{a0} b");
+
+    assert_eq!(
+      WhiteSpace::from_facet("trim").unwrap_err(),
+      Error::new("Invalid whiteSpace facet \"trim\", expected preserve, replace or collapse")
+    );
+  }
+}
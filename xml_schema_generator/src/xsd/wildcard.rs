@@ -0,0 +1,348 @@
+use crate::{
+  xsd::{registry::ComponentKind, XsdContext},
+  Error,
+};
+use proc_macro2::{Span, TokenStream};
+use syn::{parse2, FieldsNamed, Ident, LitStr};
+
+/// `xs:any` or `xs:anyAttribute` wildcard.
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Wildcard {
+  #[yaserde(attribute)]
+  pub namespace: Option<String>,
+  #[yaserde(rename = "processContents", attribute)]
+  pub process_contents: Option<String>,
+}
+
+/// Namespaces matched by a wildcard, `None` standing for unqualified names.
+#[derive(Clone, Debug, PartialEq)]
+enum NamespaceConstraint {
+  Any,
+  Other(Option<String>),
+  List(Vec<Option<String>>),
+}
+
+impl NamespaceConstraint {
+  fn allows(&self, namespace: &Option<String>) -> bool {
+    match self {
+      NamespaceConstraint::Any => true,
+      NamespaceConstraint::Other(target_namespace) => {
+        namespace.is_some() && namespace != target_namespace
+      }
+      NamespaceConstraint::List(namespaces) => namespaces.contains(namespace),
+    }
+  }
+}
+
+impl Wildcard {
+  /// Field of the elements matched by an `xs:any` wildcard of the structure,
+  /// and the implementation of its constraints.
+  pub fn implement_any(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    let wildcard_name = Ident::new(&format!("{struct_name}Any"), Span::call_site());
+    let constraints =
+      self.implement_constraints(context, &wildcard_name, ComponentKind::Element, &[])?;
+
+    Ok((
+      quote!(
+        #[yaserde(flatten)]
+        pub any: xml_schema::types::XsAny<#wildcard_name>,
+      ),
+      constraints,
+    ))
+  }
+
+  /// Field of the attributes matched by an `xs:anyAttribute` wildcard of the structure,
+  /// and the implementation of its constraints. The wildcard does not match the attributes
+  /// of the `attribute_fields`.
+  pub fn implement_any_attribute(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+    attribute_fields: &TokenStream,
+  ) -> Result<(TokenStream, TokenStream), Error> {
+    let wildcard_name = Ident::new(&format!("{struct_name}AnyAttribute"), Span::call_site());
+    let excluded = get_attribute_names(attribute_fields)?;
+    let constraints =
+      self.implement_constraints(context, &wildcard_name, ComponentKind::Attribute, &excluded)?;
+
+    Ok((
+      quote!(
+        #[yaserde(flatten)]
+        pub any_attributes: xml_schema::types::XsAnyAttributes<#wildcard_name>,
+      ),
+      constraints,
+    ))
+  }
+
+  /// Type implementing the constraints of the wildcard, named `wildcard_name`.
+  pub fn implement_constraints(
+    &self,
+    context: &XsdContext,
+    wildcard_name: &Ident,
+    kind: ComponentKind,
+    excluded: &[String],
+  ) -> Result<TokenStream, Error> {
+    let namespace_constraint = self.get_namespace_constraint(context)?;
+
+    let namespace = match &namespace_constraint {
+      NamespaceConstraint::Any => quote!(xml_schema::types::NamespaceConstraint::Any),
+      NamespaceConstraint::Other(target_namespace) => {
+        let target_namespace = implement_namespace(target_namespace);
+        quote!(xml_schema::types::NamespaceConstraint::Other(#target_namespace))
+      }
+      NamespaceConstraint::List(namespaces) => {
+        let namespaces = namespaces.iter().map(implement_namespace);
+        quote!(xml_schema::types::NamespaceConstraint::List(&[#(#namespaces),*]))
+      }
+    };
+
+    // only the names matched by the wildcard are checked with strict processing
+    let declared = if self.is_strict()? {
+      let declared = context
+        .get_declared_names(kind)
+        .into_iter()
+        .map(|(namespace, name)| {
+          (
+            Some(namespace).filter(|namespace| !namespace.is_empty()),
+            name,
+          )
+        })
+        .filter(|(namespace, _name)| namespace_constraint.allows(namespace))
+        .map(|(namespace, name)| {
+          let namespace = implement_namespace(&namespace);
+          quote!((#namespace, #name))
+        });
+
+      quote!(const DECLARED: &'static [(Option<&'static str>, &'static str)] = &[#(#declared),*];)
+    } else {
+      quote!()
+    };
+
+    let excluded = if excluded.is_empty() {
+      quote!()
+    } else {
+      quote!(const EXCLUDED: &'static [&'static str] = &[#(#excluded),*];)
+    };
+
+    let process_contents = match self.process_contents.as_deref() {
+      None | Some("strict") => quote!(Strict),
+      Some("lax") => quote!(Lax),
+      Some(_) => quote!(Skip),
+    };
+
+    Ok(quote!(
+      #[derive(Clone, Debug, Default, PartialEq)]
+      pub struct #wildcard_name;
+
+      impl xml_schema::types::Wildcard for #wildcard_name {
+        const NAMESPACE: xml_schema::types::NamespaceConstraint = #namespace;
+        const PROCESS_CONTENTS: xml_schema::types::ProcessContents = xml_schema::types::ProcessContents::#process_contents;
+        #declared
+        #excluded
+      }
+    ))
+  }
+
+  /// Constraint of the `namespace` attribute, relative to the target namespace of the schema.
+  fn get_namespace_constraint(&self, context: &XsdContext) -> Result<NamespaceConstraint, Error> {
+    let target_namespace = context.get_target_namespace().map(str::to_string);
+
+    match self.namespace.as_deref().map(str::trim) {
+      None | Some("##any") => Ok(NamespaceConstraint::Any),
+      Some("##other") => Ok(NamespaceConstraint::Other(target_namespace)),
+      Some(namespaces) => namespaces
+        .split_whitespace()
+        .map(|namespace| match namespace {
+          "##targetNamespace" => Ok(target_namespace.clone()),
+          "##local" => Ok(None),
+          namespace if namespace.starts_with("##") => Err(Error::new(format!(
+            "Invalid namespace {namespace:?} of wildcard, expected ##any, ##other or a list of namespaces, ##targetNamespace or ##local"
+          ))),
+          namespace => Ok(Some(namespace.to_string())),
+        })
+        .collect::<Result<_, _>>()
+        .map(NamespaceConstraint::List),
+    }
+  }
+
+  fn is_strict(&self) -> Result<bool, Error> {
+    match self.process_contents.as_deref() {
+      None | Some("strict") => Ok(true),
+      Some("lax") | Some("skip") => Ok(false),
+      Some(process_contents) => Err(Error::new(format!(
+        "Invalid processContents {process_contents:?} of wildcard, expected strict, lax or skip"
+      ))),
+    }
+  }
+}
+
+fn implement_namespace(namespace: &Option<String>) -> TokenStream {
+  match namespace {
+    Some(namespace) => quote!(Some(#namespace)),
+    None => quote!(None),
+  }
+}
+
+/// Names of the attributes of generated fields, renamed or named after the field.
+fn get_attribute_names(fields: &TokenStream) -> Result<Vec<String>, Error> {
+  let fields_named = parse2::<FieldsNamed>(quote!({ #fields }))
+    .map_err(|e| Error::new(format!("Invalid attribute fields: {e}")))?;
+
+  let mut names = vec![];
+
+  for field in &fields_named.named {
+    for attribute in field
+      .attrs
+      .iter()
+      .filter(|attribute| attribute.path().is_ident("yaserde"))
+    {
+      let mut is_attribute = false;
+      let mut rename = None;
+
+      attribute
+        .parse_nested_meta(|meta| {
+          if meta.path.is_ident("attribute") {
+            is_attribute = true;
+          } else if meta.path.is_ident("rename") {
+            rename = Some(meta.value()?.parse::<LitStr>()?.value());
+          } else if meta.input.peek(syn::Token![=]) {
+            meta.value()?.parse::<syn::Expr>()?;
+          }
+          Ok(())
+        })
+        .map_err(|e| Error::new(format!("Invalid attribute fields: {e}")))?;
+
+      if is_attribute {
+        names.push(rename.unwrap_or_else(|| field.ident.as_ref().unwrap().to_string()));
+      }
+    }
+  }
+
+  Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  fn context() -> XsdContext {
+    XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+      .unwrap()
+      .with_target_namespace(&Some("http://example.com".to_string()))
+  }
+
+  #[test]
+  fn any() {
+    let wildcard = Wildcard {
+      namespace: Some("##targetNamespace ##local http://other.com".to_string()),
+      process_contents: Some("lax".to_string()),
+    };
+
+    let (field, constraints) = wildcard
+      .implement_any(&context(), &Ident::new("Book", Span::call_site()))
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub any: xml_schema::types::XsAny<BookAny> ,
+      "#,
+    )
+    .unwrap();
+    assert_eq!(field.to_string(), expected.to_string());
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct BookAny;
+
+        impl xml_schema::types::Wildcard for BookAny {
+          const NAMESPACE: xml_schema::types::NamespaceConstraint = xml_schema::types::NamespaceConstraint::List(&[Some("http://example.com"), None, Some("http://other.com")]);
+          const PROCESS_CONTENTS: xml_schema::types::ProcessContents = xml_schema::types::ProcessContents::Lax;
+        }
+      "#,
+    )
+    .unwrap();
+    assert_eq!(constraints.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn any_attribute() {
+    let wildcard = Wildcard {
+      namespace: Some("##other".to_string()),
+      process_contents: None,
+    };
+
+    let attribute_fields = quote!(
+      #[yaserde(attribute)]
+      pub lang: Option<String>,
+      #[yaserde(attribute, rename = "type")]
+      pub kind: String,
+      #[yaserde(rename = "title")]
+      pub title: String,
+    );
+
+    let (field, constraints) = wildcard
+      .implement_any_attribute(
+        &context(),
+        &Ident::new("Book", Span::call_site()),
+        &attribute_fields,
+      )
+      .unwrap();
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub any_attributes: xml_schema::types::XsAnyAttributes<BookAnyAttribute> ,
+      "#,
+    )
+    .unwrap();
+    assert_eq!(field.to_string(), expected.to_string());
+
+    // lifetimes are compared as generated, not as parsed from a string
+    let expected = quote!(
+      #[derive(Clone, Debug, Default, PartialEq)]
+      pub struct BookAnyAttribute;
+
+      impl xml_schema::types::Wildcard for BookAnyAttribute {
+        const NAMESPACE: xml_schema::types::NamespaceConstraint = xml_schema::types::NamespaceConstraint::Other(Some("http://example.com"));
+        const PROCESS_CONTENTS: xml_schema::types::ProcessContents = xml_schema::types::ProcessContents::Strict;
+        const DECLARED: &'static [(Option<&'static str>, &'static str)] = &[];
+        const EXCLUDED: &'static [&'static str] = &["lang", "type"];
+      }
+    );
+    assert_eq!(constraints.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn invalid_wildcard() {
+    let struct_name = Ident::new("Book", Span::call_site());
+
+    let wildcard = Wildcard {
+      namespace: Some("##all".to_string()),
+      process_contents: None,
+    };
+    assert_eq!(
+      wildcard.implement_any(&context(), &struct_name).unwrap_err().message(),
+      "Invalid namespace \"##all\" of wildcard, expected ##any, ##other or a list of namespaces, ##targetNamespace or ##local"
+    );
+
+    let wildcard = Wildcard {
+      namespace: None,
+      process_contents: Some("none".to_string()),
+    };
+    assert_eq!(
+      wildcard
+        .implement_any(&context(), &struct_name)
+        .unwrap_err()
+        .message(),
+      "Invalid processContents \"none\" of wildcard, expected strict, lax or skip"
+    );
+  }
+}
@@ -0,0 +1,549 @@
+use crate::{
+  xsd::{
+    attribute::Attribute,
+    attribute_group::AttributeGroup,
+    complex_type::ComplexType,
+    element::Element,
+    group::Group,
+    qualification::Qualification,
+    registry::{Component, ComponentKind, DerivedType, Registry, Substitute},
+    simple_type::SimpleType,
+    type_mappings::TypeMappings,
+    value_constraint::ValueConstraints,
+  },
+  Error,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::rc::Rc;
+use xml::namespace::Namespace;
+use xml::reader::{EventReader, XmlEvent};
+
+#[derive(Clone, Debug)]
+pub struct XsdContext {
+  module_namespace_mappings: BTreeMap<String, String>,
+  pub namespace: Namespace,
+  target_namespace: Option<String>,
+  element_form_default: Qualification,
+  attribute_form_default: Qualification,
+  xml_schema_prefix: Option<String>,
+  is_in_sub_module: bool,
+  registry: Option<Rc<Registry>>,
+  type_mappings: Rc<TypeMappings>,
+  path: Vec<String>,
+  value_constraints: Option<ValueConstraints>,
+}
+
+impl XsdContext {
+  pub fn new(content: &str) -> Result<Self, String> {
+    let cursor = Cursor::new(content);
+    let parser = EventReader::new(cursor);
+
+    for xml_element in parser {
+      match xml_element {
+        Ok(XmlEvent::StartElement {
+          name, namespace, ..
+        }) => {
+          if name.namespace == Some("http://www.w3.org/2001/XMLSchema".to_string())
+            && name.local_name == "schema"
+          {
+            let module_namespace_mappings = BTreeMap::new();
+            let xml_schema_prefix = name.prefix;
+
+            return Ok(XsdContext {
+              module_namespace_mappings,
+              namespace,
+              target_namespace: None,
+              element_form_default: Qualification::default(),
+              attribute_form_default: Qualification::default(),
+              xml_schema_prefix,
+              is_in_sub_module: false,
+              registry: None,
+              type_mappings: Rc::default(),
+              path: vec![],
+              value_constraints: None,
+            });
+          }
+        }
+        Err(_) => break,
+        _ => {}
+      }
+    }
+
+    Err("Bad XML Schema, unable to found schema element.".to_string())
+  }
+
+  pub fn with_module_namespace_mappings(
+    mut self,
+    module_namespace_mappings: &BTreeMap<String, String>,
+  ) -> Self {
+    self.module_namespace_mappings = module_namespace_mappings.clone();
+    self
+  }
+
+  /// Validate references against the components of all the loaded schemas.
+  pub fn with_registry(mut self, registry: &Rc<Registry>) -> Self {
+    self.registry = Some(registry.clone());
+    self
+  }
+
+  pub fn with_target_namespace(mut self, target_namespace: &Option<String>) -> Self {
+    self.target_namespace = target_namespace.clone();
+    self
+  }
+
+  pub fn get_target_namespace(&self) -> Option<&str> {
+    self.target_namespace.as_deref()
+  }
+
+  /// Qualification of the local elements and attributes declared without `form` in the schema.
+  pub fn with_form_defaults(
+    mut self,
+    element_form_default: &Option<Qualification>,
+    attribute_form_default: &Option<Qualification>,
+  ) -> Self {
+    self.element_form_default = element_form_default.clone().unwrap_or_default();
+    self.attribute_form_default = attribute_form_default.clone().unwrap_or_default();
+    self
+  }
+
+  /// Check if a local element is in the target namespace, by its `form` or the schema default.
+  pub fn is_qualified_element(&self, form: &Option<Qualification>) -> bool {
+    form.as_ref().unwrap_or(&self.element_form_default) == &Qualification::Qualidified
+  }
+
+  /// Check if a local attribute is in the target namespace, by its `form` or the schema default.
+  pub fn is_qualified_attribute(&self, form: &Option<Qualification>) -> bool {
+    form.as_ref().unwrap_or(&self.attribute_form_default) == &Qualification::Qualidified
+  }
+
+  pub fn with_type_mappings(mut self, type_mappings: &Rc<TypeMappings>) -> Self {
+    self.type_mappings = type_mappings.clone();
+    self
+  }
+
+  /// Context of the fields of a top-level component, for their path in type mappings.
+  pub fn with_path(&self, name: &str) -> Self {
+    let mut context = self.clone();
+    context.path = vec![name.to_string()];
+    context
+  }
+
+  /// Context of the fields of an inline type, nested in the element `name`.
+  pub fn with_child_path(&self, name: &str) -> Self {
+    let mut context = self.clone();
+    context.path.push(name.to_string());
+    context
+  }
+
+  /// Context of the fields of the structure `struct_name`, collecting their value constraints.
+  pub fn with_value_constraints(&self, struct_name: &Ident) -> Self {
+    let mut context = self.clone();
+    context.value_constraints = Some(ValueConstraints::new(struct_name));
+    context
+  }
+
+  /// Value constraints of the fields of the current structure.
+  pub fn get_value_constraints(&self) -> Result<&ValueConstraints, Error> {
+    self
+      .value_constraints
+      .as_ref()
+      .ok_or_else(|| Error::new("Default and fixed values are only supported in a structure"))
+  }
+
+  /// Rust type mapped to a type by its qualified name.
+  pub fn get_type_mapping(&self, kind: &str) -> Option<TokenStream> {
+    self
+      .type_mappings
+      .get_type(kind, |reference| self.resolve(reference).ok())
+  }
+
+  /// Rust type mapped to an element, or an attribute (`@name`), of the current component.
+  pub fn get_field_type_mapping(&self, name: &str) -> Option<TokenStream> {
+    let path = self
+      .path
+      .iter()
+      .map(String::as_str)
+      .chain(std::iter::once(name))
+      .collect::<Vec<_>>()
+      .join("/");
+
+    self.type_mappings.get_path(&path)
+  }
+
+  /// Name of the type generated for an inline simple type of an element, or an attribute,
+  /// of the current component, e.g. `DocumentStatus` for the `status` of `Document`.
+  pub fn get_inline_type_name(&self, name: &str) -> Ident {
+    let name = self
+      .path
+      .iter()
+      .map(String::as_str)
+      .chain(std::iter::once(name))
+      .collect::<Vec<_>>()
+      .join("_")
+      .replace('.', "_")
+      .to_upper_camel_case();
+
+    Ident::new(&name, Span::call_site())
+  }
+
+  /// Unprefixed references of a schema included without target namespace
+  /// belong to the namespace of the including schema.
+  pub fn set_chameleon_namespace(&mut self, namespace: &str) {
+    if self.namespace.get("").unwrap_or_default().is_empty() {
+      self.namespace.force_put("", namespace);
+    }
+  }
+
+  pub fn has_xml_schema_prefix(&self) -> bool {
+    self.xml_schema_prefix.is_some()
+  }
+
+  pub fn match_xml_schema_prefix(&self, value: &str) -> bool {
+    self.xml_schema_prefix == Some(value.to_string())
+  }
+
+  pub fn get_module(&self, prefix: &str) -> Option<String> {
+    self
+      .namespace
+      .get(prefix)
+      .and_then(|namespace| self.get_namespace_module(namespace))
+  }
+
+  /// Module where the types of a namespace are generated or mapped, if it is not the current one.
+  pub fn get_namespace_module(&self, namespace: &str) -> Option<String> {
+    self.module_namespace_mappings.get(namespace).cloned()
+  }
+
+  /// Prefix bound to a namespace in the schema element, the default namespace is not considered.
+  pub fn get_prefix(&self, namespace: &str) -> Option<String> {
+    self
+      .namespace
+      .0
+      .iter()
+      .find(|(prefix, uri)| !prefix.is_empty() && uri.as_str() == namespace)
+      .map(|(prefix, _uri)| prefix.to_owned())
+  }
+
+  /// Prefixes bound in the schema element to the namespaces generated in, or mapped to,
+  /// another module, which referenced elements and attributes are written with.
+  pub fn get_module_prefixes(&self) -> Vec<(String, String)> {
+    self
+      .namespace
+      .0
+      .iter()
+      .filter(|(prefix, namespace)| {
+        !prefix.is_empty()
+          && self
+            .module_namespace_mappings
+            .contains_key(namespace.as_str())
+      })
+      .map(|(prefix, namespace)| (prefix.to_owned(), namespace.to_owned()))
+      .collect()
+  }
+
+  /// Namespace and local name of a qualified name.
+  pub fn resolve(&self, reference: &str) -> Result<(String, String), Error> {
+    let (prefix, local_name) = reference.split_once(':').unwrap_or(("", reference));
+
+    match self.namespace.get(prefix) {
+      Some(namespace) => Ok((namespace.to_owned(), local_name.to_owned())),
+      None if prefix.is_empty() => Ok((String::new(), local_name.to_owned())),
+      None => Err(Error::new(format!(
+        "Undefined prefix {prefix:?} in {reference:?}"
+      ))),
+    }
+  }
+
+  /// Find the referenced top-level component. Without registry, or for a namespace mapped to an
+  /// existing module, references can not be checked and `None` is returned.
+  pub fn lookup(&self, kind: ComponentKind, reference: &str) -> Result<Option<Component>, Error> {
+    let Some(registry) = &self.registry else {
+      return Ok(None);
+    };
+
+    let (namespace, local_name) = self.resolve(reference)?;
+
+    if let Some(component) = registry.get(kind, &namespace, &local_name) {
+      return Ok(Some(component));
+    }
+
+    if registry.has_namespace(&namespace) {
+      return Err(Error::new(format!("{kind} {reference:?} is not defined")));
+    }
+
+    if self.module_namespace_mappings.contains_key(&namespace) {
+      return Ok(None);
+    }
+
+    Err(Error::new(format!(
+      "{kind} {reference:?} is not defined, namespace {namespace:?} is not loaded nor mapped to a module"
+    )))
+  }
+
+  /// Namespaces and local names of the top-level components of a kind in all the loaded schemas.
+  pub fn get_declared_names(&self, kind: ComponentKind) -> Vec<(String, String)> {
+    self
+      .registry
+      .iter()
+      .flat_map(|registry| registry.names(kind))
+      .map(|(namespace, name)| (namespace.to_owned(), name.to_owned()))
+      .collect()
+  }
+
+  /// Concrete elements which can substitute a top-level element, empty if no element substitutes it.
+  /// Their types are implemented with the prefixes of the schema defining them.
+  pub fn get_substitutes(&self, namespace: &str, name: &str) -> Vec<Substitute> {
+    self
+      .registry
+      .iter()
+      .flat_map(|registry| registry.get_substitutes(namespace, name))
+      .map(|substitute| Substitute {
+        context: self.with_definition_context(&substitute.context),
+        ..substitute
+      })
+      .collect()
+  }
+
+  /// Concrete complex types which can replace a complex type with `xsi:type`, empty if no type
+  /// derives from it.
+  pub fn get_derived_types(&self, namespace: &str, name: &str) -> Vec<DerivedType> {
+    self
+      .registry
+      .iter()
+      .flat_map(|registry| registry.get_derived_types(namespace, name))
+      .collect()
+  }
+
+  /// Context of a component defined in another schema, with the prefixes of this schema.
+  fn with_definition_context(&self, definition_context: &XsdContext) -> Self {
+    let mut context = self.clone();
+    context.namespace = definition_context.namespace.clone();
+    context.xml_schema_prefix = definition_context.xml_schema_prefix.clone();
+    context.element_form_default = definition_context.element_form_default.clone();
+    context.attribute_form_default = definition_context.attribute_form_default.clone();
+    context
+  }
+
+  /// Definition of a referenced attribute group, with the context to implement its attributes:
+  /// prefixes are the ones of the schema defining the group.
+  pub fn get_attribute_group(
+    &self,
+    reference: &str,
+  ) -> Result<((String, String), AttributeGroup, XsdContext), Error> {
+    let Some(registry) = &self.registry else {
+      return Err(Error::new(format!(
+        "Attribute group {reference:?} can not be resolved"
+      )));
+    };
+
+    let (namespace, local_name) = self.resolve(reference)?;
+
+    match registry.get_attribute_group(&namespace, &local_name) {
+      Some((attribute_group, definition_context)) => Ok((
+        (namespace, local_name),
+        attribute_group.clone(),
+        self.with_definition_context(definition_context),
+      )),
+      None => {
+        self.lookup(ComponentKind::AttributeGroup, reference)?;
+        Err(Error::new(format!(
+          "Attribute group {reference:?} is defined in a mapped namespace, its attributes can not be generated"
+        )))
+      }
+    }
+  }
+
+  /// Definition of a referenced simple type, with the context of the schema defining it.
+  /// `None` if the type is not defined in the loaded schemas, like a type of a mapped namespace.
+  pub fn get_simple_type(
+    &self,
+    reference: &str,
+  ) -> Result<Option<(SimpleType, XsdContext)>, Error> {
+    self.get_definition(reference, Registry::get_simple_type)
+  }
+
+  /// Definition of a referenced complex type, with the context of the schema defining it.
+  pub fn get_complex_type(
+    &self,
+    reference: &str,
+  ) -> Result<Option<(ComplexType, XsdContext)>, Error> {
+    self.get_definition(reference, Registry::get_complex_type)
+  }
+
+  /// Definition of a referenced model group, with the context of the schema defining it.
+  pub fn get_group(&self, reference: &str) -> Result<Option<(Group, XsdContext)>, Error> {
+    self.get_definition(reference, Registry::get_group)
+  }
+
+  /// Declaration of a referenced top-level element, with the context of the schema declaring it.
+  pub fn get_element(&self, reference: &str) -> Result<Option<(Element, XsdContext)>, Error> {
+    self.get_definition(reference, Registry::get_element)
+  }
+
+  /// Declaration of a referenced top-level attribute, with the context of the schema declaring it.
+  pub fn get_attribute(&self, reference: &str) -> Result<Option<(Attribute, XsdContext)>, Error> {
+    self.get_definition(reference, Registry::get_attribute)
+  }
+
+  fn get_definition<T: Clone>(
+    &self,
+    reference: &str,
+    get: impl for<'a> Fn(&'a Registry, &str, &str) -> Option<&'a (T, XsdContext)>,
+  ) -> Result<Option<(T, XsdContext)>, Error> {
+    let Some(registry) = &self.registry else {
+      return Ok(None);
+    };
+
+    let (namespace, local_name) = self.resolve(reference)?;
+
+    Ok(
+      get(registry, &namespace, &local_name).map(|(definition, definition_context)| {
+        (
+          definition.clone(),
+          self.with_definition_context(definition_context),
+        )
+      }),
+    )
+  }
+
+  pub fn set_is_in_sub_module(&mut self, is_in_sub_module: bool) {
+    self.is_in_sub_module = is_in_sub_module;
+  }
+
+  pub fn is_in_sub_module(&self) -> bool {
+    self.is_in_sub_module
+  }
+}
+
+#[test]
+fn get_module() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let mut mapping = BTreeMap::new();
+  mapping.insert(
+    "http://example.com".to_string(),
+    "crate::example".to_string(),
+  );
+  let context = context.with_module_namespace_mappings(&mapping);
+
+  assert_eq!(
+    context.get_module("example"),
+    Some("crate::example".to_string())
+  );
+  assert_eq!(context.get_module("other"), None);
+}
+
+#[test]
+fn get_prefix() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns="http://example.com"
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  assert_eq!(
+    context.get_prefix("http://example.com"),
+    Some("example".to_string())
+  );
+  assert_eq!(context.get_prefix("http://other.com"), None);
+}
+
+#[test]
+fn lookup() {
+  use crate::xsd::schema::Schema;
+
+  let content = r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      xmlns:other="http://other.com"
+      xmlns:unknown="http://unknown.com"
+      targetNamespace="http://example.com"
+      >
+      <xs:complexType name="Book"/>
+    </xs:schema>
+  "#;
+
+  let context = XsdContext::new(content).unwrap();
+
+  let mut registry = Registry::default();
+  registry
+    .register(&yaserde::de::from_str::<Schema>(content).unwrap(), &context)
+    .unwrap();
+
+  let mut mapping = BTreeMap::new();
+  mapping.insert("http://other.com".to_string(), "crate::other".to_string());
+
+  assert_eq!(
+    context.lookup(ComponentKind::Type, "example:Author"),
+    Ok(None)
+  );
+
+  let context = context
+    .with_module_namespace_mappings(&mapping)
+    .with_registry(&Rc::new(registry));
+
+  assert_eq!(
+    context.lookup(ComponentKind::Type, "example:Book"),
+    Ok(Some(Component::ComplexType {
+      simple_content: false
+    }))
+  );
+  assert_eq!(
+    context.lookup(ComponentKind::Type, "other:Author"),
+    Ok(None)
+  );
+  assert_eq!(
+    context
+      .lookup(ComponentKind::Element, "example:Book")
+      .unwrap_err()
+      .message(),
+    "Element \"example:Book\" is not defined"
+  );
+  assert_eq!(
+    context
+      .lookup(ComponentKind::Type, "unknown:Book")
+      .unwrap_err()
+      .message(),
+    "Type \"unknown:Book\" is not defined, namespace \"http://unknown.com\" is not loaded nor mapped to a module"
+  );
+  assert_eq!(
+    context
+      .lookup(ComponentKind::Type, "missing:Book")
+      .unwrap_err()
+      .message(),
+    "Undefined prefix \"missing\" in \"missing:Book\""
+  );
+}
+
+#[test]
+fn bad_schema_definition() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns="http://www.w3.org/2001/XMLSchema"
+      >
+    </xs:schema>
+  "#,
+  );
+
+  assert!(context.is_err());
+}